@@ -0,0 +1,297 @@
+//! The [`AnnotatedDateTime`] struct and its associated `impl`s.
+
+use alloc::string::String;
+#[cfg(feature = "formatting")]
+use std::io;
+
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::error;
+#[cfg(feature = "formatting")]
+use crate::format_description::well_known::rfc9557::{
+    is_valid_annotation_value, is_valid_time_zone_name,
+};
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::format_description::well_known::Rfc3339;
+#[cfg(feature = "formatting")]
+use crate::formatting::{format_number_pad_zero, write};
+#[cfg(feature = "parsing")]
+use crate::parsing::rfc9557::{parse_annotations, TimeZone};
+#[cfg(feature = "parsing")]
+use crate::parsing::ParsedItem;
+use crate::{OffsetDateTime, UtcOffset};
+
+/// The time zone named by an [`AnnotatedDateTime`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TimeZoneAnnotation {
+    /// A time zone identifier, such as `Europe/Paris`.
+    Name(String),
+    /// A UTC offset, such as `+01:00`.
+    Offset(UtcOffset),
+}
+
+/// An [`OffsetDateTime`] along with the annotations of
+/// [RFC 9557](crate::format_description::well_known::Rfc9557): the time zone and the calendar.
+///
+/// Each annotation may be critical, in which case a recipient must reject the value if it does not
+/// understand the annotation.
+///
+/// ```rust
+/// # use time::{macros::datetime, AnnotatedDateTime, TimeZoneAnnotation};
+/// let value =
+///     AnnotatedDateTime::parse("2022-07-08T00:14:07+01:00[!Europe/Paris][u-ca=gregory]")?;
+/// assert_eq!(value.date_time(), datetime!(2022-07-08 0:14:07 +1));
+/// assert_eq!(
+///     value.time_zone(),
+///     Some(&TimeZoneAnnotation::Name("Europe/Paris".to_owned()))
+/// );
+/// assert!(value.time_zone_is_critical());
+/// assert_eq!(value.calendar(), Some("gregory"));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnnotatedDateTime {
+    /// The timestamp.
+    date_time: OffsetDateTime,
+    /// The time zone, if any.
+    time_zone: Option<TimeZoneAnnotation>,
+    /// Whether the time zone is critical.
+    time_zone_is_critical: bool,
+    /// The calendar, if any.
+    calendar: Option<String>,
+    /// Whether the calendar is critical.
+    calendar_is_critical: bool,
+}
+
+impl AnnotatedDateTime {
+    /// Create an `AnnotatedDateTime` without any annotations.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, AnnotatedDateTime};
+    /// let value = AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1));
+    /// assert_eq!(value.time_zone(), None);
+    /// assert_eq!(value.calendar(), None);
+    /// ```
+    pub const fn new(date_time: OffsetDateTime) -> Self {
+        Self {
+            date_time,
+            time_zone: None,
+            time_zone_is_critical: false,
+            calendar: None,
+            calendar_is_critical: false,
+        }
+    }
+
+    /// Set the time zone annotation.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, AnnotatedDateTime, TimeZoneAnnotation};
+    /// let value = AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1))
+    ///     .with_time_zone(TimeZoneAnnotation::Offset(offset!(+1)), true);
+    /// assert_eq!(value.time_zone(), Some(&TimeZoneAnnotation::Offset(offset!(+1))));
+    /// assert!(value.time_zone_is_critical());
+    /// ```
+    #[must_use = "This method does not mutate the original `AnnotatedDateTime`."]
+    pub fn with_time_zone(self, time_zone: TimeZoneAnnotation, is_critical: bool) -> Self {
+        Self {
+            time_zone: Some(time_zone),
+            time_zone_is_critical: is_critical,
+            ..self
+        }
+    }
+
+    /// Set the calendar annotation, such as `gregory` or `iso8601`.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, AnnotatedDateTime};
+    /// let value = AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1))
+    ///     .with_calendar("gregory", false);
+    /// assert_eq!(value.calendar(), Some("gregory"));
+    /// assert!(!value.calendar_is_critical());
+    /// ```
+    #[must_use = "This method does not mutate the original `AnnotatedDateTime`."]
+    pub fn with_calendar(self, calendar: impl Into<String>, is_critical: bool) -> Self {
+        Self {
+            calendar: Some(calendar.into()),
+            calendar_is_critical: is_critical,
+            ..self
+        }
+    }
+
+    /// Get the timestamp.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, AnnotatedDateTime};
+    /// assert_eq!(
+    ///     AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1)).date_time(),
+    ///     datetime!(2022-07-08 0:14:07 +1)
+    /// );
+    /// ```
+    pub const fn date_time(&self) -> OffsetDateTime {
+        self.date_time
+    }
+
+    /// Get the time zone annotation, if any.
+    pub const fn time_zone(&self) -> Option<&TimeZoneAnnotation> {
+        self.time_zone.as_ref()
+    }
+
+    /// Whether the time zone annotation is critical.
+    pub const fn time_zone_is_critical(&self) -> bool {
+        self.time_zone_is_critical
+    }
+
+    /// Get the calendar annotation, if any.
+    pub fn calendar(&self) -> Option<&str> {
+        self.calendar.as_deref()
+    }
+
+    /// Whether the calendar annotation is critical.
+    pub const fn calendar_is_critical(&self) -> bool {
+        self.calendar_is_critical
+    }
+}
+
+impl From<OffsetDateTime> for AnnotatedDateTime {
+    fn from(date_time: OffsetDateTime) -> Self {
+        Self::new(date_time)
+    }
+}
+
+// region: formatting & parsing
+#[cfg(feature = "formatting")]
+impl AnnotatedDateTime {
+    /// Format the value as RFC 9557, writing the timestamp followed by its annotations.
+    ///
+    /// A critical time zone given as a UTC offset must match the offset of the timestamp, unless
+    /// the timestamp is in UTC.
+    pub fn format_into(&self, output: &mut impl io::Write) -> Result<usize, error::Format> {
+        match &self.time_zone {
+            Some(TimeZoneAnnotation::Name(name)) if !is_valid_time_zone_name(name.as_bytes()) => {
+                return Err(error::Format::InvalidComponent("time zone"));
+            }
+            Some(TimeZoneAnnotation::Offset(offset))
+                if offset.seconds_past_minute() != 0
+                    || (self.time_zone_is_critical
+                        && self.date_time.offset() != UtcOffset::UTC
+                        && self.date_time.offset() != *offset) =>
+            {
+                return Err(error::Format::InvalidComponent("time zone"));
+            }
+            _ => {}
+        }
+        if matches!(&self.calendar, Some(calendar) if !is_valid_annotation_value(calendar.as_bytes()))
+        {
+            return Err(error::Format::InvalidComponent("calendar"));
+        }
+
+        let mut bytes = self.date_time.format_into(output, &Rfc3339)?;
+
+        if let Some(time_zone) = &self.time_zone {
+            bytes += write(output, b"[")?;
+            if self.time_zone_is_critical {
+                bytes += write(output, b"!")?;
+            }
+            match time_zone {
+                TimeZoneAnnotation::Name(name) => bytes += write(output, name.as_bytes())?,
+                TimeZoneAnnotation::Offset(offset) => {
+                    bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
+                    bytes += format_number_pad_zero::<_, _, 2>(
+                        output,
+                        offset.whole_hours().unsigned_abs(),
+                    )?;
+                    bytes += write(output, b":")?;
+                    bytes += format_number_pad_zero::<_, _, 2>(
+                        output,
+                        offset.minutes_past_hour().unsigned_abs(),
+                    )?;
+                }
+            }
+            bytes += write(output, b"]")?;
+        }
+
+        if let Some(calendar) = &self.calendar {
+            bytes += write(
+                output,
+                if self.calendar_is_critical {
+                    b"[!"
+                } else {
+                    b"["
+                },
+            )?;
+            bytes += write(output, b"u-ca=")?;
+            bytes += write(output, calendar.as_bytes())?;
+            bytes += write(output, b"]")?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// Format the value as RFC 9557, writing the timestamp followed by its annotations.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, AnnotatedDateTime, TimeZoneAnnotation};
+    /// let value = AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1))
+    ///     .with_time_zone(TimeZoneAnnotation::Name("Europe/Paris".to_owned()), false)
+    ///     .with_calendar("gregory", true);
+    /// assert_eq!(
+    ///     value.format()?,
+    ///     "2022-07-08T00:14:07+01:00[Europe/Paris][!u-ca=gregory]"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format(&self) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        self.format_into(&mut buf)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl AnnotatedDateTime {
+    /// Parse an RFC 9557 timestamp, keeping the time zone and calendar annotations.
+    ///
+    /// Critical annotations that are not understood or that are inconsistent with the timestamp
+    /// result in an error. Elective annotations other than the calendar are ignored.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, AnnotatedDateTime, TimeZoneAnnotation};
+    /// let value = AnnotatedDateTime::parse("2022-07-08T00:14:07Z[+01:00][x-foo=bar]")?;
+    /// assert_eq!(value.date_time(), datetime!(2022-07-08 0:14:07 UTC));
+    /// assert_eq!(value.time_zone(), Some(&TimeZoneAnnotation::Offset(offset!(+1))));
+    /// assert_eq!(value.calendar(), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, error::Parse> {
+        let (timestamp, annotations) = input.split_at(input.find('[').unwrap_or(input.len()));
+        let date_time = OffsetDateTime::parse(timestamp, &Rfc3339)?;
+        // A `Z` indicates that the local offset is unknown, so any time zone is consistent with it.
+        let offset = if timestamp.ends_with(['Z', 'z'].as_ref()) {
+            None
+        } else {
+            Some(date_time.offset())
+        };
+
+        let annotations = match parse_annotations(annotations.as_bytes(), offset)? {
+            ParsedItem(b"", annotations) => annotations,
+            _ => return Err(error::Parse::UnexpectedTrailingCharacters),
+        };
+
+        // The annotations have been validated, so they are guaranteed to be ASCII.
+        let to_string = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+        Ok(Self {
+            date_time,
+            time_zone: annotations.time_zone.map(|(time_zone, _)| match time_zone {
+                TimeZone::Name(name) => TimeZoneAnnotation::Name(to_string(name)),
+                TimeZone::Offset(offset) => TimeZoneAnnotation::Offset(offset),
+            }),
+            time_zone_is_critical: matches!(annotations.time_zone, Some((_, true))),
+            calendar: annotations
+                .calendar
+                .map(|(calendar, _)| to_string(calendar)),
+            calendar_is_critical: matches!(annotations.calendar, Some((_, true))),
+        })
+    }
+}
+// endregion formatting & parsing
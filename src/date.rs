@@ -1,5 +1,7 @@
 //! The [`Date`] struct and its associated `impl`s.
 
+#[cfg(feature = "parsing")]
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
@@ -9,7 +11,7 @@ use std::io;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{self, Parsable};
 use crate::util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year};
 use crate::{error, Duration, Month, PrimitiveDateTime, Time, Weekday};
 
@@ -857,6 +859,28 @@ impl Date {
     ) -> Result<Self, error::Parse> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the remainder of the input.
+    ///
+    /// Unlike [`Date::parse`], it is not an error for the input to continue after the `Date`.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::date, Date};
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// assert_eq!(
+    ///     Date::parse_prefix("2020-01-02 rest of line", &format)?,
+    ///     (date!(2020 - 01 - 02), " rest of line")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((Self::try_from(parsed)?, parsing::remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for Date {
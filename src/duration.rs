@@ -5,8 +5,13 @@ use core::convert::{TryFrom, TryInto};
 use core::fmt;
 use core::iter::Sum;
 use core::ops::{Add, Div, Mul, Neg, Sub, SubAssign};
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
+#[cfg(feature = "formatting")]
+use std::io;
 
+use crate::duration_format::DurationFormat;
 use crate::error;
 #[cfg(feature = "std")]
 use crate::Instant;
@@ -739,6 +744,103 @@ impl Duration {
     }
 }
 
+// region: formatting & parsing
+#[cfg(feature = "formatting")]
+impl Duration {
+    /// Format the `Duration` using the ISO 8601 duration format, such as `P3DT4H`.
+    ///
+    /// The duration is formatted in days, hours, minutes, and seconds, as years and months do not
+    /// have a fixed length. A duration that is a whole number of weeks is formatted in weeks.
+    pub fn format_iso8601_into(self, output: &mut impl io::Write) -> Result<usize, io::Error> {
+        crate::formatting::iso8601::format_duration(output, self)
+    }
+
+    /// Format the `Duration` using the ISO 8601 duration format, such as `P3DT4H`.
+    ///
+    /// The duration is formatted in days, hours, minutes, and seconds, as years and months do not
+    /// have a fixed length. A duration that is a whole number of weeks is formatted in weeks.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(90.minutes().format_iso8601(), "PT1H30M");
+    /// assert_eq!((-1.5).seconds().format_iso8601(), "-PT1.5S");
+    /// assert_eq!(14.days().format_iso8601(), "P2W");
+    /// assert_eq!(0.seconds().format_iso8601(), "PT0S");
+    /// ```
+    pub fn format_iso8601(self) -> String {
+        let mut buf = Vec::new();
+        // Writing to a `Vec` cannot fail.
+        let _ = self.format_iso8601_into(&mut buf);
+        String::from_utf8_lossy(&buf).into_owned()
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Duration {
+    /// Parse a `Duration` from the ISO 8601 duration format, such as `P3DT4H`.
+    ///
+    /// The input may have a leading sign, and the last unit may have a decimal fraction, which is
+    /// separated by either a period or a comma. Years and months are rejected, as they do not have
+    /// a fixed length.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, Duration};
+    /// assert_eq!(Duration::parse_iso8601("PT1H30M")?, 90.minutes());
+    /// assert_eq!(Duration::parse_iso8601("P3DT4H")?, 3.days() + 4.hours());
+    /// assert_eq!(Duration::parse_iso8601("-PT0,5S")?, (-0.5).seconds());
+    /// assert_eq!(Duration::parse_iso8601("P2W")?, 2.weeks());
+    /// assert!(Duration::parse_iso8601("P1Y").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_iso8601(input: &str) -> Result<Self, error::Parse> {
+        crate::parsing::iso8601::parse_duration(input.as_bytes())
+    }
+}
+
+/// The duration is formatted using [`DurationFormat::DEFAULT`], such as `1h 30m`. Other
+/// configurations are available using [`DurationFormat::display`].
+///
+/// ```rust
+/// # use time::ext::NumericalDuration;
+/// assert_eq!(90.minutes().to_string(), "1h 30m");
+/// assert_eq!((-1.5).seconds().to_string(), "-1s 500ms");
+/// assert_eq!(0.seconds().to_string(), "0ns");
+/// ```
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DurationFormat::DEFAULT.display(*self).fmt(f)
+    }
+}
+
+/// Parse a `Duration` written as a sequence of values with units, such as `1h30m`, `90s`,
+/// `1.5 days`, or `2 weeks 3 days`.
+///
+/// The input may have a leading sign, which applies to the duration as a whole. Each value may
+/// have a decimal fraction and may be separated from its unit by whitespace. Values may be
+/// separated from each other by whitespace, a comma, or nothing at all. Units are matched
+/// regardless of case and may be abbreviated (`ns`, `us`, `ms`, `s`, `m`, `h`, `d`, `w`) or
+/// written in full, in the singular or plural. The output of [`DurationFormat`] is always accepted.
+///
+/// ```rust
+/// # use time::{ext::NumericalDuration, Duration};
+/// assert_eq!("1h30m".parse::<Duration>()?, 90.minutes());
+/// assert_eq!("90s".parse::<Duration>()?, 90.seconds());
+/// assert_eq!("1.5 days".parse::<Duration>()?, 36.hours());
+/// assert_eq!("2 weeks 3 days".parse::<Duration>()?, 17.days());
+/// assert_eq!("-1 hour, 30 mins".parse::<Duration>()?, (-90).minutes());
+/// assert!("1 month".parse::<Duration>().is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg(feature = "parsing")]
+impl FromStr for Duration {
+    type Err = error::Parse;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        crate::parsing::duration::parse_duration(input.as_bytes())
+    }
+}
+// endregion formatting & parsing
+
 // region: trait impls
 impl TryFrom<StdDuration> for Duration {
     type Error = error::ConversionRange;
@@ -0,0 +1,332 @@
+//! Human-friendly formatting of a [`Duration`].
+//!
+//! A [`DurationFormat`] describes how a duration is broken down into units, such as `1h 30m` or
+//! `1 hour 30 minutes`. The [`Display`](fmt::Display) implementation of [`Duration`] uses
+//! [`DurationFormat::DEFAULT`], and its [`FromStr`](core::str::FromStr) implementation accepts the
+//! output of any configuration.
+//!
+//! ```rust
+//! # use time::duration_format::{DurationFormat, DurationUnit, UnitNames};
+//! # use time::ext::NumericalDuration;
+//! assert_eq!(90.minutes().to_string(), "1h 30m");
+//!
+//! let format = DurationFormat::DEFAULT
+//!     .set_largest_unit(DurationUnit::Hour)
+//!     .set_unit_names(UnitNames::Long);
+//! assert_eq!(format.display(2.days() + 1.minutes()).to_string(), "48 hours 1 minute");
+//! ```
+
+use core::fmt;
+use core::num::NonZeroU8;
+
+use crate::Duration;
+
+/// A unit that a [`Duration`] can be broken down into.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DurationUnit {
+    #[allow(clippy::missing_docs_in_private_items)]
+    Nanosecond,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Microsecond,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Millisecond,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Second,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Minute,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Hour,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Day,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Week,
+}
+
+impl DurationUnit {
+    /// All units, from smallest to largest.
+    pub(crate) const ALL: [Self; 8] = [
+        Self::Nanosecond,
+        Self::Microsecond,
+        Self::Millisecond,
+        Self::Second,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::Week,
+    ];
+
+    /// The number of nanoseconds in one of the unit.
+    pub(crate) const fn nanoseconds(self) -> u64 {
+        match self {
+            Self::Nanosecond => 1,
+            Self::Microsecond => 1_000,
+            Self::Millisecond => 1_000_000,
+            Self::Second => 1_000_000_000,
+            Self::Minute => 60_000_000_000,
+            Self::Hour => 3_600_000_000_000,
+            Self::Day => 86_400_000_000_000,
+            Self::Week => 604_800_000_000_000,
+        }
+    }
+
+    /// The abbreviated name of the unit, such as `ms`.
+    pub(crate) const fn short_name(self) -> &'static str {
+        match self {
+            Self::Nanosecond => "ns",
+            Self::Microsecond => "us",
+            Self::Millisecond => "ms",
+            Self::Second => "s",
+            Self::Minute => "m",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Week => "w",
+        }
+    }
+
+    /// The singular name of the unit, such as `millisecond`.
+    pub(crate) const fn long_name(self) -> &'static str {
+        match self {
+            Self::Nanosecond => "nanosecond",
+            Self::Microsecond => "microsecond",
+            Self::Millisecond => "millisecond",
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+}
+
+/// Whether units are written abbreviated, such as `1h`, or in full, such as `1 hour`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitNames {
+    /// Abbreviated names directly following the value, such as `1h 30m`.
+    Short,
+    /// Full names following the value and a space, such as `1 hour 30 minutes`.
+    Long,
+}
+
+/// How the part of a duration that is not shown is accounted for.
+///
+/// Rounding is applied to the magnitude of the duration, so a negative duration is rounded the
+/// same way as its positive counterpart. If rounding away from zero would result in a value
+/// outside the range of [`Duration`], the value is rounded toward zero instead.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward zero, discarding the remainder.
+    Down,
+    /// Round to the nearest value, with ties rounded away from zero.
+    Nearest,
+    /// Round away from zero if there is any remainder.
+    Up,
+}
+
+/// The configuration of the human-friendly formatting of a [`Duration`].
+///
+/// The duration is broken down into units from the largest to the smallest unit, inclusive. The
+/// largest unit holds any amount that does not fit into the units above it, so `2.days()` is
+/// formatted as `48h` when hours are the largest unit. Units with a value of zero are omitted,
+/// unless the duration is zero altogether, in which case it is formatted using the smallest unit.
+///
+/// If a maximum number of units is set, only that many units are shown, counting from the first
+/// unit with a nonzero value. The remainder is handled according to the [`Rounding`] in use.
+///
+/// ```rust
+/// # use time::{duration_format::{DurationFormat, DurationUnit, Rounding}, ext::NumericalDuration};
+/// # use core::num::NonZeroU8;
+/// let duration = 1.hours() + 29.minutes() + 31.seconds();
+/// assert_eq!(DurationFormat::DEFAULT.display(duration).to_string(), "1h 29m 31s");
+///
+/// let format = DurationFormat::DEFAULT.set_max_units(NonZeroU8::new(2));
+/// assert_eq!(format.display(duration).to_string(), "1h 29m");
+/// let format = format.set_rounding(Rounding::Nearest);
+/// assert_eq!(format.display(duration).to_string(), "1h 30m");
+///
+/// let format = DurationFormat::DEFAULT.set_smallest_unit(DurationUnit::Second);
+/// assert_eq!(format.display(1.5.milliseconds()).to_string(), "0s");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationFormat {
+    /// The largest unit to use.
+    pub(crate) largest_unit: DurationUnit,
+    /// The smallest unit to use.
+    pub(crate) smallest_unit: DurationUnit,
+    /// The maximum number of units to show.
+    pub(crate) max_units: Option<NonZeroU8>,
+    /// Whether unit names are abbreviated.
+    pub(crate) unit_names: UnitNames,
+    /// How the part of the duration that is not shown is accounted for.
+    pub(crate) rounding: Rounding,
+}
+
+impl DurationFormat {
+    /// The default configuration, which uses days through nanoseconds with abbreviated names, shows
+    /// any number of units, and rounds down.
+    pub const DEFAULT: Self = Self {
+        largest_unit: DurationUnit::Day,
+        smallest_unit: DurationUnit::Nanosecond,
+        max_units: None,
+        unit_names: UnitNames::Short,
+        rounding: Rounding::Down,
+    };
+
+    /// Set the largest unit to use. If it is smaller than the smallest unit, it is also used as the
+    /// smallest unit.
+    pub const fn set_largest_unit(self, largest_unit: DurationUnit) -> Self {
+        Self {
+            largest_unit,
+            ..self
+        }
+    }
+
+    /// Set the smallest unit to use. If it is larger than the largest unit, the largest unit is
+    /// used instead.
+    pub const fn set_smallest_unit(self, smallest_unit: DurationUnit) -> Self {
+        Self {
+            smallest_unit,
+            ..self
+        }
+    }
+
+    /// Set the maximum number of units to show, or `None` for no limit.
+    pub const fn set_max_units(self, max_units: Option<NonZeroU8>) -> Self {
+        Self { max_units, ..self }
+    }
+
+    /// Set whether unit names are abbreviated or written in full.
+    pub const fn set_unit_names(self, unit_names: UnitNames) -> Self {
+        Self { unit_names, ..self }
+    }
+
+    /// Set how the part of the duration that is not shown is accounted for.
+    pub const fn set_rounding(self, rounding: Rounding) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Obtain a value that formats the duration using this configuration when displayed.
+    pub const fn display(self, duration: Duration) -> DisplayDuration {
+        DisplayDuration {
+            duration,
+            format: self,
+        }
+    }
+
+    /// The units in use, from the largest to the smallest.
+    fn units(self) -> impl Iterator<Item = DurationUnit> {
+        let smallest_unit = if self.smallest_unit > self.largest_unit {
+            self.largest_unit
+        } else {
+            self.smallest_unit
+        };
+        DurationUnit::ALL
+            .iter()
+            .rev()
+            .copied()
+            .filter(move |unit| (smallest_unit..=self.largest_unit).contains(unit))
+    }
+
+    /// Round the number of nanoseconds to a multiple of the unit, without exceeding the provided
+    /// maximum.
+    const fn round(self, nanoseconds: u128, unit: DurationUnit, max: u128) -> u128 {
+        let unit = unit.nanoseconds() as u128;
+        let (quotient, remainder) = (nanoseconds / unit, nanoseconds % unit);
+        let round_up = match self.rounding {
+            Rounding::Down => false,
+            Rounding::Nearest => remainder * 2 >= unit,
+            Rounding::Up => remainder != 0,
+        };
+        if round_up && (quotient + 1) * unit <= max {
+            (quotient + 1) * unit
+        } else {
+            quotient * unit
+        }
+    }
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A [`Duration`] that is displayed using a [`DurationFormat`].
+///
+/// This is obtained from [`DurationFormat::display`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayDuration {
+    /// The duration being displayed.
+    duration: Duration,
+    /// The configuration in use.
+    format: DurationFormat,
+}
+
+impl fmt::Display for DisplayDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = self.format;
+        let nanoseconds = self.duration.whole_seconds().unsigned_abs() as u128 * 1_000_000_000
+            + self.duration.subsec_nanoseconds().unsigned_abs() as u128;
+        // The largest magnitude of a duration with the same sign, which rounding must not exceed
+        // for the output to be parsed.
+        let max = if self.duration.is_negative() {
+            Duration::MIN
+        } else {
+            Duration::MAX
+        };
+        let max = max.whole_seconds().unsigned_abs() as u128 * 1_000_000_000
+            + max.subsec_nanoseconds().unsigned_abs() as u128;
+
+        // The value is rounded to the smallest unit to determine the first unit shown, after which
+        // the maximum number of units determines the precision. Rounding to a coarser unit can
+        // only carry into the next larger unit, leaving all smaller units at zero.
+        let smallest_unit = format.units().last().unwrap_or(format.largest_unit);
+        let precision = format.max_units.map_or(smallest_unit, |max_units| {
+            let rounded = format.round(nanoseconds, smallest_unit, max);
+            format
+                .units()
+                .skip_while(|&unit| unit != smallest_unit && rounded < unit.nanoseconds() as u128)
+                .take(max_units.get() as _)
+                .last()
+                .unwrap_or(smallest_unit)
+        });
+        let mut nanoseconds = format.round(nanoseconds, precision, max);
+
+        if nanoseconds == 0 {
+            return write_unit(f, 0, precision, format.unit_names);
+        }
+        if self.duration.is_negative() {
+            f.write_str("-")?;
+        }
+        let mut is_first = true;
+        for unit in format.units().take_while(|&unit| unit >= precision) {
+            let value = nanoseconds / unit.nanoseconds() as u128;
+            nanoseconds %= unit.nanoseconds() as u128;
+            if value == 0 {
+                continue;
+            }
+            if !is_first {
+                f.write_str(" ")?;
+            }
+            write_unit(f, value, unit, format.unit_names)?;
+            is_first = false;
+        }
+        Ok(())
+    }
+}
+
+/// Write the value followed by the name of the unit.
+fn write_unit(
+    f: &mut fmt::Formatter<'_>,
+    value: u128,
+    unit: DurationUnit,
+    unit_names: UnitNames,
+) -> fmt::Result {
+    match unit_names {
+        UnitNames::Short => write!(f, "{}{}", value, unit.short_name()),
+        UnitNames::Long if value == 1 => write!(f, "{} {}", value, unit.long_name()),
+        UnitNames::Long => write!(f, "{} {}s", value, unit.long_name()),
+    }
+}
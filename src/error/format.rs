@@ -17,7 +17,8 @@ pub enum Format {
     InsufficientTypeInformation,
     /// The component named has a value that cannot be formatted into the requested format.
     ///
-    /// This variant is only returned when using well-known formats.
+    /// This variant is returned when using well-known formats, as well as when a component that
+    /// can only be parsed, such as `[ignore]`, is present in the format description.
     InvalidComponent(&'static str),
     /// A value of `std::io::Error` was returned internally.
     StdIo(io::Error),
@@ -42,6 +42,40 @@ pub enum InvalidFormatDescription {
         /// The zero-based index where the component name should start.
         index: usize,
     },
+    /// A `strftime`-style specifier is not supported.
+    #[non_exhaustive]
+    UnsupportedSpecifier {
+        /// The specifier, including the leading `%` and any flags.
+        specifier: String,
+        /// The zero-based index the specifier starts at.
+        index: usize,
+    },
+    /// Something was expected, but not found.
+    #[non_exhaustive]
+    Expected {
+        /// What was expected to be present, but wasn't.
+        what: &'static str,
+        /// The zero-based index the item was expected to be found at.
+        index: usize,
+    },
+    /// A required modifier is missing.
+    #[non_exhaustive]
+    MissingRequiredModifier {
+        /// The name of the modifier that is missing.
+        name: &'static str,
+        /// The zero-based index of the component.
+        index: usize,
+    },
+    /// Certain behavior is not supported in the given context.
+    #[non_exhaustive]
+    NotSupported {
+        /// The behavior that is not supported.
+        what: &'static str,
+        /// The context in which the behavior is not supported.
+        context: &'static str,
+        /// The zero-based index the error occurred at.
+        index: usize,
+    },
 }
 
 #[cfg_attr(
@@ -87,6 +121,26 @@ impl fmt::Display for InvalidFormatDescription {
             MissingComponentName { index } => {
                 write!(f, "missing component name at byte index {}", index)
             }
+            UnsupportedSpecifier { specifier, index } => write!(
+                f,
+                "unsupported specifier `{}` at byte index {}",
+                specifier, index
+            ),
+            Expected { what, index } => write!(f, "expected {} at byte index {}", what, index),
+            MissingRequiredModifier { name, index } => write!(
+                f,
+                "missing required modifier `{}` for component at byte index {}",
+                name, index
+            ),
+            NotSupported {
+                what,
+                context,
+                index,
+            } => write!(
+                f,
+                "{} is not supported in {} at byte index {}",
+                what, context, index
+            ),
         }
     }
 }
@@ -0,0 +1,272 @@
+//! Error parsing an input, along with where in the input it occurred
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::error::{self, ParseFromDescription};
+use crate::format_description::Component;
+
+/// A short excerpt of an input or literal.
+///
+/// The bytes are stored inline, so no allocation is necessary. Anything past the first
+/// [`Snippet::CAPACITY`] bytes is discarded, which is indicated by [`Snippet::is_truncated`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Snippet {
+    /// The bytes of the snippet. Only the first `len` bytes are meaningful.
+    bytes: [u8; Self::CAPACITY],
+    /// The number of bytes stored.
+    len: u8,
+    /// Whether the original value was longer than what is stored.
+    is_truncated: bool,
+}
+
+impl Snippet {
+    /// The maximum number of bytes stored in a snippet.
+    pub const CAPACITY: usize = 16;
+
+    /// Create a snippet from the start of the provided bytes.
+    pub(crate) fn new(bytes: &[u8]) -> Self {
+        let len = bytes.len().min(Self::CAPACITY);
+        let mut snippet = Self {
+            bytes: [0; Self::CAPACITY],
+            len: len as _,
+            is_truncated: bytes.len() > Self::CAPACITY,
+        };
+        snippet.bytes[..len].copy_from_slice(&bytes[..len]);
+        snippet
+    }
+
+    /// Obtain the bytes stored in the snippet.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// Whether the original value was longer than the stored bytes.
+    pub const fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
+
+    /// Whether the snippet contains no bytes.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Display for Snippet {
+    /// Write the snippet as a quoted string. Bytes that are not valid UTF-8 are written as escape
+    /// sequences, and a truncated snippet is followed by an ellipsis.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        let mut bytes = self.as_bytes();
+        while !bytes.is_empty() {
+            let valid = match core::str::from_utf8(bytes) {
+                Ok(valid) => valid,
+                // Everything prior to `valid_up_to` is guaranteed to be valid.
+                Err(err) => core::str::from_utf8(&bytes[..err.valid_up_to()]).unwrap_or_default(),
+            };
+            write!(f, "{}", valid.escape_debug())?;
+            bytes = &bytes[valid.len()..];
+            if let Some((byte, remaining)) = bytes.split_first() {
+                write!(f, "\\x{:02x}", byte)?;
+                bytes = remaining;
+            }
+        }
+        f.write_str("\"")?;
+        if self.is_truncated {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Snippet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Snippet")
+            .field(&format_args!("{}", self))
+            .finish()
+    }
+}
+
+/// The item that was expected at the location of a parse error.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    /// A literal sequence of bytes.
+    Literal(Snippet),
+    /// A component, including its modifiers.
+    Component(Component),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "literal {}", literal),
+            Self::Component(component) => write!(f, "component {}", component),
+        }
+    }
+}
+
+/// An error that occurred while parsing the input with a format description, along with where in
+/// the input it occurred.
+///
+/// This error is only returned when the format description is wrapped in
+/// [`Located`](crate::parsing::Located).
+///
+/// ```rust
+/// # use time::{error, macros::format_description, parsing::Located, Date};
+/// let input = "2021-13-01";
+/// let err = match Date::parse(input, &Located::new(format_description!("[year]-[month]-[day]"))) {
+///     Err(error::Parse::InvalidInput(err)) => err,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(err.index(), 5);
+/// assert_eq!(err.found().as_bytes(), b"13-01");
+/// assert_eq!(
+///     err.render(input).to_string(),
+///     "2021-13-01\n     ^ expected component [month], found \"13-01\""
+/// );
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidInput {
+    /// The underlying error.
+    pub(crate) error: ParseFromDescription,
+    /// The zero-based byte offset into the input at which the error occurred.
+    pub(crate) index: usize,
+    /// The item that was expected at `index`.
+    pub(crate) expected: Expected,
+    /// The input starting at `index`.
+    pub(crate) found: Snippet,
+}
+
+impl InvalidInput {
+    /// The underlying error, without any location information.
+    pub const fn error(&self) -> ParseFromDescription {
+        self.error
+    }
+
+    /// The zero-based byte offset into the input at which the error occurred.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The item that was expected at the location of the error.
+    pub const fn expected(&self) -> Expected {
+        self.expected
+    }
+
+    /// The start of the input at the location of the error.
+    pub const fn found(&self) -> Snippet {
+        self.found
+    }
+
+    /// Render the relevant line of the input with a caret pointing at the location of the error.
+    ///
+    /// `input` must be the value that was being parsed. At most [`Render::CONTEXT`] bytes are
+    /// shown on either side of the error, so that long inputs remain readable.
+    pub const fn render<'a>(&'a self, input: &'a str) -> Render<'a> {
+        Render { error: self, input }
+    }
+}
+
+impl fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte index {}: ", self.error, self.index)?;
+        fmt_expected_found(self, f)
+    }
+}
+
+/// Write what was expected and what was found in its place.
+fn fmt_expected_found(err: &InvalidInput, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if err.found.is_empty() {
+        write!(f, "expected {}, found end of input", err.expected)
+    } else {
+        write!(f, "expected {}, found {}", err.expected, err.found)
+    }
+}
+
+/// The location of an [`InvalidInput`] error, rendered with a caret beneath the input.
+///
+/// This value is obtained from [`InvalidInput::render`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+#[derive(Debug, Clone, Copy)]
+pub struct Render<'a> {
+    /// The error being rendered.
+    error: &'a InvalidInput,
+    /// The input that the error occurred in.
+    input: &'a str,
+}
+
+impl Render<'_> {
+    /// The maximum number of bytes of the input shown on either side of the error.
+    pub const CONTEXT: usize = 40;
+}
+
+impl fmt::Display for Render<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = self.input;
+        let mut index = self.error.index.min(input.len());
+        while !input.is_char_boundary(index) {
+            index -= 1;
+        }
+
+        let line_start = input[..index].rfind('\n').map_or(0, |loc| loc + 1);
+        let line_end = input[index..]
+            .find('\n')
+            .map_or(input.len(), |loc| index + loc);
+
+        let mut start = line_start.max(index.saturating_sub(Self::CONTEXT));
+        while !input.is_char_boundary(start) {
+            start += 1;
+        }
+        let mut end = line_end.min(index + Self::CONTEXT);
+        while !input.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let mut column = 0;
+        if start != line_start {
+            f.write_str("...")?;
+            column += 3;
+        }
+        for c in input[start..end].chars() {
+            // Control characters would misalign the caret, so they are replaced.
+            write!(f, "{}", if c.is_control() { ' ' } else { c })?;
+        }
+        if end != line_end {
+            f.write_str("...")?;
+        }
+
+        column += input[start..index].chars().count();
+        write!(f, "\n{:column$}^ ", "", column = column)?;
+        fmt_expected_found(self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidInput {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl From<InvalidInput> for crate::Error {
+    fn from(original: InvalidInput) -> Self {
+        Self::InvalidInput(original)
+    }
+}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl TryFrom<crate::Error> for InvalidInput {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidInput(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
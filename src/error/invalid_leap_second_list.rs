@@ -0,0 +1,49 @@
+//! Invalid leap second list
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::error;
+
+/// The contents of a `leap-seconds.list` file could not be parsed.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidLeapSecondList {
+    /// The one-based line number of the offending line.
+    pub(crate) line: usize,
+}
+
+impl InvalidLeapSecondList {
+    /// Obtain the one-based line number of the line that could not be parsed.
+    pub const fn line(self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for InvalidLeapSecondList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid leap second list entry on line {}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidLeapSecondList {}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl From<InvalidLeapSecondList> for crate::Error {
+    fn from(err: InvalidLeapSecondList) -> Self {
+        Self::InvalidLeapSecondList(err)
+    }
+}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl TryFrom<crate::Error> for InvalidLeapSecondList {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidLeapSecondList(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
@@ -10,6 +10,10 @@ mod indeterminate_offset;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 mod invalid_format_description;
 #[cfg(feature = "parsing")]
+mod invalid_input;
+#[cfg(feature = "alloc")]
+mod invalid_leap_second_list;
+#[cfg(feature = "parsing")]
 mod parse;
 #[cfg(feature = "parsing")]
 mod parse_from_description;
@@ -28,6 +32,10 @@ pub use indeterminate_offset::IndeterminateOffset;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 pub use invalid_format_description::InvalidFormatDescription;
 #[cfg(feature = "parsing")]
+pub use invalid_input::{Expected, InvalidInput, Render, Snippet};
+#[cfg(feature = "alloc")]
+pub use invalid_leap_second_list::InvalidLeapSecondList;
+#[cfg(feature = "parsing")]
 pub use parse::Parse;
 #[cfg(feature = "parsing")]
 pub use parse_from_description::ParseFromDescription;
@@ -56,8 +64,12 @@ pub enum Error {
     UnexpectedTrailingCharacters,
     #[cfg(feature = "parsing")]
     TryFromParsed(TryFromParsed),
+    #[cfg(feature = "parsing")]
+    InvalidInput(InvalidInput),
     #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
     InvalidFormatDescription(InvalidFormatDescription),
+    #[cfg(feature = "alloc")]
+    InvalidLeapSecondList(InvalidLeapSecondList),
     DifferentVariant(DifferentVariant),
 }
 
@@ -76,8 +88,12 @@ impl fmt::Display for Error {
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(e) => e.fmt(f),
+            #[cfg(feature = "parsing")]
+            Self::InvalidInput(e) => e.fmt(f),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Self::InvalidLeapSecondList(e) => e.fmt(f),
             Self::DifferentVariant(e) => e.fmt(f),
         }
     }
@@ -99,8 +115,12 @@ impl std::error::Error for Error {
             Self::UnexpectedTrailingCharacters => None,
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(err) => Some(err),
+            #[cfg(feature = "parsing")]
+            Self::InvalidInput(err) => Some(err),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Self::InvalidLeapSecondList(err) => Some(err),
             Self::DifferentVariant(err) => Some(err),
         }
     }
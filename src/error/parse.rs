@@ -3,7 +3,7 @@
 use core::convert::TryFrom;
 use core::fmt;
 
-use crate::error::{self, ParseFromDescription, TryFromParsed};
+use crate::error::{self, InvalidInput, ParseFromDescription, TryFromParsed};
 
 /// An error that occurred at some stage of parsing.
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
@@ -18,6 +18,10 @@ pub enum Parse {
     /// The input should have ended, but there were characters remaining.
     #[non_exhaustive]
     UnexpectedTrailingCharacters,
+    /// The input did not match a format description, with the location of the mismatch. This is
+    /// only returned when the format description is wrapped in
+    /// [`Located`](crate::parsing::Located).
+    InvalidInput(InvalidInput),
 }
 
 impl fmt::Display for Parse {
@@ -26,6 +30,7 @@ impl fmt::Display for Parse {
             Self::TryFromParsed(err) => err.fmt(f),
             Self::ParseFromDescription(err) => err.fmt(f),
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
+            Self::InvalidInput(err) => err.fmt(f),
         }
     }
 }
@@ -37,6 +42,7 @@ impl std::error::Error for Parse {
             Self::TryFromParsed(err) => Some(err),
             Self::ParseFromDescription(err) => Some(err),
             Self::UnexpectedTrailingCharacters => None,
+            Self::InvalidInput(err) => Some(err),
         }
     }
 }
@@ -74,6 +80,26 @@ impl TryFrom<Parse> for ParseFromDescription {
     fn try_from(err: Parse) -> Result<Self, Self::Error> {
         match err {
             Parse::ParseFromDescription(err) => Ok(err),
+            Parse::InvalidInput(err) => Ok(err.error),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl From<InvalidInput> for Parse {
+    fn from(err: InvalidInput) -> Self {
+        Self::InvalidInput(err)
+    }
+}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl TryFrom<Parse> for InvalidInput {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: Parse) -> Result<Self, Self::Error> {
+        match err {
+            Parse::InvalidInput(err) => Ok(err),
             _ => Err(error::DifferentVariant),
         }
     }
@@ -86,6 +112,7 @@ impl From<Parse> for crate::Error {
             Parse::TryFromParsed(err) => Self::TryFromParsed(err),
             Parse::ParseFromDescription(err) => Self::ParseFromDescription(err),
             Parse::UnexpectedTrailingCharacters => Self::UnexpectedTrailingCharacters,
+            Parse::InvalidInput(err) => Self::InvalidInput(err),
         }
     }
 }
@@ -99,6 +126,7 @@ impl TryFrom<crate::Error> for Parse {
             crate::Error::ParseFromDescription(err) => Ok(Self::ParseFromDescription(err)),
             crate::Error::UnexpectedTrailingCharacters => Ok(Self::UnexpectedTrailingCharacters),
             crate::Error::TryFromParsed(err) => Ok(Self::TryFromParsed(err)),
+            crate::Error::InvalidInput(err) => Ok(Self::InvalidInput(err)),
             _ => Err(error::DifferentVariant),
         }
     }
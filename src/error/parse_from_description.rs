@@ -45,6 +45,7 @@ impl TryFrom<crate::Error> for ParseFromDescription {
     fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
         match err {
             crate::Error::ParseFromDescription(err) => Ok(err),
+            crate::Error::InvalidInput(err) => Ok(err.error),
             _ => Err(error::DifferentVariant),
         }
     }
@@ -2,8 +2,12 @@
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+use core::fmt;
 
-use crate::format_description::modifier;
+use crate::format_description::modifier::{
+    self, MonthRepr, Padding, SubsecondDigits, UnixTimestampPrecision, WeekNumberRepr, WeekdayRepr,
+    YearRepr,
+};
 #[cfg(feature = "alloc")]
 use crate::{error::InvalidFormatDescription, format_description::modifier::Modifiers};
 
@@ -39,6 +43,179 @@ pub enum Component {
     OffsetMinute(modifier::OffsetMinute),
     /// Second within the minute of the UTC offset.
     OffsetSecond(modifier::OffsetSecond),
+    /// The number of seconds (or a smaller unit) since the Unix epoch.
+    UnixTimestamp(modifier::UnixTimestamp),
+    /// A fixed number of bytes, or the bytes up to a delimiter, that are skipped when parsing. This
+    /// component cannot be formatted.
+    Ignore(modifier::Ignore),
+    /// The end of the input. Parsing fails if any input remains. Nothing is written when
+    /// formatting.
+    End(modifier::End),
+}
+
+impl fmt::Display for Component {
+    /// Write the component using the format description syntax, such as `[month repr:short]`.
+    /// Modifiers are only included when they differ from the value that is used in their absence.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Day(modifier) => {
+                f.write_str("[day")?;
+                fmt_padding(f, modifier.padding)?;
+                if modifier.ordinal_suffix {
+                    f.write_str(" suffix:ordinal")?;
+                }
+            }
+            Self::Month(modifier) => {
+                f.write_str("[month")?;
+                fmt_padding(f, modifier.padding)?;
+                match modifier.repr {
+                    MonthRepr::Numerical => {}
+                    MonthRepr::Long => f.write_str(" repr:long")?,
+                    MonthRepr::Short => f.write_str(" repr:short")?,
+                }
+                fmt_case_sensitive(f, modifier.case_sensitive)?;
+            }
+            Self::Ordinal(modifier) => {
+                f.write_str("[ordinal")?;
+                fmt_padding(f, modifier.padding)?;
+            }
+            Self::Weekday(modifier) => {
+                f.write_str("[weekday")?;
+                match modifier.repr {
+                    WeekdayRepr::Short => f.write_str(" repr:short")?,
+                    WeekdayRepr::Long => {}
+                    WeekdayRepr::Sunday => f.write_str(" repr:sunday")?,
+                    WeekdayRepr::Monday => f.write_str(" repr:monday")?,
+                }
+                if !modifier.one_indexed {
+                    f.write_str(" one_indexed:false")?;
+                }
+                fmt_case_sensitive(f, modifier.case_sensitive)?;
+            }
+            Self::WeekNumber(modifier) => {
+                f.write_str("[week_number")?;
+                fmt_padding(f, modifier.padding)?;
+                match modifier.repr {
+                    WeekNumberRepr::Iso => {}
+                    WeekNumberRepr::Sunday => f.write_str(" repr:sunday")?,
+                    WeekNumberRepr::Monday => f.write_str(" repr:monday")?,
+                }
+            }
+            Self::Year(modifier) => {
+                f.write_str("[year")?;
+                fmt_padding(f, modifier.padding)?;
+                match modifier.repr {
+                    YearRepr::Full => {}
+                    YearRepr::LastTwo => f.write_str(" repr:last_two")?,
+                }
+                if modifier.iso_week_based {
+                    f.write_str(" base:iso_week")?;
+                }
+                fmt_sign_is_mandatory(f, modifier.sign_is_mandatory)?;
+            }
+            Self::Hour(modifier) => {
+                f.write_str("[hour")?;
+                fmt_padding(f, modifier.padding)?;
+                if modifier.is_12_hour_clock {
+                    f.write_str(" repr:12")?;
+                }
+            }
+            Self::Minute(modifier) => {
+                f.write_str("[minute")?;
+                fmt_padding(f, modifier.padding)?;
+            }
+            Self::Period(modifier) => {
+                f.write_str("[period")?;
+                if !modifier.is_uppercase {
+                    f.write_str(" case:lower")?;
+                }
+                fmt_case_sensitive(f, modifier.case_sensitive)?;
+            }
+            Self::Second(modifier) => {
+                f.write_str("[second")?;
+                fmt_padding(f, modifier.padding)?;
+            }
+            Self::Subsecond(modifier) => {
+                f.write_str("[subsecond")?;
+                let digits = match modifier.digits {
+                    SubsecondDigits::One => "1",
+                    SubsecondDigits::Two => "2",
+                    SubsecondDigits::Three => "3",
+                    SubsecondDigits::Four => "4",
+                    SubsecondDigits::Five => "5",
+                    SubsecondDigits::Six => "6",
+                    SubsecondDigits::Seven => "7",
+                    SubsecondDigits::Eight => "8",
+                    SubsecondDigits::Nine => "9",
+                    SubsecondDigits::OneOrMore => "",
+                };
+                if !digits.is_empty() {
+                    write!(f, " digits:{}", digits)?;
+                }
+            }
+            Self::OffsetHour(modifier) => {
+                f.write_str("[offset_hour")?;
+                fmt_padding(f, modifier.padding)?;
+                fmt_sign_is_mandatory(f, modifier.sign_is_mandatory)?;
+            }
+            Self::OffsetMinute(modifier) => {
+                f.write_str("[offset_minute")?;
+                fmt_padding(f, modifier.padding)?;
+            }
+            Self::OffsetSecond(modifier) => {
+                f.write_str("[offset_second")?;
+                fmt_padding(f, modifier.padding)?;
+            }
+            Self::UnixTimestamp(modifier) => {
+                f.write_str("[unix_timestamp")?;
+                match modifier.precision {
+                    UnixTimestampPrecision::Second => {}
+                    UnixTimestampPrecision::Millisecond => f.write_str(" precision:millisecond")?,
+                    UnixTimestampPrecision::Microsecond => f.write_str(" precision:microsecond")?,
+                    UnixTimestampPrecision::Nanosecond => f.write_str(" precision:nanosecond")?,
+                }
+                fmt_sign_is_mandatory(f, modifier.sign_is_mandatory)?;
+            }
+            Self::Ignore(modifier) => {
+                f.write_str("[ignore")?;
+                if let Some(count) = modifier.count {
+                    write!(f, " count:{}", count)?;
+                }
+                if let Some(until) = modifier.until {
+                    write!(f, " until:{}", until as char)?;
+                }
+            }
+            Self::End(_) => f.write_str("[end")?,
+        }
+        f.write_str("]")
+    }
+}
+
+/// Write the `padding` modifier if it is not the default.
+fn fmt_padding(f: &mut fmt::Formatter<'_>, padding: Padding) -> fmt::Result {
+    match padding {
+        Padding::Space => f.write_str(" padding:space"),
+        Padding::Zero => Ok(()),
+        Padding::None => f.write_str(" padding:none"),
+    }
+}
+
+/// Write the `case_sensitive` modifier if it is not the default.
+fn fmt_case_sensitive(f: &mut fmt::Formatter<'_>, case_sensitive: bool) -> fmt::Result {
+    if case_sensitive {
+        Ok(())
+    } else {
+        f.write_str(" case_sensitive:false")
+    }
+}
+
+/// Write the `sign` modifier if it is not the default.
+fn fmt_sign_is_mandatory(f: &mut fmt::Formatter<'_>, sign_is_mandatory: bool) -> fmt::Result {
+    if sign_is_mandatory {
+        f.write_str(" sign:mandatory")
+    } else {
+        Ok(())
+    }
 }
 
 /// A component with no modifiers present.
@@ -72,6 +249,12 @@ pub(crate) enum NakedComponent {
     OffsetMinute,
     /// Second within the minute of the UTC offset.
     OffsetSecond,
+    /// The number of seconds (or a smaller unit) since the Unix epoch.
+    UnixTimestamp,
+    /// A fixed number of bytes that are skipped when parsing.
+    Ignore,
+    /// The end of the input.
+    End,
 }
 
 #[cfg(feature = "alloc")]
@@ -96,6 +279,9 @@ impl NakedComponent {
             b"offset_hour" => Ok(Self::OffsetHour),
             b"offset_minute" => Ok(Self::OffsetMinute),
             b"offset_second" => Ok(Self::OffsetSecond),
+            b"unix_timestamp" => Ok(Self::UnixTimestamp),
+            b"ignore" => Ok(Self::Ignore),
+            b"end" => Ok(Self::End),
             b"" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -106,11 +292,17 @@ impl NakedComponent {
         }
     }
 
-    /// Attach the necessary modifiers to the component.
-    pub(crate) fn attach_modifiers(self, modifiers: &Modifiers) -> Component {
-        match self {
+    /// Attach the necessary modifiers to the component. An error is returned if a required
+    /// modifier is not present.
+    pub(crate) fn attach_modifiers(
+        self,
+        modifiers: &Modifiers,
+        component_index: usize,
+    ) -> Result<Component, InvalidFormatDescription> {
+        Ok(match self {
             Self::Day => Component::Day(modifier::Day {
                 padding: modifiers.padding.unwrap_or_default(),
+                ordinal_suffix: modifiers.day_has_ordinal_suffix.unwrap_or_default(),
             }),
             Self::Month => Component::Month(modifier::Month {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -162,6 +354,20 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
-        }
+            Self::UnixTimestamp => Component::UnixTimestamp(modifier::UnixTimestamp {
+                precision: modifiers.unix_timestamp_precision.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+            }),
+            Self::Ignore => match (modifiers.ignore_count, modifiers.ignore_until) {
+                (None, None) => {
+                    return Err(InvalidFormatDescription::MissingRequiredModifier {
+                        name: "count",
+                        index: component_index,
+                    });
+                }
+                (count, until) => Component::Ignore(modifier::Ignore { count, until }),
+            },
+            Self::End => Component::End(modifier::End),
+        })
     }
 }
@@ -8,7 +8,11 @@
 mod component;
 pub mod modifier;
 #[cfg(feature = "alloc")]
+mod owned_format_item;
+#[cfg(feature = "alloc")]
 pub(crate) mod parse;
+#[cfg(feature = "alloc")]
+mod strftime;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
@@ -18,7 +22,11 @@ use core::fmt;
 
 pub use self::component::Component;
 #[cfg(feature = "alloc")]
-pub use self::parse::parse;
+pub use self::owned_format_item::OwnedFormatItem;
+#[cfg(feature = "alloc")]
+pub use self::parse::{parse, parse_borrowed, parse_owned};
+#[cfg(feature = "alloc")]
+pub use self::strftime::{parse_strftime, parse_strftime_owned};
 use crate::error;
 
 /// Helper methods.
@@ -38,6 +46,21 @@ mod helper {
 
 /// Well-known formats, typically RFCs.
 pub mod well_known {
+    mod asn1;
+    mod http_date;
+    pub mod iso8601;
+    mod rfc3164;
+    mod rfc5424;
+    pub(crate) mod rfc9557;
+
+    pub use self::asn1::{GeneralizedTime, UtcTime};
+    pub use self::http_date::HttpDate;
+    #[doc(inline)]
+    pub use self::iso8601::Iso8601;
+    pub use self::rfc3164::Rfc3164;
+    pub use self::rfc5424::Rfc5424;
+    pub use self::rfc9557::Rfc9557;
+
     /// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6).
     ///
     /// Format example: 1985-04-12T23:20:50.52Z
@@ -89,6 +112,17 @@ pub mod well_known {
 }
 
 /// A complete description of how to format and parse a type.
+///
+/// With the `alloc` feature enabled, the description can be converted back into a string. The
+/// result uses the syntax of [version 2](parse_borrowed) format descriptions, which is the same as
+/// the syntax of version 1 for any description without brackets or backslashes in a literal.
+///
+/// ```rust
+/// # use time::format_description::{self, FormatItem};
+/// let format = format_description::parse("[year]-[month repr:short padding:zero]-[day]")?;
+/// assert_eq!(FormatItem::Compound(&format).to_string(), "[year]-[month repr:short]-[day]");
+/// # Ok::<_, time::Error>(())
+/// ```
 #[non_exhaustive]
 #[cfg_attr(not(feature = "alloc"), derive(Debug))]
 #[derive(Clone, PartialEq, Eq)]
@@ -127,6 +161,41 @@ impl fmt::Debug for FormatItem<'_> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl fmt::Display for FormatItem<'_> {
+    /// Write the item using the syntax of [version 2](parse_borrowed) format descriptions.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatItem::Literal(literal) => fmt_literal(f, literal),
+            FormatItem::Component(component) => component.fmt(f),
+            FormatItem::Compound(compound) => compound.iter().try_for_each(|item| item.fmt(f)),
+            FormatItem::Optional(item) => write!(f, "[optional [{}]]", item),
+            FormatItem::First(items) => {
+                f.write_str("[first")?;
+                for item in items.iter() {
+                    write!(f, " [{}]", item)?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// Write a literal using the syntax of version 2 format descriptions, escaping any characters that
+/// would otherwise be interpreted as part of the syntax.
+///
+/// Any bytes that are not valid UTF-8 are replaced, as with [`String::from_utf8_lossy`].
+#[cfg(feature = "alloc")]
+fn fmt_literal(f: &mut fmt::Formatter<'_>, literal: &[u8]) -> fmt::Result {
+    for c in String::from_utf8_lossy(literal).chars() {
+        if matches!(c, '\\' | '[' | ']') {
+            f.write_str("\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    Ok(())
+}
+
 impl From<Component> for FormatItem<'_> {
     fn from(component: Component) -> Self {
         Self::Component(component)
@@ -4,6 +4,7 @@
 use alloc::string::String;
 #[cfg(feature = "alloc")]
 use core::mem;
+use core::num::NonZeroU16;
 
 #[cfg(feature = "alloc")]
 use crate::{error::InvalidFormatDescription, format_description::helper};
@@ -15,6 +16,9 @@ use crate::{error::InvalidFormatDescription, format_description::helper};
 pub struct Day {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// Whether the value is followed by its ordinal suffix, such as `st` in `1st`. The suffix is
+    /// provided by the [`Locale`](crate::locale::Locale) in use, which is English by default.
+    pub ordinal_suffix: bool,
 }
 
 /// The representation of a month.
@@ -229,6 +233,76 @@ pub struct OffsetSecond {
 }
 // endregion offset modifiers
 
+/// The precision of a Unix timestamp.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnixTimestampPrecision {
+    /// Seconds since the Unix epoch.
+    Second,
+    /// Milliseconds since the Unix epoch.
+    Millisecond,
+    /// Microseconds since the Unix epoch.
+    Microsecond,
+    /// Nanoseconds since the Unix epoch.
+    Nanosecond,
+}
+
+/// A Unix timestamp.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnixTimestamp {
+    /// The precision of the timestamp.
+    pub precision: UnixTimestampPrecision,
+    /// Whether the `+` sign is present on non-negative values.
+    pub sign_is_mandatory: bool,
+}
+
+// region: special modifiers
+/// Skip a fixed number of bytes, or the bytes up to a delimiter, when parsing.
+///
+/// There is no default value, as either the number of bytes or the delimiter must always be
+/// provided. Formatting a description containing this component always fails.
+///
+/// In a format description, the delimiter is written as a single byte following `until:`, such as
+/// `[ignore until:|]`. As whitespace and `]` end the modifier, they cannot be used as delimiters.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ignore {
+    /// The number of bytes to skip. If `until` is present, this is instead the maximum number of
+    /// bytes that may precede the delimiter.
+    pub count: Option<NonZeroU16>,
+    /// Skip the bytes up to, but not including, the first occurrence of this byte. Parsing fails
+    /// if it is not present.
+    pub until: Option<u8>,
+}
+
+impl Ignore {
+    /// Create an instance of `Ignore` that skips the provided number of bytes.
+    pub const fn count(count: NonZeroU16) -> Self {
+        Self {
+            count: Some(count),
+            until: None,
+        }
+    }
+
+    /// Create an instance of `Ignore` that skips the bytes up to the provided delimiter.
+    pub const fn until(until: u8) -> Self {
+        Self {
+            count: None,
+            until: Some(until),
+        }
+    }
+}
+
+/// The end of the input.
+///
+/// There are currently no modifiers for this component. The struct exists so that modifiers can
+/// be added in the future without a breaking change.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct End;
+// endregion special modifiers
+
 /// Type of padding to ensure a minimum width.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -282,8 +356,12 @@ macro_rules! impl_const_default {
 }
 
 impl_const_default! {
-    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Day => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and
+    /// has no ordinal suffix.
+    @pub Day => Self {
+        padding: Padding::Zero,
+        ordinal_suffix: false,
+    };
     /// Creates a modifier that indicates the value uses the
     /// [`Numerical`](Self::Numerical) representation.
     MonthRepr => Self::Numerical;
@@ -358,6 +436,16 @@ impl_const_default! {
     @pub OffsetMinute => Self { padding: Padding::Zero };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
     @pub OffsetSecond => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value is [in seconds](Self::Second).
+    UnixTimestampPrecision => Self::Second;
+    /// Creates a modifier that indicates the value is [in seconds](UnixTimestampPrecision::Second)
+    /// and only includes the sign if the value is negative.
+    @pub UnixTimestamp => Self {
+        precision: UnixTimestampPrecision::Second,
+        sign_is_mandatory: false,
+    };
+    /// Creates a modifier used to represent the end of input.
+    @pub End => Self;
     /// Creates a modifier that indicates the value is [padded with zeroes](Self::Zero).
     Padding => Self::Zero;
 }
@@ -379,6 +467,10 @@ pub(crate) struct Modifiers {
     pub(crate) year_is_iso_week_based: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
     pub(crate) case_sensitive: Option<bool>,
+    pub(crate) unix_timestamp_precision: Option<UnixTimestampPrecision>,
+    pub(crate) ignore_count: Option<NonZeroU16>,
+    pub(crate) ignore_until: Option<u8>,
+    pub(crate) day_has_ordinal_suffix: Option<bool>,
 }
 
 #[cfg(feature = "alloc")]
@@ -425,6 +517,8 @@ impl Modifiers {
                     | b"offset_second" | b"ordinal" | b"second" | b"week_number" | b"year",
                     b"padding:none",
                 ) => modifiers.padding = Some(Padding::None),
+                (b"day", b"suffix:none") => modifiers.day_has_ordinal_suffix = Some(false),
+                (b"day", b"suffix:ordinal") => modifiers.day_has_ordinal_suffix = Some(true),
                 (b"hour", b"repr:24") => modifiers.hour_is_12_hour_clock = Some(false),
                 (b"hour", b"repr:12") => modifiers.hour_is_12_hour_clock = Some(true),
                 (b"month" | b"period" | b"weekday", b"case_sensitive:true") => {
@@ -433,13 +527,35 @@ impl Modifiers {
                 (b"month" | b"period" | b"weekday", b"case_sensitive:false") => {
                     modifiers.case_sensitive = Some(false)
                 }
+                (b"ignore", modifier) if modifier.starts_with(b"count:") => {
+                    modifiers.ignore_count = Some(
+                        core::str::from_utf8(&modifier[b"count:".len()..])
+                            .ok()
+                            .and_then(|count| count.parse().ok())
+                            .ok_or_else(|| InvalidFormatDescription::InvalidModifier {
+                                value: String::from_utf8_lossy(modifier).into_owned(),
+                                index: *index,
+                            })?,
+                    );
+                }
+                (b"ignore", modifier) if modifier.starts_with(b"until:") => {
+                    modifiers.ignore_until = match modifier[b"until:".len()..] {
+                        [byte] => Some(byte),
+                        _ => {
+                            return Err(InvalidFormatDescription::InvalidModifier {
+                                value: String::from_utf8_lossy(modifier).into_owned(),
+                                index: *index,
+                            });
+                        }
+                    };
+                }
                 (b"month", b"repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 (b"month", b"repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 (b"month", b"repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                (b"offset_hour" | b"year", b"sign:automatic") => {
+                (b"offset_hour" | b"unix_timestamp" | b"year", b"sign:automatic") => {
                     modifiers.sign_is_mandatory = Some(false);
                 }
-                (b"offset_hour" | b"year", b"sign:mandatory") => {
+                (b"offset_hour" | b"unix_timestamp" | b"year", b"sign:mandatory") => {
                     modifiers.sign_is_mandatory = Some(true);
                 }
                 (b"period", b"case:upper") => modifiers.period_is_uppercase = Some(true),
@@ -474,6 +590,18 @@ impl Modifiers {
                 (b"subsecond", b"digits:1+") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::OneOrMore);
                 }
+                (b"unix_timestamp", b"precision:second") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Second);
+                }
+                (b"unix_timestamp", b"precision:millisecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Millisecond);
+                }
+                (b"unix_timestamp", b"precision:microsecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Microsecond);
+                }
+                (b"unix_timestamp", b"precision:nanosecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Nanosecond);
+                }
                 (b"weekday", b"repr:short") => modifiers.weekday_repr = Some(WeekdayRepr::Short),
                 (b"weekday", b"repr:long") => modifiers.weekday_repr = Some(WeekdayRepr::Long),
                 (b"weekday", b"repr:sunday") => modifiers.weekday_repr = Some(WeekdayRepr::Sunday),
@@ -1,16 +1,51 @@
 //! Parse a format description into a standardized representation.
 
+use alloc::boxed::Box;
 use alloc::vec::Vec;
+use core::convert::TryFrom;
 
 use crate::error::InvalidFormatDescription;
 use crate::format_description::component::{Component, NakedComponent};
-use crate::format_description::{helper, modifier, FormatItem};
+use crate::format_description::{helper, modifier, FormatItem, OwnedFormatItem};
+
+/// A helper type to statically validate the version of a format description.
+struct Version<const N: usize>;
+
+impl<const N: usize> Version<N> {
+    /// The version, guaranteed to be one that is supported. Any other version fails to compile, as
+    /// the index is out of bounds.
+    const VALUE: u8 = [1, 2][N - 1];
+}
+
+/// An item that may contain nested items, prior to being converted to a [`FormatItem`] or
+/// [`OwnedFormatItem`].
+#[derive(Debug)]
+pub(crate) enum Item<'a> {
+    /// Bytes that are formatted as-is.
+    Literal(&'a [u8]),
+    /// A single non-literal item.
+    Component(Component),
+    /// `[optional [...]]`
+    Optional {
+        /// The items that may or may not be present.
+        value: Vec<Self>,
+        /// The zero-based index of the opening bracket.
+        index: usize,
+    },
+    /// `[first [...] [...]]`
+    First {
+        /// The sequences of items, the first of which that succeeds is used.
+        value: Vec<Vec<Self>>,
+        /// The zero-based index of the opening bracket.
+        index: usize,
+    },
+}
 
 /// The item parsed and remaining chunk of the format description after one iteration.
 #[derive(Debug)]
 pub(crate) struct ParsedItem<'a> {
     /// The item that was parsed.
-    pub(crate) item: FormatItem<'a>,
+    pub(crate) item: Item<'a>,
     /// What is left of the input string after the item was parsed.
     pub(crate) remaining: &'a [u8],
 }
@@ -32,16 +67,108 @@ fn parse_component(mut s: &[u8], index: &mut usize) -> Result<Component, Invalid
     s = &s[whitespace_loc..];
     s = helper::consume_whitespace(s, index);
 
-    Ok(NakedComponent::parse(component_name, component_index)?
-        .attach_modifiers(&modifier::Modifiers::parse(component_name, s, index)?))
+    NakedComponent::parse(component_name, component_index)?.attach_modifiers(
+        &modifier::Modifiers::parse(component_name, s, index)?,
+        component_index,
+    )
+}
+
+/// Parse a bracketed sequence of items, as used by `[optional]` and `[first]`. The opening bracket
+/// must be the first byte of the input.
+fn parse_nested<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+) -> Result<(Vec<Item<'a>>, &'a [u8]), InvalidFormatDescription> {
+    let opening_bracket_index = *index;
+    *index += 1;
+    let (items, remaining) = parse_items(&s[1..], index, version, true)?;
+    match remaining.split_first() {
+        Some((b']', remaining)) => {
+            *index += 1;
+            Ok((items, remaining))
+        }
+        _ => Err(InvalidFormatDescription::UnclosedOpeningBracket {
+            index: opening_bracket_index,
+        }),
+    }
+}
+
+/// Parse the remainder of an `[optional]` or `[first]` item, starting immediately after the
+/// component name.
+fn parse_nested_component<'a>(
+    component_name: &[u8],
+    mut s: &'a [u8],
+    index: &mut usize,
+    opening_bracket_index: usize,
+    version: u8,
+) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
+    let mut sequences = Vec::new();
+
+    loop {
+        s = helper::consume_whitespace(s, index);
+        match s.first() {
+            Some(b'[') if component_name == b"first" || sequences.is_empty() => {
+                let (items, remaining) = parse_nested(s, index, version)?;
+                sequences.push(items);
+                s = remaining;
+            }
+            Some(b']') if component_name == b"first" || !sequences.is_empty() => {
+                *index += 1;
+                break;
+            }
+            Some(b'[') => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "closing bracket",
+                    index: *index,
+                });
+            }
+            Some(_) => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "opening bracket",
+                    index: *index,
+                });
+            }
+            None => {
+                return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket_index,
+                });
+            }
+        }
+    }
+
+    let item = if component_name == b"optional" {
+        Item::Optional {
+            value: sequences.pop().unwrap_or_default(),
+            index: opening_bracket_index,
+        }
+    } else {
+        Item::First {
+            value: sequences,
+            index: opening_bracket_index,
+        }
+    };
+
+    Ok(ParsedItem {
+        item,
+        remaining: &s[1..],
+    })
 }
 
 /// Parse a literal string from the format description.
-fn parse_literal<'a>(s: &'a [u8], index: &mut usize) -> ParsedItem<'a> {
-    let loc = s.iter().position(|&c| c == b'[').unwrap_or(s.len());
+fn parse_literal<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+    is_nested: bool,
+) -> ParsedItem<'a> {
+    let loc = s
+        .iter()
+        .position(|&c| c == b'[' || (version >= 2 && c == b'\\') || (is_nested && c == b']'))
+        .unwrap_or(s.len());
     *index += loc;
     ParsedItem {
-        item: FormatItem::Literal(&s[..loc]),
+        item: Item::Literal(&s[..loc]),
         remaining: &s[loc..],
     }
 }
@@ -50,48 +177,193 @@ fn parse_literal<'a>(s: &'a [u8], index: &mut usize) -> ParsedItem<'a> {
 fn parse_item<'a>(
     s: &'a [u8],
     index: &mut usize,
+    version: u8,
+    is_nested: bool,
 ) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
-    if let [b'[', b'[', remaining @ ..] = s {
-        *index += 2;
-        return Ok(ParsedItem {
-            item: FormatItem::Literal(&[b'[']),
-            remaining,
-        });
-    };
+    match (version, s) {
+        (1, [b'[', b'[', remaining @ ..]) => {
+            *index += 2;
+            return Ok(ParsedItem {
+                item: Item::Literal(&[b'[']),
+                remaining,
+            });
+        }
+        (2, [b'\\', c @ (b'\\' | b'[' | b']'), remaining @ ..]) => {
+            *index += 2;
+            return Ok(ParsedItem {
+                item: Item::Literal(core::slice::from_ref(c)),
+                remaining,
+            });
+        }
+        (2, [b'\\', ..]) => {
+            return Err(InvalidFormatDescription::Expected {
+                what: "valid escape sequence",
+                index: *index,
+            });
+        }
+        _ => {}
+    }
+
+    if !s.starts_with(&[b'[']) {
+        return Ok(parse_literal(s, index, version, is_nested));
+    }
 
-    if s.starts_with(&[b'[']) {
-        if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
-            *index += 1; // opening bracket
-            let ret_val = ParsedItem {
-                item: FormatItem::Component(parse_component(&s[1..bracket_index], index)?),
-                remaining: &s[bracket_index + 1..],
-            };
-            *index += 1; // closing bracket
-            Ok(ret_val)
-        } else {
-            Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
+    if version >= 2 {
+        let name_start = helper::consume_whitespace(&s[1..], &mut 0);
+        let name_len = name_start
+            .iter()
+            .position(|&c| c.is_ascii_whitespace() || c == b']')
+            .unwrap_or(name_start.len());
+        let component_name = &name_start[..name_len];
+        if component_name == b"optional" || component_name == b"first" {
+            let opening_bracket_index = *index;
+            *index += s.len() - name_start.len() + name_len;
+            return parse_nested_component(
+                component_name,
+                &name_start[name_len..],
+                index,
+                opening_bracket_index,
+                version,
+            );
         }
+    }
+
+    if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
+        *index += 1; // opening bracket
+        let ret_val = ParsedItem {
+            item: Item::Component(parse_component(&s[1..bracket_index], index)?),
+            remaining: &s[bracket_index + 1..],
+        };
+        *index += 1; // closing bracket
+        Ok(ret_val)
     } else {
-        Ok(parse_literal(s, index))
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
+    }
+}
+
+/// Parse a sequence of items, stopping at the end of the input or, if the sequence is nested, at
+/// an unescaped closing bracket.
+fn parse_items<'a>(
+    mut s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+    is_nested: bool,
+) -> Result<(Vec<Item<'a>>, &'a [u8]), InvalidFormatDescription> {
+    let mut items = Vec::new();
+
+    while let Some(&first) = s.first() {
+        if is_nested && first == b']' {
+            break;
+        }
+        let ParsedItem { item, remaining } = parse_item(s, index, version, is_nested)?;
+        s = remaining;
+        items.push(item);
+    }
+
+    Ok((items, s))
+}
+
+impl<'a> TryFrom<Item<'a>> for FormatItem<'a> {
+    type Error = InvalidFormatDescription;
+
+    fn try_from(item: Item<'a>) -> Result<Self, Self::Error> {
+        match item {
+            Item::Literal(literal) => Ok(Self::Literal(literal)),
+            Item::Component(component) => Ok(Self::Component(component)),
+            Item::Optional { index, .. } => Err(InvalidFormatDescription::NotSupported {
+                what: "optional item",
+                context: "borrowed format descriptions",
+                index,
+            }),
+            Item::First { index, .. } => Err(InvalidFormatDescription::NotSupported {
+                what: "'first' item",
+                context: "borrowed format descriptions",
+                index,
+            }),
+        }
+    }
+}
+
+impl From<Item<'_>> for OwnedFormatItem {
+    fn from(item: Item<'_>) -> Self {
+        match item {
+            Item::Literal(literal) => Self::Literal(literal.to_vec().into_boxed_slice()),
+            Item::Component(component) => Self::Component(component),
+            Item::Optional { value, .. } => Self::Optional(Box::new(value.into())),
+            Item::First { value, .. } => Self::First(value.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<Vec<Item<'_>>> for OwnedFormatItem {
+    fn from(items: Vec<Item<'_>>) -> Self {
+        Self::Compound(items.into_iter().map(Into::into).collect())
     }
 }
 
 /// Parse a sequence of items from the format description.
 ///
 /// The syntax for the format description can be found in [the
-/// book](https://time-rs.github.io/book/api/format-description.html).
+/// book](https://time-rs.github.io/book/api/format-description.html). This is equivalent to
+/// [`parse_borrowed::<1>`](parse_borrowed).
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
 pub fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
-    let mut compound = Vec::new();
-    let mut loc = 0;
-
-    let mut s = s.as_bytes();
+    parse_borrowed::<1>(s)
+}
 
-    while !s.is_empty() {
-        let ParsedItem { item, remaining } = parse_item(s, &mut loc)?;
-        s = remaining;
-        compound.push(item);
-    }
+/// Parse a sequence of items from the format description, using the given version of the syntax.
+///
+/// Version 1 is the syntax accepted by [`parse`], where a literal opening bracket is written as
+/// `[[`. Version 2 instead escapes brackets and backslashes with a backslash (`\[`, `\]`, `\\`),
+/// and additionally accepts `[optional [...]]` and `[first [...] [...]]`. As nested items cannot
+/// be borrowed from the input, they are only supported by [`parse_owned`]; they result in an error
+/// here.
+///
+/// ```rust
+/// # use time::{format_description, macros::time};
+/// let description = format_description::parse_borrowed::<2>(r"\[[hour]:[minute]\]")?;
+/// assert_eq!(time!(13:05).format(&description)?, "[13:05]");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_borrowed<const VERSION: usize>(
+    s: &str,
+) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let (items, _) = parse_items(s.as_bytes(), &mut 0, Version::<VERSION>::VALUE, false)?;
+    items.into_iter().map(FormatItem::try_from).collect()
+}
 
-    Ok(compound)
+/// Parse a sequence of items from the format description into an [`OwnedFormatItem`], using the
+/// given version of the syntax.
+///
+/// The syntax is the same as [`parse_borrowed`], with the addition of nested items being supported
+/// in version 2. Unlike the result of that function, the returned value does not borrow from the
+/// input, so it can be stored without the input being kept alive.
+///
+/// ```rust
+/// # use time::{format_description, macros::{date, time}, Date, Time};
+/// let description = format_description::parse_owned::<1>("[hour]:[minute]")?;
+/// assert_eq!(time!(13:05).format(&description)?, "13:05");
+///
+/// let description = format_description::parse_owned::<2>(
+///     "[year]-[month]-[day][optional [ [hour]:[minute]]]",
+/// )?;
+/// assert_eq!(Date::parse("2021-06-01", &description)?, date!(2021 - 06 - 01));
+/// assert_eq!(
+///     Date::parse("2021-06-01 13:05", &description)?,
+///     date!(2021 - 06 - 01)
+/// );
+///
+/// let description =
+///     format_description::parse_owned::<2>("[first [[hour]:[minute]] [[hour][minute]]]")?;
+/// assert_eq!(Time::parse("13:05", &description)?, time!(13:05));
+/// assert_eq!(Time::parse("1305", &description)?, time!(13:05));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_owned<const VERSION: usize>(
+    s: &str,
+) -> Result<OwnedFormatItem, InvalidFormatDescription> {
+    let (items, _) = parse_items(s.as_bytes(), &mut 0, Version::<VERSION>::VALUE, false)?;
+    Ok(items.into())
 }
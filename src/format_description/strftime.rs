@@ -0,0 +1,368 @@
+//! Convert a `strftime`-style format string into a format description.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::InvalidFormatDescription;
+use crate::format_description::modifier::{
+    self, MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+};
+use crate::format_description::{Component, FormatItem, OwnedFormatItem};
+
+// region: items
+/// `%Y`
+const YEAR: FormatItem<'_> = FormatItem::Component(Component::Year(modifier::Year::default()));
+/// `%y`
+const YEAR_LAST_TWO: FormatItem<'_> = FormatItem::Component(Component::Year(modifier::Year {
+    repr: YearRepr::LastTwo,
+    ..modifier::Year::default()
+}));
+/// `%G`
+const ISO_YEAR: FormatItem<'_> = FormatItem::Component(Component::Year(modifier::Year {
+    iso_week_based: true,
+    ..modifier::Year::default()
+}));
+/// `%g`
+const ISO_YEAR_LAST_TWO: FormatItem<'_> = FormatItem::Component(Component::Year(modifier::Year {
+    repr: YearRepr::LastTwo,
+    iso_week_based: true,
+    ..modifier::Year::default()
+}));
+/// `%m`
+const MONTH: FormatItem<'_> = FormatItem::Component(Component::Month(modifier::Month::default()));
+/// `%b`
+const MONTH_SHORT: FormatItem<'_> = FormatItem::Component(Component::Month(modifier::Month {
+    repr: MonthRepr::Short,
+    ..modifier::Month::default()
+}));
+/// `%B`
+const MONTH_LONG: FormatItem<'_> = FormatItem::Component(Component::Month(modifier::Month {
+    repr: MonthRepr::Long,
+    ..modifier::Month::default()
+}));
+/// `%d`
+const DAY: FormatItem<'_> = FormatItem::Component(Component::Day(modifier::Day::default()));
+/// `%e`
+const DAY_SPACE: FormatItem<'_> = FormatItem::Component(Component::Day(modifier::Day {
+    padding: Padding::Space,
+    ..modifier::Day::default()
+}));
+/// `%j`
+const ORDINAL: FormatItem<'_> =
+    FormatItem::Component(Component::Ordinal(modifier::Ordinal::default()));
+/// `%a`
+const WEEKDAY_SHORT: FormatItem<'_> =
+    FormatItem::Component(Component::Weekday(modifier::Weekday {
+        repr: WeekdayRepr::Short,
+        ..modifier::Weekday::default()
+    }));
+/// `%A`
+const WEEKDAY_LONG: FormatItem<'_> =
+    FormatItem::Component(Component::Weekday(modifier::Weekday::default()));
+/// `%u`
+const WEEKDAY_MONDAY: FormatItem<'_> =
+    FormatItem::Component(Component::Weekday(modifier::Weekday {
+        repr: WeekdayRepr::Monday,
+        ..modifier::Weekday::default()
+    }));
+/// `%w`
+const WEEKDAY_SUNDAY: FormatItem<'_> =
+    FormatItem::Component(Component::Weekday(modifier::Weekday {
+        repr: WeekdayRepr::Sunday,
+        one_indexed: false,
+        ..modifier::Weekday::default()
+    }));
+/// `%V`
+const WEEK_NUMBER_ISO: FormatItem<'_> =
+    FormatItem::Component(Component::WeekNumber(modifier::WeekNumber::default()));
+/// `%U`
+const WEEK_NUMBER_SUNDAY: FormatItem<'_> =
+    FormatItem::Component(Component::WeekNumber(modifier::WeekNumber {
+        repr: WeekNumberRepr::Sunday,
+        ..modifier::WeekNumber::default()
+    }));
+/// `%W`
+const WEEK_NUMBER_MONDAY: FormatItem<'_> =
+    FormatItem::Component(Component::WeekNumber(modifier::WeekNumber {
+        repr: WeekNumberRepr::Monday,
+        ..modifier::WeekNumber::default()
+    }));
+/// `%H`
+const HOUR: FormatItem<'_> = FormatItem::Component(Component::Hour(modifier::Hour::default()));
+/// `%k`
+const HOUR_SPACE: FormatItem<'_> = FormatItem::Component(Component::Hour(modifier::Hour {
+    padding: Padding::Space,
+    is_12_hour_clock: false,
+}));
+/// `%I`
+const HOUR_12: FormatItem<'_> = FormatItem::Component(Component::Hour(modifier::Hour {
+    padding: Padding::Zero,
+    is_12_hour_clock: true,
+}));
+/// `%l`
+const HOUR_12_SPACE: FormatItem<'_> = FormatItem::Component(Component::Hour(modifier::Hour {
+    padding: Padding::Space,
+    is_12_hour_clock: true,
+}));
+/// `%M`
+const MINUTE: FormatItem<'_> =
+    FormatItem::Component(Component::Minute(modifier::Minute::default()));
+/// `%S`
+const SECOND: FormatItem<'_> =
+    FormatItem::Component(Component::Second(modifier::Second::default()));
+/// `%f`
+const SUBSECOND: FormatItem<'_> =
+    FormatItem::Component(Component::Subsecond(modifier::Subsecond {
+        digits: SubsecondDigits::Six,
+    }));
+/// `%p`
+const PERIOD_UPPER: FormatItem<'_> =
+    FormatItem::Component(Component::Period(modifier::Period::default()));
+/// `%P`
+const PERIOD_LOWER: FormatItem<'_> = FormatItem::Component(Component::Period(modifier::Period {
+    is_uppercase: false,
+    case_sensitive: true,
+}));
+/// The hour of `%z`.
+const OFFSET_HOUR: FormatItem<'_> =
+    FormatItem::Component(Component::OffsetHour(modifier::OffsetHour::default()));
+/// The minute of `%z`.
+const OFFSET_MINUTE: FormatItem<'_> =
+    FormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute::default()));
+/// `%s`
+const UNIX_TIMESTAMP: FormatItem<'_> =
+    FormatItem::Component(Component::UnixTimestamp(modifier::UnixTimestamp::default()));
+/// A colon, used by the composite specifiers.
+const COLON: FormatItem<'_> = FormatItem::Literal(b":");
+// endregion items
+
+/// Obtain the items that the given specifier (without the leading `%`) expands to, or `None` if
+/// the specifier is not supported.
+fn expand(specifier: &str) -> Option<&'static [FormatItem<'static>]> {
+    Some(match specifier {
+        "a" => &[WEEKDAY_SHORT],
+        "A" => &[WEEKDAY_LONG],
+        "b" | "h" => &[MONTH_SHORT],
+        "B" => &[MONTH_LONG],
+        "d" => &[DAY],
+        "D" => &[
+            MONTH,
+            FormatItem::Literal(b"/"),
+            DAY,
+            FormatItem::Literal(b"/"),
+            YEAR_LAST_TWO,
+        ],
+        "e" => &[DAY_SPACE],
+        "f" => &[SUBSECOND],
+        "F" => &[
+            YEAR,
+            FormatItem::Literal(b"-"),
+            MONTH,
+            FormatItem::Literal(b"-"),
+            DAY,
+        ],
+        "g" => &[ISO_YEAR_LAST_TWO],
+        "G" => &[ISO_YEAR],
+        "H" => &[HOUR],
+        "I" => &[HOUR_12],
+        "j" => &[ORDINAL],
+        "k" => &[HOUR_SPACE],
+        "l" => &[HOUR_12_SPACE],
+        "m" => &[MONTH],
+        "M" => &[MINUTE],
+        "n" => &[FormatItem::Literal(b"\n")],
+        "p" => &[PERIOD_UPPER],
+        "P" => &[PERIOD_LOWER],
+        "r" => &[
+            HOUR_12,
+            COLON,
+            MINUTE,
+            COLON,
+            SECOND,
+            FormatItem::Literal(b" "),
+            PERIOD_UPPER,
+        ],
+        "R" => &[HOUR, COLON, MINUTE],
+        "s" => &[UNIX_TIMESTAMP],
+        "S" => &[SECOND],
+        "t" => &[FormatItem::Literal(b"\t")],
+        "T" => &[HOUR, COLON, MINUTE, COLON, SECOND],
+        "u" => &[WEEKDAY_MONDAY],
+        "U" => &[WEEK_NUMBER_SUNDAY],
+        "V" => &[WEEK_NUMBER_ISO],
+        "w" => &[WEEKDAY_SUNDAY],
+        "W" => &[WEEK_NUMBER_MONDAY],
+        "y" => &[YEAR_LAST_TWO],
+        "Y" => &[YEAR],
+        "z" => &[OFFSET_HOUR, OFFSET_MINUTE],
+        ":z" => &[OFFSET_HOUR, COLON, OFFSET_MINUTE],
+        "%" => &[FormatItem::Literal(b"%")],
+        _ => return None,
+    })
+}
+
+/// Replace the padding of a numerical component, returning `None` if the component has no
+/// padding.
+const fn with_padding(component: Component, padding: Padding) -> Option<Component> {
+    Some(match component {
+        Component::Day(modifier) => Component::Day(modifier::Day {
+            padding,
+            ..modifier
+        }),
+        Component::Month(modifier) if matches!(modifier.repr, MonthRepr::Numerical) => {
+            Component::Month(modifier::Month {
+                padding,
+                ..modifier
+            })
+        }
+        Component::Ordinal(_) => Component::Ordinal(modifier::Ordinal { padding }),
+        Component::WeekNumber(modifier) => Component::WeekNumber(modifier::WeekNumber {
+            padding,
+            ..modifier
+        }),
+        Component::Year(modifier) => Component::Year(modifier::Year {
+            padding,
+            ..modifier
+        }),
+        Component::Hour(modifier) => Component::Hour(modifier::Hour {
+            padding,
+            ..modifier
+        }),
+        Component::Minute(_) => Component::Minute(modifier::Minute { padding }),
+        Component::Second(_) => Component::Second(modifier::Second { padding }),
+        _ => return None,
+    })
+}
+
+/// Convert a `strftime`-style format string into a sequence of items.
+///
+/// This permits reusing the format strings of C, Python, and other tools. Anything other than a
+/// specifier is a literal. The following specifiers are supported.
+///
+/// | Specifier | Equivalent                                        |
+/// |-----------|---------------------------------------------------|
+/// | `%a`      | `[weekday repr:short]`                            |
+/// | `%A`      | `[weekday]`                                       |
+/// | `%b`, `%h`| `[month repr:short]`                              |
+/// | `%B`      | `[month repr:long]`                               |
+/// | `%d`      | `[day]`                                           |
+/// | `%D`      | `%m/%d/%y`                                        |
+/// | `%e`      | `[day padding:space]`                             |
+/// | `%f`      | `[subsecond digits:6]`                            |
+/// | `%F`      | `%Y-%m-%d`                                        |
+/// | `%g`      | `[year repr:last_two base:iso_week]`              |
+/// | `%G`      | `[year base:iso_week]`                            |
+/// | `%H`      | `[hour]`                                          |
+/// | `%I`      | `[hour repr:12]`                                  |
+/// | `%j`      | `[ordinal]`                                       |
+/// | `%k`      | `[hour padding:space]`                            |
+/// | `%l`      | `[hour repr:12 padding:space]`                    |
+/// | `%m`      | `[month]`                                         |
+/// | `%M`      | `[minute]`                                        |
+/// | `%n`      | a newline                                         |
+/// | `%p`      | `[period]`                                        |
+/// | `%P`      | `[period case:lower]`                             |
+/// | `%r`      | `%I:%M:%S %p`                                     |
+/// | `%R`      | `%H:%M`                                           |
+/// | `%s`      | `[unix_timestamp]`                                |
+/// | `%S`      | `[second]`                                        |
+/// | `%t`      | a tab                                             |
+/// | `%T`      | `%H:%M:%S`                                        |
+/// | `%u`      | `[weekday repr:monday]`                           |
+/// | `%U`      | `[week_number repr:sunday]`                       |
+/// | `%V`      | `[week_number]`                                   |
+/// | `%w`      | `[weekday repr:sunday one_indexed:false]`         |
+/// | `%W`      | `[week_number repr:monday]`                       |
+/// | `%y`      | `[year repr:last_two]`                            |
+/// | `%Y`      | `[year]`                                          |
+/// | `%z`      | `[offset_hour sign:mandatory][offset_minute]`     |
+/// | `%:z`     | `[offset_hour sign:mandatory]:[offset_minute]`    |
+/// | `%%`      | a literal `%`                                     |
+///
+/// The glibc flags `-`, `_`, and `0` may precede a numerical specifier to set its padding to none,
+/// spaces, or zeros respectively. Any other specifier results in an error containing the specifier
+/// and its byte index. This includes `%Z`, as time zone names are not known, and the
+/// locale-dependent `%c`, `%x`, and `%X`.
+///
+/// ```rust
+/// # use time::{format_description, macros::datetime};
+/// let description = format_description::parse_strftime("%Y-%m-%dT%H:%M:%S%z")?;
+/// assert_eq!(
+///     datetime!(2021-06-01 9:05 +1).format(&description)?,
+///     "2021-06-01T09:05:00+0100"
+/// );
+///
+/// let description = format_description::parse_strftime("%a %-d %b")?;
+/// assert_eq!(
+///     description,
+///     format_description::parse("[weekday repr:short] [day padding:none] [month repr:short]")?
+/// );
+/// assert_eq!(datetime!(2021-06-01 9:05 +1).format(&description)?, "Tue 1 Jun");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_strftime(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let mut items = Vec::new();
+    let mut index = 0;
+
+    while index < s.len() {
+        let remaining = &s[index..];
+        if !remaining.starts_with('%') {
+            let len = remaining.find('%').unwrap_or(remaining.len());
+            items.push(FormatItem::Literal(&remaining.as_bytes()[..len]));
+            index += len;
+            continue;
+        }
+
+        let specifier_index = index;
+        let mut specifier = &remaining[1..];
+        let padding = match specifier.as_bytes().first() {
+            Some(b'-') => Some(Padding::None),
+            Some(b'_') => Some(Padding::Space),
+            Some(b'0') => Some(Padding::Zero),
+            _ => None,
+        };
+        if padding.is_some() {
+            specifier = &specifier[1..];
+        }
+        let len = match specifier.chars().next() {
+            Some(':') => specifier[1..].chars().next().map(|c| c.len_utf8() + 1),
+            Some(c) => Some(c.len_utf8()),
+            None => None,
+        }
+        .ok_or(InvalidFormatDescription::MissingComponentName { index: s.len() })?;
+        specifier = &specifier[..len];
+        index = specifier_index + 1 + padding.map_or(0, |_| 1) + len;
+
+        let unsupported = || InvalidFormatDescription::UnsupportedSpecifier {
+            specifier: String::from(&s[specifier_index..index]),
+            index: specifier_index,
+        };
+        let expansion = expand(specifier).ok_or_else(unsupported)?;
+        match (padding, expansion) {
+            (None, _) => items.extend_from_slice(expansion),
+            (Some(padding), &[FormatItem::Component(component)]) => items.push(
+                FormatItem::Component(with_padding(component, padding).ok_or_else(unsupported)?),
+            ),
+            (Some(_), _) => return Err(unsupported()),
+        }
+    }
+
+    Ok(items)
+}
+
+/// Convert a `strftime`-style format string into an [`OwnedFormatItem`].
+///
+/// The specifiers are the same as [`parse_strftime`]. Unlike the result of that function, the
+/// returned value does not borrow from the input.
+///
+/// ```rust
+/// # use time::{format_description, macros::time};
+/// let description = format_description::parse_strftime_owned("%I:%M %p")?;
+/// assert_eq!(time!(13:05).format(&description)?, "01:05 PM");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_strftime_owned(s: &str) -> Result<OwnedFormatItem, InvalidFormatDescription> {
+    parse_strftime(s).map(Into::into)
+}
@@ -0,0 +1,82 @@
+//! The ASN.1 `UTCTime` and `GeneralizedTime` formats.
+
+/// The ASN.1 `UTCTime` format, as used in X.509 certificates.
+///
+/// Format example: 491231235959Z
+///
+/// The year has two digits. Following [RFC 5280](https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.5.1),
+/// years 50 through 99 are in the 1900s and years 00 through 49 are in the 2000s, so only the
+/// years 1950 through 2049 can be represented.
+///
+/// The `IS_DER` parameter selects the encoding rules used when parsing. The Distinguished Encoding
+/// Rules ([`UtcTime::DER`]) require the seconds and a `Z`. The Basic Encoding Rules
+/// ([`UtcTime::BER`]) also allow the seconds to be omitted and a `±hhmm` UTC offset in place of
+/// the `Z`. Formatting always produces the DER form, converting the value to UTC.
+///
+/// ```rust
+/// # use time::{format_description::well_known::UtcTime, macros::datetime, OffsetDateTime};
+/// assert_eq!(
+///     OffsetDateTime::parse("491231235959Z", &UtcTime::DER)?,
+///     datetime!(2049-12-31 23:59:59 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("5001010000+0100", &UtcTime::BER)?,
+///     datetime!(1950-01-01 0:00 +1)
+/// );
+/// assert_eq!(
+///     datetime!(2000-01-01 1:00 +1).format(&UtcTime::DER)?,
+///     "000101000000Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtcTime<const IS_DER: bool = true>;
+
+impl UtcTime {
+    /// `UTCTime` using the Distinguished Encoding Rules.
+    pub const DER: Self = Self;
+    /// `UTCTime` using the Basic Encoding Rules.
+    pub const BER: UtcTime<false> = UtcTime;
+}
+
+/// The ASN.1 `GeneralizedTime` format, as used in X.509 certificates.
+///
+/// Format example: 20491231235959.5Z
+///
+/// The year has four digits, and the seconds may have a fractional part.
+///
+/// The `IS_DER` parameter selects the encoding rules used when parsing. The Distinguished Encoding
+/// Rules ([`GeneralizedTime::DER`]) require the seconds and a `Z`, and the fractional part must
+/// use `.` and must not have trailing zeros. The Basic Encoding Rules ([`GeneralizedTime::BER`])
+/// also allow the minutes and seconds to be omitted, a fractional part on the last unit present
+/// using `.` or `,`, and a `±hh` or `±hhmm` UTC offset in place of the `Z`. Without either, the
+/// value is a local time, which can only be parsed into a
+/// [`PrimitiveDateTime`](crate::PrimitiveDateTime). Formatting always produces the DER form,
+/// converting the value to UTC.
+///
+/// ```rust
+/// # use time::{format_description::well_known::GeneralizedTime, macros::datetime};
+/// # use time::{OffsetDateTime, PrimitiveDateTime};
+/// assert_eq!(
+///     OffsetDateTime::parse("20491231235959.5Z", &GeneralizedTime::DER)?,
+///     datetime!(2049-12-31 23:59:59.5 UTC)
+/// );
+/// assert_eq!(
+///     PrimitiveDateTime::parse("2049123123,5", &GeneralizedTime::BER)?,
+///     datetime!(2049-12-31 23:30)
+/// );
+/// assert_eq!(
+///     datetime!(2050-01-01 0:00:00.25 +1).format(&GeneralizedTime::DER)?,
+///     "20491231230000.25Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneralizedTime<const IS_DER: bool = true>;
+
+impl GeneralizedTime {
+    /// `GeneralizedTime` using the Distinguished Encoding Rules.
+    pub const DER: Self = Self;
+    /// `GeneralizedTime` using the Basic Encoding Rules.
+    pub const BER: GeneralizedTime<false> = GeneralizedTime;
+}
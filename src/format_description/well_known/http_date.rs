@@ -0,0 +1,45 @@
+//! The format described in RFC 9110.
+
+/// The HTTP-date format described in
+/// [RFC 9110](https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7).
+///
+/// Example: Sun, 06 Nov 1994 08:49:37 GMT
+///
+/// Formatting always produces the preferred IMF-fixdate form, converting the value to UTC.
+/// Parsing additionally accepts the obsolete RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and
+/// asctime (`Sun Nov  6 08:49:37 1994`) forms. Unlike [`Rfc2822`](super::Rfc2822), no comments or
+/// folding whitespace are permitted, and all names are case-sensitive.
+///
+/// A two-digit year in the RFC 850 form is interpreted relative to the current year: a year
+/// that appears to be more than 50 years in the future is instead the most recent year in the
+/// past with the same last two digits. Without the `std` feature, the current date is not
+/// available, and two-digit years are instead interpreted as 1951 through 2050.
+///
+/// # Examples
+/// ```rust
+/// # use time::{format_description::well_known::HttpDate, macros::datetime, OffsetDateTime};
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// ```rust
+/// # use time::{format_description::well_known::HttpDate, macros::datetime};
+/// assert_eq!(
+///     datetime!(1994-11-06 09:49:37 +01:00).format(&HttpDate)?,
+///     "Sun, 06 Nov 1994 08:49:37 GMT"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpDate;
@@ -0,0 +1,371 @@
+//! The format described in ISO 8601.
+
+use core::num::NonZeroU8;
+
+/// The default configuration for [`Iso8601`].
+const DEFAULT_CONFIG: EncodedConfig = Config::DEFAULT.encode();
+
+/// A configuration for [`Iso8601`] that only formats the date.
+const DATE_CONFIG: EncodedConfig = Config::DEFAULT
+    .set_formatted_components(FormattedComponents::Date)
+    .encode();
+
+/// A configuration for [`Iso8601`] that only formats the time.
+const TIME_CONFIG: EncodedConfig = Config::DEFAULT
+    .set_formatted_components(FormattedComponents::Time)
+    .encode();
+
+/// A configuration for [`Iso8601`] that formats the date and time, but not the offset.
+const DATE_TIME_CONFIG: EncodedConfig = Config::DEFAULT
+    .set_formatted_components(FormattedComponents::DateTime)
+    .encode();
+
+/// The format described in [ISO 8601](https://www.iso.org/iso-8601-date-and-time-format.html).
+///
+/// The exact representation is controlled by the `CONFIG` parameter, which is a [`Config`] that
+/// has been [encoded](Config::encode). The default configuration uses extended notation, a calendar
+/// date, and the time with nanosecond precision.
+///
+/// When formatting, the value is output exactly as configured. When parsing, the configured
+/// components must be present, but any notation, kind of date, and precision is accepted. The
+/// notation must be consistent throughout the input.
+///
+/// Format example: 1985-04-12T23:20:50.520000000Z
+///
+/// ```rust
+/// # use time::{format_description::well_known::Iso8601, macros::datetime, OffsetDateTime};
+/// assert_eq!(
+///     OffsetDateTime::parse("1985-W15-5T23:20:50,52+01", &Iso8601::DEFAULT)?,
+///     datetime!(1985-04-12 23:20:50.52 +01:00)
+/// );
+/// assert_eq!(
+///     datetime!(1985-04-12 23:20:50.52 +00:00).format(&Iso8601::DEFAULT)?,
+///     "1985-04-12T23:20:50.520000000Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// ```rust
+/// # use time::{
+/// #     format_description::well_known::{iso8601::{Config, DateKind, TimePrecision}, Iso8601},
+/// #     macros::datetime,
+/// # };
+/// const CONFIG: time::format_description::well_known::iso8601::EncodedConfig = Config::DEFAULT
+///     .set_use_separators(false)
+///     .set_date_kind(DateKind::Ordinal)
+///     .set_time_precision(TimePrecision::Minute { decimal_digits: None })
+///     .encode();
+/// assert_eq!(
+///     datetime!(1985-04-12 23:20:50.52 -05:00).format(&Iso8601::<CONFIG>)?,
+///     "1985102T2320-0500"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iso8601<const CONFIG: EncodedConfig>;
+
+impl Iso8601<DEFAULT_CONFIG> {
+    /// An `Iso8601` with the default configuration.
+    ///
+    /// This formats the date, time, and offset.
+    pub const DEFAULT: Self = Self;
+
+    /// An `Iso8601` with the default configuration, except that only the date is formatted.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Iso8601, macros::date, Date};
+    /// assert_eq!(date!(2021-01-02).format(&Iso8601::DATE)?, "2021-01-02");
+    /// assert_eq!(Date::parse("2020-W53-6", &Iso8601::DATE)?, date!(2021-01-02));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const DATE: Iso8601<DATE_CONFIG> = Iso8601;
+
+    /// An `Iso8601` with the default configuration, except that only the time is formatted.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Iso8601, macros::time, Time};
+    /// assert_eq!(time!(12:30).format(&Iso8601::TIME)?, "12:30:00.000000000");
+    /// assert_eq!(Time::parse("12.5", &Iso8601::TIME)?, time!(12:30));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const TIME: Iso8601<TIME_CONFIG> = Iso8601;
+
+    /// An `Iso8601` with the default configuration, except that the offset is not formatted.
+    pub const DATE_TIME: Iso8601<DATE_TIME_CONFIG> = Iso8601;
+}
+
+impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
+    /// The configuration, decoded from the const parameter.
+    pub(crate) const CONFIG: Config = Config::decode(CONFIG);
+}
+
+/// A [`Config`] that has been encoded into a value usable as a const parameter of [`Iso8601`].
+///
+/// The value is opaque. It should only be obtained by calling [`Config::encode`].
+pub type EncodedConfig = u128;
+
+/// Which components of a value are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormattedComponents {
+    /// The date only.
+    Date,
+    /// The time only.
+    Time,
+    /// The UTC offset only.
+    Offset,
+    /// The date and time.
+    DateTime,
+    /// The date, time, and UTC offset.
+    DateTimeOffset,
+    /// The time and UTC offset.
+    TimeOffset,
+}
+
+/// Which kind of date is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateKind {
+    /// The year, month, and day: `2021-01-02`.
+    Calendar,
+    /// The ISO year, week, and weekday: `2020-W53-6`.
+    Week,
+    /// The year and day of the year: `2021-002`.
+    Ordinal,
+}
+
+/// The smallest unit of the time that is formatted.
+///
+/// The unit may be followed by a decimal fraction with the given number of digits, which must not
+/// be more than nine. The fraction is truncated, not rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimePrecision {
+    /// The hour: `12.5`.
+    Hour {
+        /// The number of digits in the fraction of the hour, if any.
+        decimal_digits: Option<NonZeroU8>,
+    },
+    /// The hour and minute: `12:30.25`.
+    Minute {
+        /// The number of digits in the fraction of the minute, if any.
+        decimal_digits: Option<NonZeroU8>,
+    },
+    /// The hour, minute, and second: `12:30:15.123`.
+    Second {
+        /// The number of digits in the fraction of the second, if any.
+        decimal_digits: Option<NonZeroU8>,
+    },
+}
+
+/// The smallest unit of the UTC offset that is formatted. An offset of zero is always formatted as
+/// `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetPrecision {
+    /// The hour: `+01`. Formatting an offset that is not a whole number of hours is an error.
+    Hour,
+    /// The hour and minute: `+01:30`.
+    Minute,
+}
+
+/// The configuration of an [`Iso8601`] format.
+///
+/// A configuration is built by calling the `set_*` methods on [`Config::DEFAULT`], then
+/// [encoded](Self::encode) to be used as the const parameter of [`Iso8601`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Which components are formatted.
+    pub(crate) formatted_components: FormattedComponents,
+    /// Whether the extended notation, with separators between the units, is used.
+    pub(crate) use_separators: bool,
+    /// Whether the year has six digits and a mandatory sign, rather than four digits.
+    pub(crate) year_is_six_digits: bool,
+    /// Which kind of date is formatted.
+    pub(crate) date_kind: DateKind,
+    /// The smallest unit of the time that is formatted.
+    pub(crate) time_precision: TimePrecision,
+    /// The smallest unit of the UTC offset that is formatted.
+    pub(crate) offset_precision: OffsetPrecision,
+}
+
+impl Config {
+    /// The default configuration.
+    ///
+    /// The date, time, and offset are formatted using the extended notation. The date is a
+    /// calendar date with a four-digit year, the time has nanosecond precision, and the offset has
+    /// minute precision.
+    pub const DEFAULT: Self = Self {
+        formatted_components: FormattedComponents::DateTimeOffset,
+        use_separators: true,
+        year_is_six_digits: false,
+        date_kind: DateKind::Calendar,
+        time_precision: TimePrecision::Second {
+            decimal_digits: NonZeroU8::new(9),
+        },
+        offset_precision: OffsetPrecision::Minute,
+    };
+
+    /// Set which components are formatted.
+    pub const fn set_formatted_components(self, formatted_components: FormattedComponents) -> Self {
+        Self {
+            formatted_components,
+            ..self
+        }
+    }
+
+    /// Set whether the extended notation (`2021-01-02T12:30`) is used rather than the basic
+    /// notation (`20210102T1230`).
+    pub const fn set_use_separators(self, use_separators: bool) -> Self {
+        Self {
+            use_separators,
+            ..self
+        }
+    }
+
+    /// Set whether the year is formatted with six digits and a mandatory sign (`+002021`) rather
+    /// than four digits. Years outside the range `0..=9999` can only be formatted with six digits.
+    pub const fn set_year_is_six_digits(self, year_is_six_digits: bool) -> Self {
+        Self {
+            year_is_six_digits,
+            ..self
+        }
+    }
+
+    /// Set which kind of date is formatted.
+    pub const fn set_date_kind(self, date_kind: DateKind) -> Self {
+        Self { date_kind, ..self }
+    }
+
+    /// Set the smallest unit of the time that is formatted.
+    pub const fn set_time_precision(self, time_precision: TimePrecision) -> Self {
+        Self {
+            time_precision,
+            ..self
+        }
+    }
+
+    /// Set the smallest unit of the UTC offset that is formatted.
+    pub const fn set_offset_precision(self, offset_precision: OffsetPrecision) -> Self {
+        Self {
+            offset_precision,
+            ..self
+        }
+    }
+
+    /// Encode the configuration for use as the const parameter of [`Iso8601`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the time precision has more than nine decimal digits. When the
+    /// configuration is encoded in a `const`, this is a compile-time error.
+    pub const fn encode(self) -> EncodedConfig {
+        let formatted_components = match self.formatted_components {
+            FormattedComponents::Date => 0,
+            FormattedComponents::Time => 1,
+            FormattedComponents::Offset => 2,
+            FormattedComponents::DateTime => 3,
+            FormattedComponents::DateTimeOffset => 4,
+            FormattedComponents::TimeOffset => 5,
+        };
+        let date_kind = match self.date_kind {
+            DateKind::Calendar => 0,
+            DateKind::Week => 1,
+            DateKind::Ordinal => 2,
+        };
+        let (time_precision, decimal_digits) = match self.time_precision {
+            TimePrecision::Hour { decimal_digits } => (0, decimal_digits),
+            TimePrecision::Minute { decimal_digits } => (1, decimal_digits),
+            TimePrecision::Second { decimal_digits } => (2, decimal_digits),
+        };
+        let decimal_digits = match decimal_digits {
+            Some(digits) => digits.get(),
+            None => 0,
+        };
+        // At most nine decimal digits are supported.
+        const_assert(decimal_digits <= 9);
+        let offset_precision = match self.offset_precision {
+            OffsetPrecision::Hour => 0,
+            OffsetPrecision::Minute => 1,
+        };
+
+        formatted_components
+            | (self.use_separators as EncodedConfig) << 3
+            | (self.year_is_six_digits as EncodedConfig) << 4
+            | date_kind << 5
+            | time_precision << 7
+            | (decimal_digits as EncodedConfig) << 9
+            | offset_precision << 13
+    }
+
+    /// Decode a configuration previously obtained from [`Config::encode`].
+    const fn decode(encoded: EncodedConfig) -> Self {
+        // Every value is checked up front, so the final arm of each `match` is the only value
+        // remaining.
+        const_assert(
+            encoded & 0b111 <= 5
+                && (encoded >> 5) & 0b11 <= 2
+                && (encoded >> 7) & 0b11 <= 2
+                && (encoded >> 9) & 0b1111 <= 9
+                && encoded >> 14 == 0,
+        );
+
+        let formatted_components = match encoded & 0b111 {
+            0 => FormattedComponents::Date,
+            1 => FormattedComponents::Time,
+            2 => FormattedComponents::Offset,
+            3 => FormattedComponents::DateTime,
+            4 => FormattedComponents::DateTimeOffset,
+            _ => FormattedComponents::TimeOffset,
+        };
+        let date_kind = match (encoded >> 5) & 0b11 {
+            0 => DateKind::Calendar,
+            1 => DateKind::Week,
+            _ => DateKind::Ordinal,
+        };
+        let decimal_digits = NonZeroU8::new(((encoded >> 9) & 0b1111) as _);
+        let time_precision = match (encoded >> 7) & 0b11 {
+            0 => TimePrecision::Hour { decimal_digits },
+            1 => TimePrecision::Minute { decimal_digits },
+            _ => TimePrecision::Second { decimal_digits },
+        };
+        let offset_precision = if (encoded >> 13) & 1 == 0 {
+            OffsetPrecision::Hour
+        } else {
+            OffsetPrecision::Minute
+        };
+
+        Self {
+            formatted_components,
+            use_separators: (encoded >> 3) & 1 == 1,
+            year_is_six_digits: (encoded >> 4) & 1 == 1,
+            date_kind,
+            time_precision,
+            offset_precision,
+        }
+    }
+}
+
+/// Panic if the condition does not hold.
+///
+/// `panic!` cannot be used in a `const fn` on the minimum supported Rust version, so indexing out
+/// of bounds is used instead. When evaluated in a `const`, this is a compile-time error.
+const fn const_assert(condition: bool) {
+    [()][!condition as usize]
+}
+
+impl FormattedComponents {
+    /// Whether the date is formatted.
+    pub(crate) const fn date_is_present(self) -> bool {
+        matches!(self, Self::Date | Self::DateTime | Self::DateTimeOffset)
+    }
+
+    /// Whether the time is formatted.
+    pub(crate) const fn time_is_present(self) -> bool {
+        matches!(
+            self,
+            Self::Time | Self::DateTime | Self::DateTimeOffset | Self::TimeOffset
+        )
+    }
+
+    /// Whether the UTC offset is formatted.
+    pub(crate) const fn offset_is_present(self) -> bool {
+        matches!(self, Self::Offset | Self::DateTimeOffset | Self::TimeOffset)
+    }
+}
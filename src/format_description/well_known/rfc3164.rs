@@ -0,0 +1,99 @@
+//! The format described in RFC 3164.
+
+#[cfg(feature = "parsing")]
+use core::convert::TryFrom;
+
+#[cfg(feature = "parsing")]
+use crate::error;
+#[cfg(feature = "parsing")]
+use crate::parsing::parsable::sealed::Sealed;
+#[cfg(feature = "parsing")]
+use crate::{OffsetDateTime, PrimitiveDateTime};
+
+/// The BSD syslog timestamp format described in
+/// [RFC 3164](https://datatracker.ietf.org/doc/html/rfc3164#section-4.1.2).
+///
+/// Format example: Oct 11 22:14:15
+///
+/// The day of the month is padded with a space rather than a zero. The timestamp includes neither
+/// the year nor the UTC offset, so it can only be parsed into a [`Time`](crate::Time) on its own.
+/// Use [`Rfc3164::parse_with_year`] to supply the year, or [`Rfc3164::parse_relative_to`] to infer
+/// it from a nearby date-time. When formatting, the year and offset are ignored.
+///
+/// ```rust
+/// # use time::{format_description::well_known::Rfc3164, macros::{datetime, time}, Time};
+/// assert_eq!(Time::parse("Oct  1 22:14:15", &Rfc3164)?, time!(22:14:15));
+/// assert_eq!(
+///     datetime!(2021-10-01 22:14:15).format(&Rfc3164)?,
+///     "Oct  1 22:14:15"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3164;
+
+#[cfg(feature = "parsing")]
+impl Rfc3164 {
+    /// Parse a timestamp, using the provided year.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc3164, macros::datetime};
+    /// assert_eq!(
+    ///     Rfc3164.parse_with_year("Oct 11 22:14:15", 2003)?,
+    ///     datetime!(2003-10-11 22:14:15)
+    /// );
+    /// assert!(Rfc3164.parse_with_year("Feb 29 00:00:00", 2021).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_year(
+        self,
+        input: &str,
+        year: i32,
+    ) -> Result<PrimitiveDateTime, error::Parse> {
+        let mut parsed = self.parse(input.as_bytes())?;
+        parsed.year = Some(year);
+        Ok(PrimitiveDateTime::try_from(parsed)?)
+    }
+
+    /// Parse a timestamp, inferring the year from a reference date-time, such as the time the
+    /// message was received.
+    ///
+    /// The year is chosen from the year of the reference and the years on either side of it, such
+    /// that the result is as close as possible to the reference. This handles messages that cross
+    /// the December/January boundary. The timestamp is assumed to have the same UTC offset as the
+    /// reference.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc3164, macros::datetime};
+    /// assert_eq!(
+    ///     Rfc3164.parse_relative_to("Dec 31 23:59:58", datetime!(2022-01-01 0:00:01 UTC))?,
+    ///     datetime!(2021-12-31 23:59:58 UTC)
+    /// );
+    /// assert_eq!(
+    ///     Rfc3164.parse_relative_to("Jan  1 00:00:01", datetime!(2021-12-31 23:59:58 -5))?,
+    ///     datetime!(2022-01-01 0:00:01 -5)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_relative_to(
+        self,
+        input: &str,
+        reference: OffsetDateTime,
+    ) -> Result<OffsetDateTime, error::Parse> {
+        let parsed = self.parse(input.as_bytes())?;
+        let with_year = |year| {
+            let mut parsed = parsed;
+            parsed.year = Some(year);
+            PrimitiveDateTime::try_from(parsed)
+                .map(|date_time| date_time.assume_offset(reference.offset()))
+        };
+
+        let year = reference.year();
+        [year - 1, year, year + 1]
+            .iter()
+            .filter_map(|&year| with_year(year).ok())
+            .min_by_key(|&date_time| (date_time - reference).abs())
+            // If no year results in a valid date, report the error for the year of the reference.
+            .map_or_else(|| Ok(with_year(year)?), Ok)
+    }
+}
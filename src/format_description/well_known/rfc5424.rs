@@ -0,0 +1,32 @@
+//! The format described in RFC 5424.
+
+/// The syslog timestamp format described in
+/// [RFC 5424](https://datatracker.ietf.org/doc/html/rfc5424#section-6.2.3).
+///
+/// Format example: 2003-10-11T22:14:15.003-07:00
+///
+/// This is a stricter profile of [RFC 3339](super::Rfc3339). The `T` and `Z` must be uppercase,
+/// and the fractional second has at most six digits. Formatting truncates the value to microsecond
+/// precision. The `-` used by syslog messages to indicate a missing timestamp is not accepted, as
+/// it does not describe any value.
+///
+/// ```rust
+/// # use time::{format_description::well_known::Rfc5424, macros::datetime, OffsetDateTime};
+/// assert_eq!(
+///     OffsetDateTime::parse("2003-10-11T22:14:15.003-07:00", &Rfc5424)?,
+///     datetime!(2003-10-11 22:14:15.003 -7)
+/// );
+/// assert!(OffsetDateTime::parse("2003-10-11t22:14:15.003z", &Rfc5424).is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// ```rust
+/// # use time::{format_description::well_known::Rfc5424, macros::datetime};
+/// assert_eq!(
+///     datetime!(2003-10-11 22:14:15.003_456_789 UTC).format(&Rfc5424)?,
+///     "2003-10-11T22:14:15.003456Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc5424;
@@ -0,0 +1,66 @@
+//! The format described in RFC 9557.
+
+/// The format described in [RFC 9557](https://www.rfc-editor.org/rfc/rfc9557), also known as the
+/// Internet Extended Date/Time Format (IXDTF).
+///
+/// Format example: 2022-07-08T00:14:07+01:00\[Europe/Paris\]\[u-ca=gregory\]
+///
+/// This is an [RFC 3339](super::Rfc3339) timestamp followed by any number of bracketed
+/// annotations. The first annotation may name a time zone, either by its identifier or as a UTC
+/// offset. Any further annotations are `key=value` pairs, of which only the calendar (`u-ca`) is
+/// recognized. An annotation prefixed with `!` is critical: parsing fails if it is not understood
+/// or if it is inconsistent with the timestamp.
+///
+/// This crate does not include a time zone database, so a critical time zone identifier is never
+/// considered inconsistent with the offset of the timestamp. A critical time zone given as a UTC
+/// offset must match the offset of the timestamp, unless the offset is `Z`, which indicates that
+/// the local offset is unknown.
+///
+/// When parsing an [`OffsetDateTime`](crate::OffsetDateTime), the annotations are validated and
+/// then discarded. Formatting produces an RFC 3339 timestamp without any annotations. Use
+/// [`AnnotatedDateTime`](crate::AnnotatedDateTime) to keep the annotations.
+///
+/// ```rust
+/// # use time::{format_description::well_known::Rfc9557, macros::datetime, OffsetDateTime};
+/// assert_eq!(
+///     OffsetDateTime::parse("2022-07-08T00:14:07+01:00[Europe/Paris][u-ca=gregory]", &Rfc9557)?,
+///     datetime!(2022-07-08 0:14:07 +1)
+/// );
+/// assert!(OffsetDateTime::parse("2022-07-08T00:14:07+01:00[!+02:00]", &Rfc9557).is_err());
+/// assert!(OffsetDateTime::parse("2022-07-08T00:14:07+01:00[!foo=bar]", &Rfc9557).is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc9557;
+
+/// Whether the provided bytes are a valid time zone identifier. Each `/`-separated part must begin
+/// with a letter, `.`, or `_` and may not be `.` or `..`.
+pub(crate) fn is_valid_time_zone_name(name: &[u8]) -> bool {
+    !name.is_empty()
+        && name.split(|&c| c == b'/').all(|part| {
+            matches!(part.first(), Some(c) if c.is_ascii_alphabetic() || matches!(c, b'.' | b'_'))
+                && part != b"."
+                && part != b".."
+                && part
+                    .iter()
+                    .all(|&c| c.is_ascii_alphanumeric() || matches!(c, b'.' | b'_' | b'-' | b'+'))
+        })
+}
+
+/// Whether the provided bytes are a valid annotation key. A key begins with a lowercase letter or
+/// `_`, followed by lowercase letters, digits, `_`, or `-`.
+#[cfg(feature = "parsing")]
+pub(crate) fn is_valid_annotation_key(key: &[u8]) -> bool {
+    matches!(key.first(), Some(c) if c.is_ascii_lowercase() || *c == b'_')
+        && key
+            .iter()
+            .all(|&c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, b'_' | b'-'))
+}
+
+/// Whether the provided bytes are a valid annotation value: one or more `-`-separated parts of
+/// alphanumeric characters.
+pub(crate) fn is_valid_annotation_value(value: &[u8]) -> bool {
+    value
+        .split(|&c| c == b'-')
+        .all(|part| !part.is_empty() && part.iter().all(u8::is_ascii_alphanumeric))
+}
@@ -0,0 +1,99 @@
+//! Helpers for implementing formatting for the ASN.1 `UTCTime` and `GeneralizedTime` formats.
+
+use core::ops::RangeInclusive;
+use std::io;
+
+use crate::formatting::{format_number_pad_zero, write};
+use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
+
+/// Convert the value to UTC, ensuring that the year is within the provided range.
+fn to_utc(
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+    years: RangeInclusive<i32>,
+) -> Result<OffsetDateTime, error::Format> {
+    let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+    let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+    let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+    // An offset changes the date by at most one day, so checking the year before the conversion
+    // ensures that the conversion cannot overflow.
+    if date.year() < years.start() - 1 || date.year() > years.end() + 1 {
+        return Err(error::Format::InvalidComponent("year"));
+    }
+    let date_time = date
+        .with_time(time)
+        .assume_offset(offset)
+        .to_offset(UtcOffset::UTC);
+    if !years.contains(&date_time.year()) {
+        return Err(error::Format::InvalidComponent("year"));
+    }
+
+    Ok(date_time)
+}
+
+/// Format the month, day, hour, minute, and second, which are common to both formats.
+fn format_month_to_second(
+    output: &mut impl io::Write,
+    date_time: OffsetDateTime,
+) -> Result<usize, io::Error> {
+    let mut bytes = 0;
+    bytes += format_number_pad_zero::<_, _, 2>(output, date_time.month() as u8)?;
+    bytes += format_number_pad_zero::<_, _, 2>(output, date_time.day())?;
+    bytes += format_number_pad_zero::<_, _, 2>(output, date_time.hour())?;
+    bytes += format_number_pad_zero::<_, _, 2>(output, date_time.minute())?;
+    bytes += format_number_pad_zero::<_, _, 2>(output, date_time.second())?;
+    Ok(bytes)
+}
+
+/// Format the `UTCTime` format using the Distinguished Encoding Rules.
+pub(super) fn format_utc_time(
+    output: &mut impl io::Write,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    let date_time = to_utc(date, time, offset, 1950..=2049)?;
+
+    let mut bytes = 0;
+    bytes += format_number_pad_zero::<_, _, 2>(output, (date_time.year() % 100) as u8)?;
+    bytes += format_month_to_second(output, date_time)?;
+    bytes += write(output, b"Z")?;
+    Ok(bytes)
+}
+
+/// Format the `GeneralizedTime` format using the Distinguished Encoding Rules.
+pub(super) fn format_generalized_time(
+    output: &mut impl io::Write,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    let date_time = to_utc(date, time, offset, 0..=9_999)?;
+
+    let mut bytes = 0;
+    bytes += format_number_pad_zero::<_, _, 4>(output, date_time.year() as u32)?;
+    bytes += format_month_to_second(output, date_time)?;
+
+    // DER requires that trailing zeros be omitted, along with the separator if nothing remains.
+    let nanosecond = date_time.nanosecond();
+    if nanosecond != 0 {
+        let mut digits = [b'0'; 9];
+        let mut remaining = nanosecond;
+        for digit in digits.iter_mut().rev() {
+            *digit = b'0' + (remaining % 10) as u8;
+            remaining /= 10;
+        }
+        let len = digits
+            .iter()
+            .rposition(|&digit| digit != b'0')
+            .map_or(0, |i| i + 1);
+
+        bytes += write(output, b".")?;
+        bytes += write(output, &digits[..len])?;
+    }
+
+    bytes += write(output, b"Z")?;
+    Ok(bytes)
+}
@@ -3,11 +3,18 @@
 use core::ops::Deref;
 use std::io;
 
-use crate::format_description::well_known::{Rfc2822, Rfc3339};
+use crate::format_description::well_known::iso8601::EncodedConfig;
+use crate::format_description::well_known::{
+    GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424, Rfc9557, UtcTime,
+};
 use crate::format_description::FormatItem;
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
 use crate::formatting::{
-    format_component, format_number_pad_zero, write, MONTH_NAMES, WEEKDAY_NAMES,
+    asn1, format_component, format_number_pad_space, format_number_pad_zero, iso8601, write,
+    MONTH_NAMES, WEEKDAY_NAMES,
 };
+use crate::locale::{English, Locale};
 use crate::{error, Date, Time, UtcOffset};
 
 /// A type that can be formatted.
@@ -15,12 +22,23 @@ use crate::{error, Date, Time, UtcOffset};
 pub trait Formattable: sealed::Sealed {}
 impl Formattable for FormatItem<'_> {}
 impl Formattable for [FormatItem<'_>] {}
+#[cfg(feature = "alloc")]
+impl Formattable for OwnedFormatItem {}
+#[cfg(feature = "alloc")]
+impl Formattable for [OwnedFormatItem] {}
+impl Formattable for Rfc3164 {}
 impl Formattable for Rfc3339 {}
+impl Formattable for Rfc5424 {}
 impl Formattable for Rfc2822 {}
+impl Formattable for HttpDate {}
+impl Formattable for Rfc9557 {}
+impl<const CONFIG: EncodedConfig> Formattable for Iso8601<CONFIG> {}
+impl<const IS_DER: bool> Formattable for UtcTime<IS_DER> {}
+impl<const IS_DER: bool> Formattable for GeneralizedTime<IS_DER> {}
 impl<T: Deref> Formattable for T where T::Target: Formattable {}
 
 /// Seal the trait to prevent downstream users from implementing it.
-mod sealed {
+pub(crate) mod sealed {
     #[allow(clippy::wildcard_imports)]
     use super::*;
 
@@ -36,6 +54,21 @@ mod sealed {
             offset: Option<UtcOffset>,
         ) -> Result<usize, error::Format>;
 
+        /// Format the item into the provided output using the names of the provided locale,
+        /// returning the number of bytes written.
+        ///
+        /// Only custom format descriptions are affected by the locale.
+        fn format_into_localized(
+            &self,
+            output: &mut impl io::Write,
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+            _locale: &dyn Locale,
+        ) -> Result<usize, error::Format> {
+            self.format_into(output, date, time, offset)
+        }
+
         /// Format the item directly to a `String`.
         fn format(
             &self,
@@ -58,15 +91,32 @@ impl<'a> sealed::Sealed for FormatItem<'a> {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
     ) -> Result<usize, error::Format> {
         Ok(match *self {
             Self::Literal(literal) => write(output, literal)?,
-            Self::Component(component) => format_component(output, component, date, time, offset)?,
-            Self::Compound(items) => items.format_into(output, date, time, offset)?,
-            Self::Optional(item) => item.format_into(output, date, time, offset)?,
+            Self::Component(component) => {
+                format_component(output, component, date, time, offset, locale)?
+            }
+            Self::Compound(items) => {
+                items.format_into_localized(output, date, time, offset, locale)?
+            }
+            Self::Optional(item) => {
+                item.format_into_localized(output, date, time, offset, locale)?
+            }
             Self::First(items) => match items {
                 [] => 0,
-                [item, ..] => item.format_into(output, date, time, offset)?,
+                [item, ..] => item.format_into_localized(output, date, time, offset, locale)?,
             },
         })
     }
@@ -79,10 +129,88 @@ impl<'a> sealed::Sealed for [FormatItem<'a>] {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, error::Format> {
+        let mut bytes = 0;
+        for item in self.iter() {
+            bytes += item.format_into_localized(output, date, time, offset, locale)?;
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for OwnedFormatItem {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, error::Format> {
+        Ok(match self {
+            Self::Literal(literal) => write(output, literal)?,
+            Self::Component(component) => {
+                format_component(output, *component, date, time, offset, locale)?
+            }
+            Self::Compound(items) => {
+                items.format_into_localized(output, date, time, offset, locale)?
+            }
+            Self::Optional(item) => {
+                item.format_into_localized(output, date, time, offset, locale)?
+            }
+            Self::First(items) => match &**items {
+                [] => 0,
+                [item, ..] => item.format_into_localized(output, date, time, offset, locale)?,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for [OwnedFormatItem] {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
     ) -> Result<usize, error::Format> {
         let mut bytes = 0;
         for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+            bytes += item.format_into_localized(output, date, time, offset, locale)?;
         }
         Ok(bytes)
     }
@@ -101,6 +229,18 @@ where
     ) -> Result<usize, error::Format> {
         self.deref().format_into(output, date, time, offset)
     }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, error::Format> {
+        self.deref()
+            .format_into_localized(output, date, time, offset, locale)
+    }
 }
 // endregion custom formats
 
@@ -154,6 +294,80 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
+impl sealed::Sealed for HttpDate {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        // Checking the year before converting to UTC ensures that the conversion cannot overflow.
+        if !(0..10_000).contains(&date.year()) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        let date_time = date
+            .with_time(time)
+            .assume_offset(offset)
+            .to_offset(UtcOffset::UTC);
+        let (year, month, day) = date_time.to_calendar_date();
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+
+        let mut bytes = 0;
+        bytes += write(
+            output,
+            &WEEKDAY_NAMES[date_time.weekday().number_days_from_monday() as usize][..3],
+        )?;
+        bytes += write(output, b", ")?;
+        bytes += format_number_pad_zero::<_, _, 2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += write(output, &MONTH_NAMES[month as usize - 1][..3])?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<_, _, 4>(output, year as u32)?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<_, _, 2>(output, date_time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<_, _, 2>(output, date_time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<_, _, 2>(output, date_time.second())?;
+        bytes += write(output, b" GMT")?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Rfc3164 {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        _: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let mut bytes = 0;
+        bytes += write(output, &MONTH_NAMES[date.month() as usize - 1][..3])?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_space::<_, _, 2>(output, date.day())?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<_, _, 2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<_, _, 2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<_, _, 2>(output, time.second())?;
+
+        Ok(bytes)
+    }
+}
+
 impl sealed::Sealed for Rfc3339 {
     fn format_into(
         &self,
@@ -235,4 +449,102 @@ impl sealed::Sealed for Rfc3339 {
         Ok(bytes)
     }
 }
+
+impl sealed::Sealed for Rfc5424 {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        // The fractional second is limited to six digits.
+        let time = time.map(|time| {
+            Time::__from_hms_nanos_unchecked(
+                time.hour(),
+                time.minute(),
+                time.second(),
+                time.nanosecond() / 1_000 * 1_000,
+            )
+        });
+        Rfc3339.format_into(output, date, time, offset)
+    }
+}
+
+impl sealed::Sealed for Rfc9557 {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        Rfc3339.format_into(output, date, time, offset)
+    }
+}
+
+impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let mut bytes = 0;
+
+        let formatted_components = Self::CONFIG.formatted_components;
+        let date = formatted_components
+            .date_is_present()
+            .then(|| date.ok_or(error::Format::InsufficientTypeInformation))
+            .transpose()?;
+        let time = formatted_components
+            .time_is_present()
+            .then(|| time.ok_or(error::Format::InsufficientTypeInformation))
+            .transpose()?;
+        let offset = formatted_components
+            .offset_is_present()
+            .then(|| offset.ok_or(error::Format::InsufficientTypeInformation))
+            .transpose()?;
+
+        if let Some(date) = date {
+            bytes += iso8601::format_date::<CONFIG>(output, date)?;
+            if time.is_some() {
+                bytes += write(output, b"T")?;
+            }
+        }
+        if let Some(time) = time {
+            bytes += iso8601::format_time::<CONFIG>(output, time)?;
+        }
+        if let Some(offset) = offset {
+            bytes += iso8601::format_offset::<CONFIG>(output, offset)?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl<const IS_DER: bool> sealed::Sealed for UtcTime<IS_DER> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        asn1::format_utc_time(output, date, time, offset)
+    }
+}
+
+impl<const IS_DER: bool> sealed::Sealed for GeneralizedTime<IS_DER> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        asn1::format_generalized_time(output, date, time, offset)
+    }
+}
 // endregion well-known formats
@@ -0,0 +1,259 @@
+//! Helpers for implementing formatting for ISO 8601.
+
+use std::io;
+
+use crate::format_description::well_known::iso8601::{
+    DateKind, EncodedConfig, OffsetPrecision, TimePrecision,
+};
+use crate::format_description::well_known::Iso8601;
+use crate::formatting::{format_number_pad_zero, write};
+use crate::{error, Date, Duration, Time, UtcOffset};
+
+/// Format the date portion of ISO 8601.
+pub(super) fn format_date<const CONFIG: EncodedConfig>(
+    output: &mut impl io::Write,
+    date: Date,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+
+    match Iso8601::<CONFIG>::CONFIG.date_kind {
+        DateKind::Calendar => {
+            let (year, month, day) = date.to_calendar_date();
+            bytes += format_year::<CONFIG>(output, year)?;
+            if Iso8601::<CONFIG>::CONFIG.use_separators {
+                bytes += write(output, b"-")?;
+            }
+            bytes += format_number_pad_zero::<_, _, 2>(output, month as u8)?;
+            if Iso8601::<CONFIG>::CONFIG.use_separators {
+                bytes += write(output, b"-")?;
+            }
+            bytes += format_number_pad_zero::<_, _, 2>(output, day)?;
+        }
+        DateKind::Week => {
+            let (year, week, weekday) = date.to_iso_week_date();
+            bytes += format_year::<CONFIG>(output, year)?;
+            bytes += write(
+                output,
+                if Iso8601::<CONFIG>::CONFIG.use_separators {
+                    &b"-W"[..]
+                } else {
+                    &b"W"[..]
+                },
+            )?;
+            bytes += format_number_pad_zero::<_, _, 2>(output, week)?;
+            if Iso8601::<CONFIG>::CONFIG.use_separators {
+                bytes += write(output, b"-")?;
+            }
+            bytes += format_number_pad_zero::<_, _, 1>(output, weekday.number_from_monday())?;
+        }
+        DateKind::Ordinal => {
+            let (year, day) = date.to_ordinal_date();
+            bytes += format_year::<CONFIG>(output, year)?;
+            if Iso8601::<CONFIG>::CONFIG.use_separators {
+                bytes += write(output, b"-")?;
+            }
+            bytes += format_number_pad_zero::<_, _, 3>(output, day)?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Format the year, which may be the calendar year or the ISO week-numbering year.
+fn format_year<const CONFIG: EncodedConfig>(
+    output: &mut impl io::Write,
+    year: i32,
+) -> Result<usize, error::Format> {
+    if Iso8601::<CONFIG>::CONFIG.year_is_six_digits {
+        let mut bytes = write(output, if year < 0 { b"-" } else { b"+" })?;
+        bytes += format_number_pad_zero::<_, _, 6>(output, year.unsigned_abs())?;
+        Ok(bytes)
+    } else if !(0..=9_999).contains(&year) {
+        Err(error::Format::InvalidComponent("year"))
+    } else {
+        Ok(format_number_pad_zero::<_, _, 4>(output, year as u32)?)
+    }
+}
+
+/// Format the time portion of ISO 8601.
+///
+/// The leading `T` is not written, as it is only needed when the time follows a date.
+pub(super) fn format_time<const CONFIG: EncodedConfig>(
+    output: &mut impl io::Write,
+    time: Time,
+) -> Result<usize, error::Format> {
+    /// The number of nanoseconds in one minute.
+    const NANOS_PER_MINUTE: u64 = 60_000_000_000;
+    /// The number of nanoseconds in one hour.
+    const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MINUTE;
+
+    let mut bytes = 0;
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    let nanos_past_minute = second as u64 * 1_000_000_000 + nanosecond as u64;
+
+    bytes += format_number_pad_zero::<_, _, 2>(output, hour)?;
+    let (decimal_digits, fraction_numerator, fraction_denominator) =
+        match Iso8601::<CONFIG>::CONFIG.time_precision {
+            TimePrecision::Hour { decimal_digits } => (
+                decimal_digits,
+                minute as u64 * NANOS_PER_MINUTE + nanos_past_minute,
+                NANOS_PER_HOUR,
+            ),
+            TimePrecision::Minute { decimal_digits } => {
+                if Iso8601::<CONFIG>::CONFIG.use_separators {
+                    bytes += write(output, b":")?;
+                }
+                bytes += format_number_pad_zero::<_, _, 2>(output, minute)?;
+                (decimal_digits, nanos_past_minute, NANOS_PER_MINUTE)
+            }
+            TimePrecision::Second { decimal_digits } => {
+                if Iso8601::<CONFIG>::CONFIG.use_separators {
+                    bytes += write(output, b":")?;
+                }
+                bytes += format_number_pad_zero::<_, _, 2>(output, minute)?;
+                if Iso8601::<CONFIG>::CONFIG.use_separators {
+                    bytes += write(output, b":")?;
+                }
+                bytes += format_number_pad_zero::<_, _, 2>(output, second)?;
+                (decimal_digits, nanosecond as u64, 1_000_000_000)
+            }
+        };
+
+    if let Some(decimal_digits) = decimal_digits {
+        let decimal_digits = decimal_digits.get();
+        let scale = 10_u64.pow(decimal_digits as _);
+        // A leap second carries into the next minute, which cannot be represented as a fraction.
+        let fraction = (fraction_numerator as u128 * scale as u128 / fraction_denominator as u128)
+            .min(scale as u128 - 1) as u32;
+
+        let mut digits = [b'0'; 9];
+        let mut remaining = fraction;
+        for digit in digits[..decimal_digits as usize].iter_mut().rev() {
+            *digit = b'0' + (remaining % 10) as u8;
+            remaining /= 10;
+        }
+
+        bytes += write(output, b".")?;
+        bytes += write(output, &digits[..decimal_digits as usize])?;
+    }
+
+    Ok(bytes)
+}
+
+/// Format the UTC offset portion of ISO 8601.
+pub(super) fn format_offset<const CONFIG: EncodedConfig>(
+    output: &mut impl io::Write,
+    offset: UtcOffset,
+) -> Result<usize, error::Format> {
+    if offset == UtcOffset::UTC {
+        return Ok(write(output, b"Z")?);
+    }
+    if offset.seconds_past_minute() != 0 {
+        return Err(error::Format::InvalidComponent("offset_second"));
+    }
+
+    let mut bytes = 0;
+    bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
+    bytes += format_number_pad_zero::<_, _, 2>(output, offset.whole_hours().unsigned_abs())?;
+
+    match Iso8601::<CONFIG>::CONFIG.offset_precision {
+        OffsetPrecision::Hour if offset.minutes_past_hour() != 0 => {
+            return Err(error::Format::InvalidComponent("offset_minute"));
+        }
+        OffsetPrecision::Hour => {}
+        OffsetPrecision::Minute => {
+            if Iso8601::<CONFIG>::CONFIG.use_separators {
+                bytes += write(output, b":")?;
+            }
+            bytes += format_number_pad_zero::<_, _, 2>(
+                output,
+                offset.minutes_past_hour().unsigned_abs(),
+            )?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Format a [`Duration`] using the ISO 8601 duration format.
+///
+/// The duration is formatted in days, hours, minutes, and seconds, as the length of a year or month
+/// is not fixed. Durations that are a whole number of weeks are formatted in weeks.
+pub(crate) fn format_duration(
+    output: &mut impl io::Write,
+    duration: Duration,
+) -> Result<usize, io::Error> {
+    /// The number of seconds in one week.
+    const SECONDS_PER_WEEK: u64 = 604_800;
+    /// The number of seconds in one day.
+    const SECONDS_PER_DAY: u64 = 86_400;
+
+    if duration.is_zero() {
+        return write(output, b"PT0S");
+    }
+
+    let mut bytes = 0;
+    if duration.is_negative() {
+        bytes += write(output, b"-")?;
+    }
+    bytes += write(output, b"P")?;
+
+    let seconds = duration.whole_seconds().unsigned_abs();
+    let nanoseconds = duration.subsec_nanoseconds().unsigned_abs();
+
+    let weeks = seconds / SECONDS_PER_WEEK;
+    if nanoseconds == 0 && weeks * SECONDS_PER_WEEK == seconds {
+        bytes += write_integer(output, weeks)?;
+        bytes += write(output, b"W")?;
+        return Ok(bytes);
+    }
+
+    let days = seconds / SECONDS_PER_DAY;
+    let hours = seconds % SECONDS_PER_DAY / 3_600;
+    let minutes = seconds % 3_600 / 60;
+    let seconds = seconds % 60;
+
+    if days != 0 {
+        bytes += write_integer(output, days)?;
+        bytes += write(output, b"D")?;
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {
+        return Ok(bytes);
+    }
+
+    bytes += write(output, b"T")?;
+    if hours != 0 {
+        bytes += write_integer(output, hours)?;
+        bytes += write(output, b"H")?;
+    }
+    if minutes != 0 {
+        bytes += write_integer(output, minutes)?;
+        bytes += write(output, b"M")?;
+    }
+    if seconds != 0 || nanoseconds != 0 {
+        bytes += write_integer(output, seconds)?;
+        if nanoseconds != 0 {
+            let mut digits = [b'0'; 9];
+            let mut remaining = nanoseconds;
+            for digit in digits.iter_mut().rev() {
+                *digit = b'0' + (remaining % 10) as u8;
+                remaining /= 10;
+            }
+            let len = digits
+                .iter()
+                .rposition(|&digit| digit != b'0')
+                .map_or(0, |i| i + 1);
+
+            bytes += write(output, b".")?;
+            bytes += write(output, &digits[..len])?;
+        }
+        bytes += write(output, b"S")?;
+    }
+
+    Ok(bytes)
+}
+
+/// Write an integer of any width, without padding.
+fn write_integer(output: &mut impl io::Write, value: u64) -> Result<usize, io::Error> {
+    write(output, itoa::Buffer::new().format(value).as_bytes())
+}
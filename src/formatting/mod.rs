@@ -1,12 +1,15 @@
 //! Formatting for various types.
 
+mod asn1;
 pub(crate) mod formattable;
+pub(crate) mod iso8601;
 
 use std::io;
 
 pub use self::formattable::Formattable;
 use crate::format_description::{modifier, Component};
-use crate::{error, Date, Time, UtcOffset};
+use crate::locale::Locale;
+use crate::{error, Date, PrimitiveDateTime, Time, UtcOffset};
 
 #[allow(clippy::missing_docs_in_private_items)]
 const MONTH_NAMES: [&[u8]; 12] = [
@@ -116,7 +119,7 @@ impl DigitCount for u32 {
 // endregion extension trait
 
 /// Write all bytes to the output, returning the number of bytes written.
-fn write(output: &mut impl io::Write, bytes: &[u8]) -> io::Result<usize> {
+pub(crate) fn write(output: &mut impl io::Write, bytes: &[u8]) -> io::Result<usize> {
     output.write_all(bytes)?;
     Ok(bytes.len())
 }
@@ -183,23 +186,29 @@ pub(crate) fn format_component(
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
+    locale: &dyn Locale,
 ) -> Result<usize, error::Format> {
     use Component::*;
     Ok(match (component, date, time, offset) {
-        (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
-        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier)?,
+        (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier, locale)?,
+        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier, locale)?,
         (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
-        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
+        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier, locale)?,
         (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
         (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
         (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
-        (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier)?,
+        (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier, locale)?,
         (Second(modifier), _, Some(time), _) => fmt_second(output, time, modifier)?,
         (Subsecond(modifier), _, Some(time), _) => fmt_subsecond(output, time, modifier)?,
         (OffsetHour(modifier), .., Some(offset)) => fmt_offset_hour(output, offset, modifier)?,
         (OffsetMinute(modifier), .., Some(offset)) => fmt_offset_minute(output, offset, modifier)?,
         (OffsetSecond(modifier), .., Some(offset)) => fmt_offset_second(output, offset, modifier)?,
+        (UnixTimestamp(modifier), Some(date), Some(time), Some(offset)) => {
+            fmt_unix_timestamp(output, date, time, offset, modifier)?
+        }
+        (Ignore(_), ..) => return Err(error::Format::InvalidComponent("ignore")),
+        (End(_), ..) => 0,
         _ => return Err(error::Format::InsufficientTypeInformation),
     })
 }
@@ -209,9 +218,17 @@ pub(crate) fn format_component(
 fn fmt_day(
     output: &mut impl io::Write,
     date: Date,
-    modifier::Day { padding }: modifier::Day,
+    modifier::Day {
+        padding,
+        ordinal_suffix,
+    }: modifier::Day,
+    locale: &dyn Locale,
 ) -> Result<usize, io::Error> {
-    format_number::<_, _, 2>(output, date.day(), padding)
+    let mut bytes = format_number::<_, _, 2>(output, date.day(), padding)?;
+    if ordinal_suffix {
+        bytes += write(output, locale.ordinal_suffix(date.day()).as_bytes())?;
+    }
+    Ok(bytes)
 }
 
 /// Format the month into the designated output.
@@ -223,13 +240,16 @@ fn fmt_month(
         repr,
         case_sensitive: _, // no effect on formatting
     }: modifier::Month,
+    locale: &dyn Locale,
 ) -> Result<usize, io::Error> {
     match repr {
         modifier::MonthRepr::Numerical => {
             format_number::<_, _, 2>(output, date.month() as u8, padding)
         }
-        modifier::MonthRepr::Long => write(output, MONTH_NAMES[date.month() as usize - 1]),
-        modifier::MonthRepr::Short => write(output, &MONTH_NAMES[date.month() as usize - 1][..3]),
+        modifier::MonthRepr::Long => write(output, locale.month_name(date.month()).as_bytes()),
+        modifier::MonthRepr::Short => {
+            write(output, locale.month_abbreviation(date.month()).as_bytes())
+        }
     }
 }
 
@@ -251,16 +271,16 @@ fn fmt_weekday(
         one_indexed,
         case_sensitive: _, // no effect on formatting
     }: modifier::Weekday,
+    locale: &dyn Locale,
 ) -> Result<usize, io::Error> {
     match repr {
         modifier::WeekdayRepr::Short => write(
             output,
-            &WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3],
-        ),
-        modifier::WeekdayRepr::Long => write(
-            output,
-            WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize],
+            locale.weekday_abbreviation(date.weekday()).as_bytes(),
         ),
+        modifier::WeekdayRepr::Long => {
+            write(output, locale.weekday_name(date.weekday()).as_bytes())
+        }
         modifier::WeekdayRepr::Sunday => format_number::<_, _, 1>(
             output,
             date.weekday().number_days_from_sunday() + one_indexed as u8,
@@ -368,13 +388,14 @@ fn fmt_period(
         is_uppercase,
         case_sensitive: _, // no effect on formatting
     }: modifier::Period,
+    locale: &dyn Locale,
 ) -> Result<usize, io::Error> {
-    match (time.hour() >= 12, is_uppercase) {
-        (false, false) => write(output, b"am"),
-        (false, true) => write(output, b"AM"),
-        (true, false) => write(output, b"pm"),
-        (true, true) => write(output, b"PM"),
-    }
+    write(
+        output,
+        locale
+            .period_name(time.hour() >= 12, is_uppercase)
+            .as_bytes(),
+    )
 }
 
 /// Format the second into the designated output.
@@ -455,3 +476,42 @@ fn fmt_offset_second(
     format_number::<_, _, 2>(output, offset.seconds_past_minute().unsigned_abs(), padding)
 }
 // endregion offset formatters
+
+// region: timestamp formatters
+/// Format the Unix timestamp into the designated output.
+///
+/// Values are rounded towards negative infinity, so that the subsecond of the value can be
+/// appended to a timestamp in seconds.
+fn fmt_unix_timestamp(
+    output: &mut impl io::Write,
+    date: Date,
+    time: Time,
+    offset: UtcOffset,
+    modifier::UnixTimestamp {
+        precision,
+        sign_is_mandatory,
+    }: modifier::UnixTimestamp,
+) -> Result<usize, io::Error> {
+    let nanos = PrimitiveDateTime::new(date, time)
+        .assume_offset(offset)
+        .unix_timestamp_nanos();
+    let value = match precision {
+        modifier::UnixTimestampPrecision::Second => nanos.div_euclid(1_000_000_000),
+        modifier::UnixTimestampPrecision::Millisecond => nanos.div_euclid(1_000_000),
+        modifier::UnixTimestampPrecision::Microsecond => nanos.div_euclid(1_000),
+        modifier::UnixTimestampPrecision::Nanosecond => nanos,
+    };
+
+    let mut bytes = 0;
+    if value < 0 {
+        bytes += write(output, b"-")?;
+    } else if sign_is_mandatory {
+        bytes += write(output, b"+")?;
+    }
+    bytes += write(
+        output,
+        itoa::Buffer::new().format(value.unsigned_abs()).as_bytes(),
+    )?;
+    Ok(bytes)
+}
+// endregion timestamp formatters
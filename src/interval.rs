@@ -0,0 +1,444 @@
+//! Time intervals and repeating intervals, as described in ISO 8601.
+//!
+//! An interval is written as two parts separated by a solidus (`/`). Each part is either a
+//! date-time or a [duration](Duration::parse_iso8601), giving four forms:
+//!
+//! - `2021-01-01T00:00Z/2021-01-02T00:00Z`: a start and an end
+//! - `2021-01-01T00:00Z/P1D`: a start and a duration
+//! - `P1D/2021-01-02T00:00Z`: a duration and an end
+//! - `P1D`: a duration alone, without any bounds
+//!
+//! A repeating interval prefixes any of these with the number of repetitions, such as
+//! `R5/2021-01-01T00:00Z/PT1H`. The number may be omitted to repeat without limit.
+//!
+//! Date-times are parsed using the [ISO 8601](crate::format_description::well_known::Iso8601)
+//! format and must have a UTC offset. They are formatted using the
+//! [RFC 3339](crate::format_description::well_known::Rfc3339) profile of ISO 8601.
+
+use core::convert::TryFrom;
+use core::ops::Range;
+#[cfg(feature = "formatting")]
+use std::io;
+
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::error;
+#[cfg(feature = "parsing")]
+use crate::format_description::well_known::Iso8601;
+#[cfg(feature = "formatting")]
+use crate::format_description::well_known::Rfc3339;
+#[cfg(feature = "formatting")]
+use crate::formatting::{iso8601::format_duration, write};
+use crate::{Duration, OffsetDateTime};
+
+/// A time interval in any of the four forms described in ISO 8601.
+///
+/// Parsing rejects intervals whose duration is negative. As the variants can be constructed
+/// directly, this is not otherwise enforced. The bounds of such an interval are computed in the
+/// same way, so its end precedes its start.
+///
+/// ```rust
+/// # use time::{ext::NumericalDuration, interval::Interval, macros::datetime};
+/// let interval = Interval::parse("2021-01-01T00:00Z/P1D")?;
+/// assert_eq!(interval.start(), Some(datetime!(2021-01-01 0:00 UTC)));
+/// assert_eq!(interval.end(), Some(datetime!(2021-01-02 0:00 UTC)));
+/// assert_eq!(interval.duration(), 1.days());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    /// An interval given by its start and end: `2021-01-01T00:00Z/2021-01-02T00:00Z`.
+    StartEnd {
+        /// The start of the interval.
+        start: OffsetDateTime,
+        /// The end of the interval.
+        end: OffsetDateTime,
+    },
+    /// An interval given by its start and duration: `2021-01-01T00:00Z/P1D`.
+    StartDuration {
+        /// The start of the interval.
+        start: OffsetDateTime,
+        /// The duration of the interval.
+        duration: Duration,
+    },
+    /// An interval given by its duration and end: `P1D/2021-01-02T00:00Z`.
+    DurationEnd {
+        /// The duration of the interval.
+        duration: Duration,
+        /// The end of the interval.
+        end: OffsetDateTime,
+    },
+    /// An interval given only by its duration: `P1D`.
+    Duration(Duration),
+}
+
+impl Interval {
+    /// Get the start of the interval, if it is known.
+    ///
+    /// ```rust
+    /// # use time::{interval::Interval, macros::datetime};
+    /// assert_eq!(
+    ///     Interval::parse("P1D/2021-01-02T00:00Z")?.start(),
+    ///     Some(datetime!(2021-01-01 0:00 UTC))
+    /// );
+    /// assert_eq!(Interval::parse("P1D")?.start(), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn start(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::StartEnd { start, .. } | Self::StartDuration { start, .. } => Some(start),
+            Self::DurationEnd { duration, end } => end.checked_sub(duration),
+            Self::Duration(_) => None,
+        }
+    }
+
+    /// Get the end of the interval, if it is known.
+    ///
+    /// ```rust
+    /// # use time::{interval::Interval, macros::datetime};
+    /// assert_eq!(
+    ///     Interval::parse("2021-01-01T00:00Z/P1D")?.end(),
+    ///     Some(datetime!(2021-01-02 0:00 UTC))
+    /// );
+    /// assert_eq!(Interval::parse("P1D")?.end(), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn end(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::StartEnd { end, .. } | Self::DurationEnd { end, .. } => Some(end),
+            Self::StartDuration { start, duration } => start.checked_add(duration),
+            Self::Duration(_) => None,
+        }
+    }
+
+    /// Get the duration of the interval.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, interval::Interval};
+    /// assert_eq!(
+    ///     Interval::parse("2021-01-01T00:00Z/2021-01-01T12:00+01:00")?.duration(),
+    ///     11.hours()
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn duration(self) -> Duration {
+        match self {
+            Self::StartEnd { start, end } => end - start,
+            Self::StartDuration { duration, .. }
+            | Self::DurationEnd { duration, .. }
+            | Self::Duration(duration) => duration,
+        }
+    }
+
+    /// Get the interval shifted forward by `count` times its duration, or `None` if the bounds
+    /// would overflow.
+    fn shifted_forward(self, count: u32) -> Option<Range<OffsetDateTime>> {
+        let start = self
+            .start()?
+            .checked_add(self.duration().checked_mul(i32::try_from(count).ok()?)?)?;
+        Some(start..start.checked_add(self.duration())?)
+    }
+
+    /// Get the interval shifted backward by `count` times its duration, or `None` if the bounds
+    /// would overflow.
+    fn shifted_backward(self, count: u32) -> Option<Range<OffsetDateTime>> {
+        let end = self
+            .end()?
+            .checked_sub(self.duration().checked_mul(i32::try_from(count).ok()?)?)?;
+        Some(end.checked_sub(self.duration())?..end)
+    }
+}
+
+/// A repeating time interval, as described in ISO 8601.
+///
+/// ```rust
+/// # use time::{interval::RepeatingInterval, macros::datetime};
+/// let interval = RepeatingInterval::parse("R3/2021-01-01T00:00Z/PT1H")?;
+/// assert_eq!(interval.repetitions(), Some(3));
+/// assert_eq!(
+///     interval.iter().map(|range| range.start).collect::<Vec<_>>(),
+///     [
+///         datetime!(2021-01-01 0:00 UTC),
+///         datetime!(2021-01-01 1:00 UTC),
+///         datetime!(2021-01-01 2:00 UTC),
+///     ]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RepeatingInterval {
+    /// The number of repetitions, or `None` if the interval repeats without limit.
+    repetitions: Option<u32>,
+    /// The interval that is repeated.
+    interval: Interval,
+}
+
+impl RepeatingInterval {
+    /// Create a repeating interval. If `repetitions` is `None`, the interval repeats without
+    /// limit.
+    pub const fn new(repetitions: Option<u32>, interval: Interval) -> Self {
+        Self {
+            repetitions,
+            interval,
+        }
+    }
+
+    /// Get the number of repetitions, or `None` if the interval repeats without limit.
+    pub const fn repetitions(self) -> Option<u32> {
+        self.repetitions
+    }
+
+    /// Get the interval that is repeated.
+    pub const fn interval(self) -> Interval {
+        self.interval
+    }
+
+    /// Iterate over the repetitions of the interval, yielding the bounds of each.
+    ///
+    /// If the interval has a start, the repetitions proceed forward from it. If it only has an end,
+    /// they proceed backward from it, so the first item yielded is the last in time. An interval
+    /// given only by its duration has no bounds, so nothing is yielded. Iteration stops early if
+    /// the bounds would overflow.
+    ///
+    /// ```rust
+    /// # use time::{interval::RepeatingInterval, macros::datetime};
+    /// let interval = RepeatingInterval::parse("R/P1D/2021-01-10T00:00Z")?;
+    /// assert_eq!(
+    ///     interval.iter().nth(1),
+    ///     Some(datetime!(2021-01-08 0:00 UTC)..datetime!(2021-01-09 0:00 UTC))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn iter(self) -> Repetitions {
+        Repetitions {
+            repeating_interval: self,
+            count: 0,
+        }
+    }
+}
+
+impl IntoIterator for RepeatingInterval {
+    type IntoIter = Repetitions;
+    type Item = Range<OffsetDateTime>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the repetitions of a [`RepeatingInterval`].
+///
+/// This struct is created by the [`RepeatingInterval::iter`] method.
+#[derive(Debug, Clone)]
+pub struct Repetitions {
+    /// The interval being iterated over.
+    repeating_interval: RepeatingInterval,
+    /// The number of repetitions that have been yielded.
+    count: u32,
+}
+
+impl Iterator for Repetitions {
+    type Item = Range<OffsetDateTime>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(repetitions) = self.repeating_interval.repetitions {
+            if self.count >= repetitions {
+                return None;
+            }
+        }
+
+        let interval = self.repeating_interval.interval;
+        let range = match interval {
+            Interval::StartEnd { .. } | Interval::StartDuration { .. } => {
+                interval.shifted_forward(self.count)
+            }
+            Interval::DurationEnd { .. } => interval.shifted_backward(self.count),
+            Interval::Duration(_) => None,
+        };
+
+        match range {
+            Some(range) => {
+                self.count += 1;
+                Some(range)
+            }
+            None => {
+                // Ensure the iterator is fused.
+                self.repeating_interval.repetitions = Some(self.count);
+                None
+            }
+        }
+    }
+}
+
+// region: formatting & parsing
+#[cfg(feature = "formatting")]
+impl Interval {
+    /// Format the interval in the form it was created with.
+    pub fn format_into(self, output: &mut impl io::Write) -> Result<usize, error::Format> {
+        Ok(match self {
+            Self::StartEnd { start, end } => {
+                start.format_into(output, &Rfc3339)?
+                    + write(output, b"/")?
+                    + end.format_into(output, &Rfc3339)?
+            }
+            Self::StartDuration { start, duration } => {
+                start.format_into(output, &Rfc3339)?
+                    + write(output, b"/")?
+                    + format_duration(output, duration)?
+            }
+            Self::DurationEnd { duration, end } => {
+                format_duration(output, duration)?
+                    + write(output, b"/")?
+                    + end.format_into(output, &Rfc3339)?
+            }
+            Self::Duration(duration) => format_duration(output, duration)?,
+        })
+    }
+
+    /// Format the interval in the form it was created with.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, interval::Interval, macros::datetime};
+    /// let interval = Interval::StartDuration {
+    ///     start: datetime!(2021-01-01 0:00 UTC),
+    ///     duration: 1.days(),
+    /// };
+    /// assert_eq!(interval.format()?, "2021-01-01T00:00:00Z/P1D");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format(self) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        self.format_into(&mut buf)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+
+#[cfg(feature = "formatting")]
+impl RepeatingInterval {
+    /// Format the repeating interval, such as `R5/2021-01-01T00:00:00Z/PT1H`.
+    pub fn format_into(self, output: &mut impl io::Write) -> Result<usize, error::Format> {
+        let mut bytes = write(output, b"R")?;
+        if let Some(repetitions) = self.repetitions {
+            bytes += write(output, itoa::Buffer::new().format(repetitions).as_bytes())?;
+        }
+        bytes += write(output, b"/")?;
+        bytes += self.interval.format_into(output)?;
+        Ok(bytes)
+    }
+
+    /// Format the repeating interval, such as `R5/2021-01-01T00:00:00Z/PT1H`.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, interval::{Interval, RepeatingInterval}};
+    /// let interval = RepeatingInterval::new(None, Interval::Duration(90.minutes()));
+    /// assert_eq!(interval.format()?, "R/PT1H30M");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format(self) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        self.format_into(&mut buf)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Interval {
+    /// Parse an interval in any of the four forms described in ISO 8601.
+    ///
+    /// Durations must not be negative, and the end must not be before the start.
+    ///
+    /// ```rust
+    /// # use time::{interval::Interval, macros::datetime};
+    /// assert_eq!(
+    ///     Interval::parse("2021-01-01T00:00Z/2021-01-02T00:00Z")?,
+    ///     Interval::StartEnd {
+    ///         start: datetime!(2021-01-01 0:00 UTC),
+    ///         end: datetime!(2021-01-02 0:00 UTC),
+    ///     }
+    /// );
+    /// assert!(Interval::parse("P1D/P1D").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, error::Parse> {
+        use crate::error::ParseFromDescription::InvalidComponent;
+
+        /// Parse a duration, which must not be negative.
+        fn parse_duration(input: &str) -> Result<Duration, error::Parse> {
+            match Duration::parse_iso8601(input)? {
+                duration if duration.is_negative() => Err(InvalidComponent("duration").into()),
+                duration => Ok(duration),
+            }
+        }
+
+        /// Whether the input is a duration rather than a date-time. A duration may have a sign,
+        /// which is also permitted at the start of a date-time with a six-digit year.
+        fn is_duration(input: &str) -> bool {
+            input
+                .strip_prefix(['+', '-'].as_ref())
+                .unwrap_or(input)
+                .starts_with('P')
+        }
+
+        let (first, second) = match input.split_once('/') {
+            Some(parts) => parts,
+            None => return Ok(Self::Duration(parse_duration(input)?)),
+        };
+
+        match (is_duration(first), is_duration(second)) {
+            (false, false) => {
+                let start = OffsetDateTime::parse(first, &Iso8601::DEFAULT)?;
+                let end = OffsetDateTime::parse(second, &Iso8601::DEFAULT)?;
+                if end < start {
+                    return Err(InvalidComponent("end").into());
+                }
+                Ok(Self::StartEnd { start, end })
+            }
+            (false, true) => Ok(Self::StartDuration {
+                start: OffsetDateTime::parse(first, &Iso8601::DEFAULT)?,
+                duration: parse_duration(second)?,
+            }),
+            (true, false) => Ok(Self::DurationEnd {
+                duration: parse_duration(first)?,
+                end: OffsetDateTime::parse(second, &Iso8601::DEFAULT)?,
+            }),
+            (true, true) => Err(InvalidComponent("end").into()),
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl RepeatingInterval {
+    /// Parse a repeating interval, such as `R5/2021-01-01T00:00Z/PT1H`. The number of repetitions
+    /// may be omitted (`R/...`) to repeat without limit.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, interval::{Interval, RepeatingInterval}};
+    /// assert_eq!(
+    ///     RepeatingInterval::parse("R/PT1H")?,
+    ///     RepeatingInterval::new(None, Interval::Duration(1.hours()))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+
+        let (repetitions, interval) = input
+            .strip_prefix('R')
+            .and_then(|input| input.split_once('/'))
+            .ok_or(InvalidLiteral)?;
+        let repetitions = match repetitions {
+            "" => None,
+            repetitions if repetitions.bytes().all(|c| c.is_ascii_digit()) => Some(
+                repetitions
+                    .parse()
+                    .map_err(|_| InvalidComponent("repetitions"))?,
+            ),
+            _ => return Err(InvalidComponent("repetitions").into()),
+        };
+
+        Ok(Self {
+            repetitions,
+            interval: Interval::parse(interval)?,
+        })
+    }
+}
+// endregion formatting & parsing
@@ -0,0 +1,367 @@
+//! Leap seconds and the offset between UTC and TAI.
+//!
+//! UTC is kept within a second of the Earth's rotation by the occasional insertion of a leap
+//! second, while [TAI](crate::time_scale::Tai) counts SI seconds uniformly. The difference between
+//! the two is tracked in a [`LeapSecondTable`]. A table is built into the crate, and an up-to-date
+//! one can be loaded from the [`leap-seconds.list`](https://data.iana.org/time-zones/tzdb/leap-seconds.list)
+//! file published by the IERS.
+//!
+//! Before 1972, UTC was not defined in terms of leap seconds. The offset of the first entry in the
+//! table (ten seconds in the built-in table) is used for all instants prior to it.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{error, Duration, OffsetDateTime, PrimitiveDateTime};
+
+/// The number of seconds between the NTP epoch (1900-01-01) and the Unix epoch (1970-01-01).
+#[cfg(feature = "alloc")]
+const NTP_TO_UNIX_SECONDS: i64 = 2_208_988_800;
+
+/// A change in the offset between TAI and UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeapSecond {
+    /// The Unix timestamp at which the new offset takes effect.
+    unix_timestamp: i64,
+    /// The value of TAI − UTC, in seconds, starting at `unix_timestamp`.
+    tai_minus_utc: i16,
+}
+
+impl LeapSecond {
+    /// Create a new entry indicating that starting at the provided Unix timestamp, TAI is
+    /// `tai_minus_utc` seconds ahead of UTC.
+    ///
+    /// ```rust
+    /// # use time::leap_seconds::LeapSecond;
+    /// let leap_second = LeapSecond::new(1_483_228_800, 37);
+    /// assert_eq!(leap_second.tai_minus_utc(), 37);
+    /// ```
+    pub const fn new(unix_timestamp: i64, tai_minus_utc: i16) -> Self {
+        Self {
+            unix_timestamp,
+            tai_minus_utc,
+        }
+    }
+
+    /// Get the Unix timestamp at which the new offset takes effect. This is the instant
+    /// immediately following the leap second.
+    ///
+    /// ```rust
+    /// # use time::leap_seconds::LeapSecond;
+    /// assert_eq!(LeapSecond::new(1_483_228_800, 37).unix_timestamp(), 1_483_228_800);
+    /// ```
+    pub const fn unix_timestamp(self) -> i64 {
+        self.unix_timestamp
+    }
+
+    /// Get the value of TAI − UTC, in seconds, once the entry has taken effect.
+    ///
+    /// ```rust
+    /// # use time::leap_seconds::LeapSecond;
+    /// assert_eq!(LeapSecond::new(1_483_228_800, 37).tai_minus_utc(), 37);
+    /// ```
+    pub const fn tai_minus_utc(self) -> i16 {
+        self.tai_minus_utc
+    }
+}
+
+/// All changes in the offset between TAI and UTC announced by the IERS as of July 2025.
+const BUILTIN_ENTRIES: &[LeapSecond] = &[
+    LeapSecond::new(63_072_000, 10),    // 1972-01-01
+    LeapSecond::new(78_796_800, 11),    // 1972-07-01
+    LeapSecond::new(94_694_400, 12),    // 1973-01-01
+    LeapSecond::new(126_230_400, 13),   // 1974-01-01
+    LeapSecond::new(157_766_400, 14),   // 1975-01-01
+    LeapSecond::new(189_302_400, 15),   // 1976-01-01
+    LeapSecond::new(220_924_800, 16),   // 1977-01-01
+    LeapSecond::new(252_460_800, 17),   // 1978-01-01
+    LeapSecond::new(283_996_800, 18),   // 1979-01-01
+    LeapSecond::new(315_532_800, 19),   // 1980-01-01
+    LeapSecond::new(362_793_600, 20),   // 1981-07-01
+    LeapSecond::new(394_329_600, 21),   // 1982-07-01
+    LeapSecond::new(425_865_600, 22),   // 1983-07-01
+    LeapSecond::new(489_024_000, 23),   // 1985-07-01
+    LeapSecond::new(567_993_600, 24),   // 1988-01-01
+    LeapSecond::new(631_152_000, 25),   // 1990-01-01
+    LeapSecond::new(662_688_000, 26),   // 1991-01-01
+    LeapSecond::new(709_948_800, 27),   // 1992-07-01
+    LeapSecond::new(741_484_800, 28),   // 1993-07-01
+    LeapSecond::new(773_020_800, 29),   // 1994-07-01
+    LeapSecond::new(820_454_400, 30),   // 1996-01-01
+    LeapSecond::new(867_715_200, 31),   // 1997-07-01
+    LeapSecond::new(915_148_800, 32),   // 1999-01-01
+    LeapSecond::new(1_136_073_600, 33), // 2006-01-01
+    LeapSecond::new(1_230_768_000, 34), // 2009-01-01
+    LeapSecond::new(1_341_100_800, 35), // 2012-07-01
+    LeapSecond::new(1_435_708_800, 36), // 2015-07-01
+    LeapSecond::new(1_483_228_800, 37), // 2017-01-01
+];
+
+/// A sorted list of changes in the offset between TAI and UTC.
+///
+/// The table borrows its entries. To load a table at runtime, see [`LeapSecondList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeapSecondTable<'a> {
+    /// The entries, sorted by their Unix timestamp.
+    entries: &'a [LeapSecond],
+    /// The Unix timestamp after which the table should no longer be relied upon.
+    expires: Option<i64>,
+}
+
+impl<'a> LeapSecondTable<'a> {
+    /// The leap seconds known at the time this version of the crate was released.
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::datetime};
+    /// assert_eq!(
+    ///     LeapSecondTable::BUILTIN.tai_minus_utc(datetime!(2017-01-01 0:00 UTC)),
+    ///     37
+    /// );
+    /// ```
+    pub const BUILTIN: LeapSecondTable<'static> = LeapSecondTable {
+        entries: BUILTIN_ENTRIES,
+        expires: None,
+    };
+
+    /// Create a table from the provided entries, which must be sorted by their Unix timestamp. If
+    /// the entries are not sorted, `None` is returned.
+    ///
+    /// ```rust
+    /// # use time::leap_seconds::{LeapSecond, LeapSecondTable};
+    /// let entries = [LeapSecond::new(0, 10), LeapSecond::new(86_400, 11)];
+    /// assert!(LeapSecondTable::new(&entries).is_some());
+    /// assert!(LeapSecondTable::new(&[entries[1], entries[0]]).is_none());
+    /// ```
+    pub const fn new(entries: &'a [LeapSecond]) -> Option<Self> {
+        let mut idx = 1;
+        while idx < entries.len() {
+            if entries[idx - 1].unix_timestamp >= entries[idx].unix_timestamp {
+                return None;
+            }
+            idx += 1;
+        }
+
+        Some(Self {
+            entries,
+            expires: None,
+        })
+    }
+
+    /// Get the entries of the table, sorted by their Unix timestamp.
+    pub const fn entries(self) -> &'a [LeapSecond] {
+        self.entries
+    }
+
+    /// Get the moment after which the table should no longer be relied upon, if known. The
+    /// built-in table has no expiration.
+    pub fn expires(self) -> Option<OffsetDateTime> {
+        self.expires
+            .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok())
+    }
+
+    /// Get the offset, in seconds, between TAI and UTC at the Unix timestamp provided. A leap
+    /// second is considered part of the minute it ends, so it uses the offset before the change.
+    fn tai_minus_utc_at(self, unix_timestamp: i64) -> i64 {
+        let mut offset = match self.entries.first() {
+            Some(entry) => entry.tai_minus_utc,
+            None => return 0,
+        };
+
+        for entry in self.entries {
+            if entry.unix_timestamp > unix_timestamp {
+                break;
+            }
+            offset = entry.tai_minus_utc;
+        }
+
+        offset as _
+    }
+
+    /// Get the value of TAI − UTC, in seconds, at the provided moment.
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::datetime, Time};
+    /// let table = LeapSecondTable::BUILTIN;
+    /// assert_eq!(table.tai_minus_utc(datetime!(2016-12-31 23:59:59 UTC)), 36);
+    /// assert_eq!(
+    ///     table.tai_minus_utc(
+    ///         datetime!(2016-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?)
+    ///     ),
+    ///     36
+    /// );
+    /// assert_eq!(table.tai_minus_utc(datetime!(2017-01-01 0:00 UTC)), 37);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn tai_minus_utc(self, datetime: OffsetDateTime) -> i64 {
+        self.tai_minus_utc_at(
+            datetime.unix_timestamp() - datetime.utc_datetime.time.is_leap_second() as i64,
+        )
+    }
+
+    /// Whether the provided moment falls within a leap second listed in the table.
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondTable, macros::datetime, Time};
+    /// let leap_second = Time::from_leap_second(23, 59, 0)?;
+    /// let table = LeapSecondTable::BUILTIN;
+    /// assert!(table.is_leap_second(datetime!(2016-12-31 0:00 UTC).replace_time(leap_second)));
+    /// assert!(!table.is_leap_second(datetime!(2017-12-31 0:00 UTC).replace_time(leap_second)));
+    /// assert!(!table.is_leap_second(datetime!(2016-12-31 23:59:59 UTC)));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn is_leap_second(self, datetime: OffsetDateTime) -> bool {
+        if !datetime.utc_datetime.time.is_leap_second() {
+            return false;
+        }
+
+        // The Unix timestamp of a leap second is that of the following minute.
+        let unix_timestamp = datetime.unix_timestamp();
+        let mut previous_offset = None;
+        for entry in self.entries {
+            if entry.unix_timestamp == unix_timestamp {
+                return matches!(previous_offset, Some(offset) if offset < entry.tai_minus_utc);
+            }
+            previous_offset = Some(entry.tai_minus_utc);
+        }
+        false
+    }
+
+    /// Convert a moment in UTC to the corresponding reading of a clock keeping TAI. Fails if the
+    /// reading is outside the supported range.
+    pub(crate) fn utc_to_tai(
+        self,
+        datetime: OffsetDateTime,
+    ) -> Result<PrimitiveDateTime, error::ConversionRange> {
+        // Arithmetic treats a leap second as the first second of the following minute, so adding
+        // the offset in effect before the change yields the correct reading.
+        datetime
+            .utc_datetime
+            .checked_add(Duration::seconds(self.tai_minus_utc(datetime)))
+            .ok_or(error::ConversionRange)
+    }
+
+    /// Convert the reading of a clock keeping TAI to the corresponding moment in UTC. Fails if the
+    /// moment is outside the supported range.
+    pub(crate) fn tai_to_utc(
+        self,
+        datetime: PrimitiveDateTime,
+    ) -> Result<OffsetDateTime, error::ConversionRange> {
+        let tai_timestamp = datetime.assume_utc().unix_timestamp();
+
+        let mut offset = match self.entries.first() {
+            Some(entry) => entry.tai_minus_utc as i64,
+            None => return Ok(datetime.assume_utc()),
+        };
+
+        for entry in self.entries {
+            let new_offset = entry.tai_minus_utc as i64;
+            // The first TAI second that uses the new offset.
+            if tai_timestamp >= entry.unix_timestamp + new_offset {
+                offset = new_offset;
+                continue;
+            }
+
+            // The TAI seconds between the two offsets correspond to an inserted leap second.
+            if tai_timestamp >= entry.unix_timestamp + offset {
+                let preceding = datetime
+                    .checked_sub(Duration::seconds(offset + 1))
+                    .ok_or(error::ConversionRange)?;
+                let (hour, minute, _) = preceding.as_hms();
+                return Ok(preceding
+                    .replace_time(crate::Time::__from_hms_nanos_unchecked(
+                        hour,
+                        minute,
+                        60,
+                        datetime.nanosecond(),
+                    ))
+                    .assume_utc());
+            }
+            break;
+        }
+
+        datetime
+            .checked_sub(Duration::seconds(offset))
+            .map(PrimitiveDateTime::assume_utc)
+            .ok_or(error::ConversionRange)
+    }
+}
+
+/// A table of leap seconds loaded at runtime, typically from a `leap-seconds.list` file.
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeapSecondList {
+    /// The entries, sorted by their Unix timestamp.
+    entries: Vec<LeapSecond>,
+    /// The Unix timestamp after which the list should no longer be relied upon.
+    expires: Option<i64>,
+}
+
+#[cfg(feature = "alloc")]
+impl LeapSecondList {
+    /// Parse the contents of a `leap-seconds.list` file as published by the IERS and distributed
+    /// with the tz database.
+    ///
+    /// Each data line contains an NTP timestamp and the value of TAI − UTC starting at that
+    /// moment. Lines beginning with `#@` contain the expiration of the list as an NTP timestamp.
+    /// All other lines beginning with `#` are ignored, as are blank lines.
+    ///
+    /// ```rust
+    /// # use time::{leap_seconds::LeapSecondList, macros::datetime};
+    /// let list = LeapSecondList::parse(
+    ///     "#@\t3960057600\n\
+    ///      2272060800\t10\t# 1 Jan 1972\n\
+    ///      3692217600\t37\t# 1 Jan 2017\n",
+    /// )?;
+    /// assert_eq!(list.table().entries().len(), 2);
+    /// assert_eq!(list.table().expires(), Some(datetime!(2025-06-28 0:00 UTC)));
+    /// assert_eq!(list.table().tai_minus_utc(datetime!(2020-01-01 0:00 UTC)), 37);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, error::InvalidLeapSecondList> {
+        let mut entries: Vec<LeapSecond> = Vec::new();
+        let mut expires = None;
+
+        for (line_idx, line) in input.lines().enumerate() {
+            let err = error::InvalidLeapSecondList { line: line_idx + 1 };
+            let line = line.trim();
+
+            if let Some(expiration) = line.strip_prefix("#@") {
+                let ntp_timestamp: i64 = expiration.trim().parse().map_err(|_| err)?;
+                expires = Some(ntp_timestamp - NTP_TO_UNIX_SECONDS);
+                continue;
+            }
+
+            // Discard any trailing comment.
+            let data = line.split('#').next().unwrap_or_default();
+            let mut fields = data.split_ascii_whitespace();
+            let ntp_timestamp = match fields.next() {
+                Some(field) => field.parse::<i64>().map_err(|_| err)?,
+                None => continue,
+            };
+            let tai_minus_utc = fields
+                .next()
+                .and_then(|field| field.parse().ok())
+                .ok_or(err)?;
+            if fields.next().is_some() {
+                return Err(err);
+            }
+
+            let entry = LeapSecond::new(ntp_timestamp - NTP_TO_UNIX_SECONDS, tai_minus_utc);
+            if matches!(entries.last(), Some(last) if last.unix_timestamp >= entry.unix_timestamp) {
+                return Err(err);
+            }
+            entries.push(entry);
+        }
+
+        Ok(Self { entries, expires })
+    }
+
+    /// Borrow the list as a [`LeapSecondTable`] for use in conversions.
+    pub fn table(&self) -> LeapSecondTable<'_> {
+        LeapSecondTable {
+            entries: &self.entries,
+            expires: self.expires,
+        }
+    }
+}
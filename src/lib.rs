@@ -279,8 +279,11 @@ macro_rules! const_try_opt {
 }
 // endregion macros
 
+#[cfg(feature = "alloc")]
+mod annotated_date_time;
 mod date;
 mod duration;
+pub mod duration_format;
 pub mod error;
 pub mod ext;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
@@ -289,6 +292,10 @@ pub mod format_description;
 pub mod formatting;
 #[cfg(feature = "std")]
 mod instant;
+pub mod interval;
+pub mod leap_seconds;
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub mod locale;
 #[cfg(feature = "macros")]
 pub mod macros;
 mod month;
@@ -310,10 +317,13 @@ mod sys;
 #[cfg(test)]
 mod tests;
 mod time;
+pub mod time_scale;
 mod utc_offset;
 pub mod util;
 mod weekday;
 
+#[cfg(feature = "alloc")]
+pub use crate::annotated_date_time::{AnnotatedDateTime, TimeZoneAnnotation};
 pub use crate::date::Date;
 pub use crate::duration::Duration;
 pub use crate::error::Error;
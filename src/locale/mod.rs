@@ -0,0 +1,434 @@
+//! Names and predefined format descriptions in languages other than English.
+//!
+//! Format descriptions use English names by default. To use a different language, wrap the
+//! format description in [`Localized`] along with a [`Locale`]. A handful of locales are provided
+//! by this module, and others can be supported by implementing the trait.
+//!
+//! Each locale also provides format descriptions for dates and times in a few predefined styles,
+//! which can be used through [`Styled`].
+//!
+//! ```rust
+//! # use time::{locale::{German, Localized}, macros::{date, format_description}, Date};
+//! let description = Localized::new(
+//!     format_description!("[weekday], [day]. [month repr:long] [year]"),
+//!     German,
+//! );
+//! assert_eq!(date!(2021 - 03 - 01).format(&description)?, "Montag, 01. März 2021");
+//! assert_eq!(
+//!     Date::parse("Montag, 01. März 2021", &description)?,
+//!     date!(2021 - 03 - 01)
+//! );
+//! # Ok::<_, time::Error>(())
+//! ```
+
+mod style;
+
+#[cfg(feature = "formatting")]
+use std::io;
+
+pub use self::style::{FormatStyle, Styled};
+use crate::format_description::FormatItem;
+use crate::{error, util};
+#[cfg(feature = "parsing")]
+use crate::parsing::{Parsable, Parsed};
+#[cfg(feature = "formatting")]
+use crate::{formatting::Formattable, Date, Time, UtcOffset};
+use crate::{Month, Weekday};
+
+/// The names used when formatting and parsing the textual representations of components.
+///
+/// Only the month, weekday and period components have textual representations. When parsing with
+/// `case_sensitive:false`, only ASCII letters are compared without regard to their case. If one
+/// name is a prefix of another, the longer name takes precedence when parsing.
+///
+/// ```rust
+/// # use time::{locale::{Locale, Localized}, macros::{date, format_description}};
+/// # use time::{Date, Month, Weekday};
+/// struct Dutch;
+///
+/// impl Locale for Dutch {
+///     fn month_name(&self, month: Month) -> &str {
+///         const NAMES: [&str; 12] = [
+///             "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus",
+///             "september", "oktober", "november", "december",
+///         ];
+///         NAMES[month as usize - 1]
+///     }
+///
+///     fn month_abbreviation(&self, month: Month) -> &str {
+///         const NAMES: [&str; 12] = [
+///             "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+///         ];
+///         NAMES[month as usize - 1]
+///     }
+///
+///     fn weekday_name(&self, weekday: Weekday) -> &str {
+///         const NAMES: [&str; 7] = [
+///             "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag",
+///         ];
+///         NAMES[weekday.number_days_from_monday() as usize]
+///     }
+///
+///     fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+///         const NAMES: [&str; 7] = ["ma", "di", "wo", "do", "vr", "za", "zo"];
+///         NAMES[weekday.number_days_from_monday() as usize]
+///     }
+///
+///     fn period_name(&self, is_pm: bool, _: bool) -> &str {
+///         if is_pm { "p.m." } else { "a.m." }
+///     }
+///
+///     fn ordinal_suffix(&self, _: u8) -> &str {
+///         "e"
+///     }
+/// }
+///
+/// let description = Localized::new(
+///     format_description!("[weekday] [day padding:none suffix:ordinal] [month repr:long] [year]"),
+///     Dutch,
+/// );
+/// assert_eq!(date!(2021 - 03 - 01).format(&description)?, "maandag 1e maart 2021");
+/// assert_eq!(
+///     Date::parse("zondag 7e maart 2021", &description)?,
+///     date!(2021 - 03 - 07)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(
+    __time_03_docs,
+    doc(cfg(any(feature = "formatting", feature = "parsing")))
+)]
+pub trait Locale {
+    /// The full name of the month, as used by `[month repr:long]`.
+    fn month_name(&self, month: Month) -> &str;
+    /// The abbreviated name of the month, as used by `[month repr:short]`.
+    fn month_abbreviation(&self, month: Month) -> &str;
+    /// The full name of the weekday, as used by `[weekday repr:long]`.
+    fn weekday_name(&self, weekday: Weekday) -> &str;
+    /// The abbreviated name of the weekday, as used by `[weekday repr:short]`.
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str;
+    /// The name of the period of the day, as used by `[period]`.
+    ///
+    /// Locales that do not distinguish between uppercase and lowercase periods may return the
+    /// same name regardless of `is_uppercase`.
+    fn period_name(&self, is_pm: bool, is_uppercase: bool) -> &str;
+
+    /// The suffix following the day of the month, as used by `[day suffix:ordinal]`. The suffix
+    /// may be empty.
+    ///
+    /// By default, the suffixes of [`English`] are used, such as `st` for the first day.
+    fn ordinal_suffix(&self, day: u8) -> &str {
+        English.ordinal_suffix(day)
+    }
+
+    /// The format description of a date in the provided style.
+    ///
+    /// By default, the order of the fields is the same as in [`English`].
+    fn date_format(&self, style: FormatStyle) -> &[FormatItem<'_>] {
+        English.date_format(style)
+    }
+
+    /// The format description of a time in the provided style, which determines whether a 12-hour
+    /// clock is used.
+    ///
+    /// By default, a 12-hour clock is used, as in [`English`].
+    fn time_format(&self, style: FormatStyle) -> &[FormatItem<'_>] {
+        English.time_format(style)
+    }
+
+    /// The text placed between a date and a time, where `date_style` is the style of the date.
+    fn date_time_separator(&self, date_style: FormatStyle) -> &str {
+        English.date_time_separator(date_style)
+    }
+}
+
+impl<L: Locale + ?Sized> Locale for &L {
+    fn month_name(&self, month: Month) -> &str {
+        (**self).month_name(month)
+    }
+
+    fn month_abbreviation(&self, month: Month) -> &str {
+        (**self).month_abbreviation(month)
+    }
+
+    fn weekday_name(&self, weekday: Weekday) -> &str {
+        (**self).weekday_name(weekday)
+    }
+
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+        (**self).weekday_abbreviation(weekday)
+    }
+
+    fn period_name(&self, is_pm: bool, is_uppercase: bool) -> &str {
+        (**self).period_name(is_pm, is_uppercase)
+    }
+
+    fn ordinal_suffix(&self, day: u8) -> &str {
+        (**self).ordinal_suffix(day)
+    }
+
+    fn date_format(&self, style: FormatStyle) -> &[FormatItem<'_>] {
+        (**self).date_format(style)
+    }
+
+    fn time_format(&self, style: FormatStyle) -> &[FormatItem<'_>] {
+        (**self).time_format(style)
+    }
+
+    fn date_time_separator(&self, date_style: FormatStyle) -> &str {
+        (**self).date_time_separator(date_style)
+    }
+}
+
+/// Implement [`Locale`] for a unit struct using fixed tables of names.
+macro_rules! impl_locale {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident {
+            months: $months:expr,
+            month_abbreviations: $month_abbreviations:expr,
+            weekdays: $weekdays:expr,
+            weekday_abbreviations: $weekday_abbreviations:expr,
+            periods: [
+                $am_upper:literal, $pm_upper:literal, $am_lower:literal, $pm_lower:literal $(,)?
+            ],
+            ordinal_suffix: $ordinal_suffix:expr,
+            dates: $dates:expr,
+            times: $times:expr,
+            date_time_separators: $separators:expr $(,)?
+        }
+    )*) => {$(
+        $(#[$attr])*
+        #[cfg_attr(
+            __time_03_docs,
+            doc(cfg(any(feature = "formatting", feature = "parsing")))
+        )]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl Locale for $name {
+            fn month_name(&self, month: Month) -> &str {
+                const NAMES: [&str; 12] = $months;
+                NAMES[month as usize - 1]
+            }
+
+            fn month_abbreviation(&self, month: Month) -> &str {
+                const NAMES: [&str; 12] = $month_abbreviations;
+                NAMES[month as usize - 1]
+            }
+
+            fn weekday_name(&self, weekday: Weekday) -> &str {
+                const NAMES: [&str; 7] = $weekdays;
+                NAMES[weekday.number_days_from_monday() as usize]
+            }
+
+            fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+                const NAMES: [&str; 7] = $weekday_abbreviations;
+                NAMES[weekday.number_days_from_monday() as usize]
+            }
+
+            fn period_name(&self, is_pm: bool, is_uppercase: bool) -> &str {
+                match (is_pm, is_uppercase) {
+                    (false, true) => $am_upper,
+                    (true, true) => $pm_upper,
+                    (false, false) => $am_lower,
+                    (true, false) => $pm_lower,
+                }
+            }
+
+            fn ordinal_suffix(&self, day: u8) -> &str {
+                ($ordinal_suffix)(day)
+            }
+
+            fn date_format(&self, style: FormatStyle) -> &[FormatItem<'_>] {
+                $dates[style as usize]
+            }
+
+            fn time_format(&self, style: FormatStyle) -> &[FormatItem<'_>] {
+                $times[style as usize]
+            }
+
+            fn date_time_separator(&self, date_style: FormatStyle) -> &str {
+                $separators[date_style as usize]
+            }
+        }
+    )*};
+}
+
+impl_locale! {
+    /// English, which is used when no other locale is specified.
+    English {
+        months: [
+            "January", "February", "March", "April", "May", "June", "July", "August", "September",
+            "October", "November", "December",
+        ],
+        month_abbreviations: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekdays: [
+            "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday",
+        ],
+        weekday_abbreviations: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        periods: ["AM", "PM", "am", "pm"],
+        ordinal_suffix: util::ordinal_suffix,
+        dates: style::ENGLISH_DATES,
+        times: style::ENGLISH_TIMES,
+        date_time_separators: style::ENGLISH_SEPARATORS,
+    }
+
+    /// French.
+    French {
+        months: [
+            "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre",
+            "octobre", "novembre", "décembre",
+        ],
+        month_abbreviations: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+        weekday_abbreviations: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        periods: ["AM", "PM", "am", "pm"],
+        ordinal_suffix: |day| if day == 1 { "er" } else { "" },
+        dates: style::FRENCH_DATES,
+        times: style::FRENCH_TIMES,
+        date_time_separators: style::FRENCH_SEPARATORS,
+    }
+
+    /// German.
+    German {
+        months: [
+            "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+            "Oktober", "November", "Dezember",
+        ],
+        month_abbreviations: [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+        weekdays: [
+            "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag",
+        ],
+        weekday_abbreviations: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+        periods: ["AM", "PM", "am", "pm"],
+        ordinal_suffix: |_| ".",
+        dates: style::GERMAN_DATES,
+        times: style::GERMAN_TIMES,
+        date_time_separators: style::GERMAN_SEPARATORS,
+    }
+
+    /// Portuguese.
+    Portuguese {
+        months: [
+            "janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto",
+            "setembro", "outubro", "novembro", "dezembro",
+        ],
+        month_abbreviations: [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+        weekdays: [
+            "segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira",
+            "sábado", "domingo",
+        ],
+        weekday_abbreviations: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+        periods: ["AM", "PM", "am", "pm"],
+        ordinal_suffix: |day| if day == 1 { "º" } else { "" },
+        dates: style::PORTUGUESE_DATES,
+        times: style::PORTUGUESE_TIMES,
+        date_time_separators: style::PORTUGUESE_SEPARATORS,
+    }
+
+    /// Spanish. Periods are written as `a. m.` and `p. m.` regardless of case.
+    Spanish {
+        months: [
+            "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre",
+            "octubre", "noviembre", "diciembre",
+        ],
+        month_abbreviations: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+        weekday_abbreviations: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        periods: ["a. m.", "p. m.", "a. m.", "p. m."],
+        ordinal_suffix: |_| "",
+        dates: style::SPANISH_DATES,
+        times: style::SPANISH_TIMES,
+        date_time_separators: style::SPANISH_SEPARATORS,
+    }
+}
+
+/// A format description whose textual components use the names of the provided locale.
+///
+/// Literals are not affected by the locale, nor are well-known formats, which are always English.
+///
+/// ```rust
+/// # use time::{locale::{French, Localized}, macros::{datetime, format_description}};
+/// let description = Localized::new(
+///     format_description!("[weekday repr:short] [day padding:none] [month repr:long] [year]"),
+///     French,
+/// );
+/// assert_eq!(datetime!(2021-08-14 0:00 UTC).format(&description)?, "sam. 14 août 2021");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(
+    __time_03_docs,
+    doc(cfg(any(feature = "formatting", feature = "parsing")))
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Localized<T, L> {
+    /// The format description being wrapped.
+    description: T,
+    /// The locale providing the names of components.
+    locale: L,
+}
+
+impl<T, L: Locale> Localized<T, L> {
+    /// Wrap the format description, using the names of the provided locale.
+    pub const fn new(description: T, locale: L) -> Self {
+        Self {
+            description,
+            locale,
+        }
+    }
+
+    /// Obtain the wrapped format description.
+    pub fn into_inner(self) -> T {
+        self.description
+    }
+
+    /// Obtain the locale used by the format description.
+    pub const fn locale(&self) -> &L {
+        &self.locale
+    }
+}
+
+#[cfg(feature = "formatting")]
+impl<T: Formattable, L: Locale> Formattable for Localized<T, L> {}
+
+#[cfg(feature = "formatting")]
+impl<T: Formattable, L: Locale> crate::formatting::formattable::sealed::Sealed for Localized<T, L> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.description
+            .format_into_localized(output, date, time, offset, &self.locale)
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<T: Parsable, L: Locale> Parsable for Localized<T, L> {}
+
+#[cfg(feature = "parsing")]
+impl<T: Parsable, L: Locale> crate::parsing::parsable::sealed::Sealed for Localized<T, L> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.description
+            .parse_into_localized(input, parsed, &self.locale)
+    }
+}
@@ -0,0 +1,453 @@
+//! Predefined styles of format descriptions for each locale.
+
+#[cfg(feature = "formatting")]
+use std::io;
+
+use crate::error;
+use crate::format_description::modifier::{self, Padding};
+use crate::format_description::{Component, FormatItem};
+use crate::locale::Locale;
+#[cfg(feature = "parsing")]
+use crate::parsing::{Parsable, Parsed};
+#[cfg(feature = "formatting")]
+use crate::{formatting::Formattable, Date, Time, UtcOffset};
+
+/// The level of detail of a predefined format description.
+///
+/// The exact output depends on the [`Locale`]. In English, the date styles look like `1/6/21`,
+/// `Jan 6, 2021`, `January 6, 2021` and `Wednesday, January 6, 2021` respectively, while the time
+/// styles look like `1:05 PM`, `1:05:09 PM` and `1:05:09 PM +01:00`. The long and full time styles
+/// include the UTC offset, so they can only be used with an [`OffsetDateTime`].
+///
+/// [`OffsetDateTime`]: crate::OffsetDateTime
+#[cfg_attr(
+    __time_03_docs,
+    doc(cfg(any(feature = "formatting", feature = "parsing")))
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FormatStyle {
+    /// A compact, numeric style.
+    ///
+    /// Some locales, such as [`English`](crate::locale::English) and
+    /// [`German`](crate::locale::German), only include the last two digits of the year. As the
+    /// century is not known, a date in such a locale cannot be parsed in this style. Parsing fails
+    /// with [`TryFromParsed::InsufficientInformation`](crate::error::TryFromParsed).
+    Short,
+    /// A style with abbreviated names.
+    Medium,
+    /// A style with full names.
+    Long,
+    /// The most detailed style, including the weekday for dates.
+    Full,
+}
+
+/// A predefined format description for a date, time, or both, in the style preferred by a locale.
+///
+/// The layout of each style, including the order of the fields and whether a 12-hour clock is
+/// used, is provided by [`Locale::date_format`], [`Locale::time_format`] and
+/// [`Locale::date_time_separator`].
+///
+/// ```rust
+/// # use time::{locale::{English, FormatStyle, French, German, Styled}, macros::datetime, Date};
+/// let datetime = datetime!(2021-01-06 13:05);
+/// assert_eq!(datetime.format(&Styled::date(FormatStyle::Short, English))?, "1/6/21");
+/// assert_eq!(datetime.format(&Styled::date(FormatStyle::Medium, English))?, "Jan 6, 2021");
+/// assert_eq!(datetime.format(&Styled::date(FormatStyle::Long, French))?, "6 janvier 2021");
+/// assert_eq!(
+///     datetime.format(&Styled::date_time(FormatStyle::Medium, FormatStyle::Short, German))?,
+///     "06.01.2021, 13:05"
+/// );
+/// assert_eq!(
+///     Date::parse("Jan 6, 2021", &Styled::date(FormatStyle::Medium, English))?,
+///     datetime.date()
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(
+    __time_03_docs,
+    doc(cfg(any(feature = "formatting", feature = "parsing")))
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Styled<L> {
+    /// The style of the date, if present.
+    date: Option<FormatStyle>,
+    /// The style of the time, if present.
+    time: Option<FormatStyle>,
+    /// The locale providing the format descriptions and names.
+    locale: L,
+}
+
+impl<L: Locale> Styled<L> {
+    /// A date in the provided style.
+    pub const fn date(style: FormatStyle, locale: L) -> Self {
+        Self {
+            date: Some(style),
+            time: None,
+            locale,
+        }
+    }
+
+    /// A time in the provided style.
+    pub const fn time(style: FormatStyle, locale: L) -> Self {
+        Self {
+            date: None,
+            time: Some(style),
+            locale,
+        }
+    }
+
+    /// A date and time, each in the provided style.
+    pub const fn date_time(date_style: FormatStyle, time_style: FormatStyle, locale: L) -> Self {
+        Self {
+            date: Some(date_style),
+            time: Some(time_style),
+            locale,
+        }
+    }
+
+    /// Obtain the locale used by the format description.
+    pub const fn locale(&self) -> &L {
+        &self.locale
+    }
+
+    /// Expand the styles into the format description of the locale.
+    fn items(&self) -> [FormatItem<'_>; 3] {
+        let date = self
+            .date
+            .map_or(&[][..], |style| self.locale.date_format(style));
+        let time = self
+            .time
+            .map_or(&[][..], |style| self.locale.time_format(style));
+        let separator = match (self.date, self.time) {
+            (Some(date_style), Some(_)) => self.locale.date_time_separator(date_style),
+            _ => "",
+        };
+        [
+            FormatItem::Compound(date),
+            FormatItem::Literal(separator.as_bytes()),
+            FormatItem::Compound(time),
+        ]
+    }
+}
+
+#[cfg(feature = "formatting")]
+impl<L: Locale> Formattable for Styled<L> {}
+
+#[cfg(feature = "formatting")]
+impl<L: Locale> crate::formatting::formattable::sealed::Sealed for Styled<L> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.items()[..].format_into_localized(output, date, time, offset, &self.locale)
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<L: Locale> Parsable for Styled<L> {}
+
+#[cfg(feature = "parsing")]
+impl<L: Locale> crate::parsing::parsable::sealed::Sealed for Styled<L> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.items()[..].parse_into_localized(input, parsed, &self.locale)
+    }
+}
+
+// region: components
+/// Create a [`FormatItem::Component`] from the component and its modifiers.
+macro_rules! component {
+    ($name:ident { $($field:ident : $value:expr),* $(,)? }) => {
+        FormatItem::Component(Component::$name(modifier::$name {
+            $($field: $value,)*
+            ..modifier::$name::default()
+        }))
+    };
+}
+
+/// A literal in a format description.
+macro_rules! lit {
+    ($value:literal) => {
+        FormatItem::Literal($value)
+    };
+}
+
+/// The day, padded with zeroes.
+const DAY: FormatItem<'_> = component!(Day {});
+/// The day without padding.
+const DAY_UNPADDED: FormatItem<'_> = component!(Day {
+    padding: Padding::None
+});
+/// The month as a number, padded with zeroes.
+const MONTH: FormatItem<'_> = component!(Month {});
+/// The month as a number without padding.
+const MONTH_UNPADDED: FormatItem<'_> = component!(Month {
+    padding: Padding::None
+});
+/// The abbreviated name of the month.
+const MONTH_SHORT: FormatItem<'_> = component!(Month {
+    repr: modifier::MonthRepr::Short
+});
+/// The full name of the month.
+const MONTH_LONG: FormatItem<'_> = component!(Month {
+    repr: modifier::MonthRepr::Long
+});
+/// The full name of the weekday.
+const WEEKDAY: FormatItem<'_> = component!(Weekday {});
+/// The full calendar year.
+const YEAR: FormatItem<'_> = component!(Year {});
+/// The last two digits of the calendar year.
+const YEAR_LAST_TWO: FormatItem<'_> = component!(Year {
+    repr: modifier::YearRepr::LastTwo
+});
+/// The hour on a 24-hour clock, padded with zeroes.
+const HOUR_24: FormatItem<'_> = component!(Hour {});
+/// The hour on a 24-hour clock without padding.
+const HOUR_24_UNPADDED: FormatItem<'_> = component!(Hour {
+    padding: Padding::None
+});
+/// The hour on a 12-hour clock without padding.
+const HOUR_12: FormatItem<'_> = component!(Hour {
+    padding: Padding::None,
+    is_12_hour_clock: true,
+});
+/// The minute, padded with zeroes.
+const MINUTE: FormatItem<'_> = component!(Minute {});
+/// The second, padded with zeroes.
+const SECOND: FormatItem<'_> = component!(Second {});
+/// The uppercase period of the day.
+const PERIOD: FormatItem<'_> = component!(Period {});
+/// The UTC offset in hours and minutes, such as `+01:00`.
+const OFFSET: FormatItem<'_> = FormatItem::Compound(&[
+    component!(OffsetHour {
+        sign_is_mandatory: true,
+    }),
+    lit!(b":"),
+    component!(OffsetMinute {}),
+]);
+// endregion components
+
+/// The format descriptions of a locale for each [`FormatStyle`], in order of increasing detail.
+pub(super) type Styles = [&'static [FormatItem<'static>]; 4];
+
+// region: English
+/// Dates in English.
+pub(super) const ENGLISH_DATES: Styles = [
+    &[
+        MONTH_UNPADDED,
+        lit!(b"/"),
+        DAY_UNPADDED,
+        lit!(b"/"),
+        YEAR_LAST_TWO,
+    ],
+    &[MONTH_SHORT, lit!(b" "), DAY_UNPADDED, lit!(b", "), YEAR],
+    &[MONTH_LONG, lit!(b" "), DAY_UNPADDED, lit!(b", "), YEAR],
+    &[
+        WEEKDAY,
+        lit!(b", "),
+        MONTH_LONG,
+        lit!(b" "),
+        DAY_UNPADDED,
+        lit!(b", "),
+        YEAR,
+    ],
+];
+
+/// Times in English.
+pub(super) const ENGLISH_TIMES: Styles = [
+    &[HOUR_12, lit!(b":"), MINUTE, lit!(b" "), PERIOD],
+    &[
+        HOUR_12,
+        lit!(b":"),
+        MINUTE,
+        lit!(b":"),
+        SECOND,
+        lit!(b" "),
+        PERIOD,
+    ],
+    &[
+        HOUR_12,
+        lit!(b":"),
+        MINUTE,
+        lit!(b":"),
+        SECOND,
+        lit!(b" "),
+        PERIOD,
+        lit!(b" "),
+        OFFSET,
+    ],
+    &[
+        HOUR_12,
+        lit!(b":"),
+        MINUTE,
+        lit!(b":"),
+        SECOND,
+        lit!(b" "),
+        PERIOD,
+        lit!(b" "),
+        OFFSET,
+    ],
+];
+
+/// Separators between dates and times in English.
+pub(super) const ENGLISH_SEPARATORS: [&str; 4] = [", ", ", ", " at ", " at "];
+// endregion English
+
+/// Times on a 24-hour clock, as used by most locales other than English.
+const TIMES_24_HOUR: Styles = [
+    &[HOUR_24, lit!(b":"), MINUTE],
+    &[HOUR_24, lit!(b":"), MINUTE, lit!(b":"), SECOND],
+    &[
+        HOUR_24,
+        lit!(b":"),
+        MINUTE,
+        lit!(b":"),
+        SECOND,
+        lit!(b" "),
+        OFFSET,
+    ],
+    &[
+        HOUR_24,
+        lit!(b":"),
+        MINUTE,
+        lit!(b":"),
+        SECOND,
+        lit!(b" "),
+        OFFSET,
+    ],
+];
+
+// region: French
+/// Dates in French.
+pub(super) const FRENCH_DATES: Styles = [
+    &[DAY, lit!(b"/"), MONTH, lit!(b"/"), YEAR],
+    &[DAY_UNPADDED, lit!(b" "), MONTH_SHORT, lit!(b" "), YEAR],
+    &[DAY_UNPADDED, lit!(b" "), MONTH_LONG, lit!(b" "), YEAR],
+    &[
+        WEEKDAY,
+        lit!(b" "),
+        DAY_UNPADDED,
+        lit!(b" "),
+        MONTH_LONG,
+        lit!(b" "),
+        YEAR,
+    ],
+];
+
+/// Times in French.
+pub(super) const FRENCH_TIMES: Styles = TIMES_24_HOUR;
+
+/// Separators between dates and times in French.
+pub(super) const FRENCH_SEPARATORS: [&str; 4] = [" ", ", ", " à ", " à "];
+// endregion French
+
+// region: German
+/// Dates in German.
+pub(super) const GERMAN_DATES: Styles = [
+    &[DAY, lit!(b"."), MONTH, lit!(b"."), YEAR_LAST_TWO],
+    &[DAY, lit!(b"."), MONTH, lit!(b"."), YEAR],
+    &[DAY_UNPADDED, lit!(b". "), MONTH_LONG, lit!(b" "), YEAR],
+    &[
+        WEEKDAY,
+        lit!(b", "),
+        DAY_UNPADDED,
+        lit!(b". "),
+        MONTH_LONG,
+        lit!(b" "),
+        YEAR,
+    ],
+];
+
+/// Times in German.
+pub(super) const GERMAN_TIMES: Styles = TIMES_24_HOUR;
+
+/// Separators between dates and times in German.
+pub(super) const GERMAN_SEPARATORS: [&str; 4] = [", ", ", ", " um ", " um "];
+// endregion German
+
+// region: Portuguese
+/// Dates in Portuguese.
+pub(super) const PORTUGUESE_DATES: Styles = [
+    &[DAY, lit!(b"/"), MONTH, lit!(b"/"), YEAR],
+    &[
+        DAY_UNPADDED,
+        lit!(b" de "),
+        MONTH_SHORT,
+        lit!(b" de "),
+        YEAR,
+    ],
+    &[DAY_UNPADDED, lit!(b" de "), MONTH_LONG, lit!(b" de "), YEAR],
+    &[
+        WEEKDAY,
+        lit!(b", "),
+        DAY_UNPADDED,
+        lit!(b" de "),
+        MONTH_LONG,
+        lit!(b" de "),
+        YEAR,
+    ],
+];
+
+/// Times in Portuguese.
+pub(super) const PORTUGUESE_TIMES: Styles = TIMES_24_HOUR;
+
+/// Separators between dates and times in Portuguese.
+pub(super) const PORTUGUESE_SEPARATORS: [&str; 4] = [" ", " ", " ", " "];
+// endregion Portuguese
+
+// region: Spanish
+/// Dates in Spanish.
+pub(super) const SPANISH_DATES: Styles = [
+    &[
+        DAY_UNPADDED,
+        lit!(b"/"),
+        MONTH_UNPADDED,
+        lit!(b"/"),
+        YEAR_LAST_TWO,
+    ],
+    &[DAY_UNPADDED, lit!(b" "), MONTH_SHORT, lit!(b" "), YEAR],
+    &[DAY_UNPADDED, lit!(b" de "), MONTH_LONG, lit!(b" de "), YEAR],
+    &[
+        WEEKDAY,
+        lit!(b", "),
+        DAY_UNPADDED,
+        lit!(b" de "),
+        MONTH_LONG,
+        lit!(b" de "),
+        YEAR,
+    ],
+];
+
+/// Times in Spanish, which do not pad the hour.
+pub(super) const SPANISH_TIMES: Styles = [
+    &[HOUR_24_UNPADDED, lit!(b":"), MINUTE],
+    &[HOUR_24_UNPADDED, lit!(b":"), MINUTE, lit!(b":"), SECOND],
+    &[
+        HOUR_24_UNPADDED,
+        lit!(b":"),
+        MINUTE,
+        lit!(b":"),
+        SECOND,
+        lit!(b" "),
+        OFFSET,
+    ],
+    &[
+        HOUR_24_UNPADDED,
+        lit!(b":"),
+        MINUTE,
+        lit!(b":"),
+        SECOND,
+        lit!(b" "),
+        OFFSET,
+    ],
+];
+
+/// Separators between dates and times in Spanish.
+pub(super) const SPANISH_SEPARATORS: [&str; 4] = [", ", ", ", ", ", ", "];
+// endregion Spanish
@@ -51,7 +51,21 @@ pub use time_macros::datetime;
 /// The syntax accepted by this macro is the same as [`format_description::parse()`], which can
 /// be found in [the book](https://time-rs.github.io/book/api/format-description.html).
 ///
+/// A different version of the syntax can be selected by passing `version = N,` before the string,
+/// as with [`format_description::parse_owned()`]. Unlike the runtime parser, nested items written
+/// in version 2 are supported without allocating.
+///
+/// ```rust
+/// # use time::{macros::{format_description, time}, Time};
+/// const DESCRIPTION: &[time::format_description::FormatItem<'_>] =
+///     format_description!(version = 2, r"\[[hour]:[minute][optional [:[second]]]\]");
+/// assert_eq!(Time::parse("[13:05:30]", DESCRIPTION)?, time!(13:05:30));
+/// assert_eq!(Time::parse("[13:05]", DESCRIPTION)?, time!(13:05));
+/// # Ok::<_, time::Error>(())
+/// ```
+///
 /// [`format_description::parse()`]: crate::format_description::parse()
+/// [`format_description::parse_owned()`]: crate::format_description::parse_owned()
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub use time_macros::format_description;
 /// Construct a [`UtcOffset`](crate::UtcOffset) with a statically known value.
@@ -77,6 +91,25 @@ pub use time_macros::format_description;
 /// # Ok::<_, time::Error>(())
 /// ```
 pub use time_macros::offset;
+/// Equivalent of performing [`format_description::parse_strftime()`] at compile time.
+///
+/// As with [`format_description!`], the result is a static slice that can be used in `const` or
+/// `static` declarations. An unsupported specifier results in a compilation error.
+///
+/// ```rust
+/// # use time::{format_description, macros::strftime};
+/// assert_eq!(
+///     strftime!("%Y-%m-%dT%H:%M:%S%z"),
+///     format_description::parse_strftime("%Y-%m-%dT%H:%M:%S%z")?
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// The specifiers accepted by this macro are the same as [`format_description::parse_strftime()`].
+///
+/// [`format_description::parse_strftime()`]: crate::format_description::parse_strftime()
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub use time_macros::strftime;
 /// Construct a [`Time`](crate::Time) with a statically known value.
 ///
 /// The resulting expression can be used in `const` or `static` declarations.
@@ -15,12 +15,21 @@ use std::time::SystemTime;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{self, Parsable};
 use crate::{error, Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 /// The Julian day of the Unix epoch.
 const UNIX_EPOCH_JULIAN_DAY: i32 = Date::__from_ordinal_date_unchecked(1970, 1).to_julian_day();
 
+/// The Unix timestamp of the Windows `FILETIME` epoch, January 1, 1601.
+const WINDOWS_FILE_TIME_EPOCH: i64 = -11_644_473_600;
+/// The Unix timestamp of the .NET `DateTime` epoch, January 1, 0001.
+const DOTNET_TICKS_EPOCH: i64 = -62_135_596_800;
+/// The Unix timestamp of the NTP prime epoch, January 1, 1900.
+const NTP_EPOCH: i64 = -2_208_988_800;
+/// The Unix timestamp of the Cocoa reference date, January 1, 2001.
+const COCOA_EPOCH: i64 = 978_307_200;
+
 /// A [`PrimitiveDateTime`] with a [`UtcOffset`].
 ///
 /// All comparisons are performed using the UTC time.
@@ -160,6 +169,44 @@ impl OffsetDateTime {
         Ok(PrimitiveDateTime::new(date, time).assume_utc())
     }
 
+    /// Construct an `OffsetDateTime` from the provided Unix timestamp (in milliseconds). Calling
+    /// `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(0),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(1_546_300_800_123),
+    ///     Ok(datetime!(2019-01-01 0:00:00.123 UTC)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_millis(timestamp: i64) -> Result<Self, error::ComponentRange> {
+        Self::from_ticks(timestamp as _, 1_000_000, 0, "timestamp")
+    }
+
+    /// Construct an `OffsetDateTime` from the provided Unix timestamp (in microseconds). Calling
+    /// `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_micros(0),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_micros(1_546_300_800_123_456),
+    ///     Ok(datetime!(2019-01-01 0:00:00.123_456 UTC)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_micros(
+        timestamp: i128,
+    ) -> Result<Self, error::ComponentRange> {
+        Self::from_ticks(timestamp, 1_000, 0, "timestamp")
+    }
+
     /// Construct an `OffsetDateTime` from the provided Unix timestamp (in nanoseconds). Calling
     /// `.offset()` on the resulting value is guaranteed to return UTC.
     ///
@@ -189,6 +236,287 @@ impl OffsetDateTime {
             ))
             .assume_utc())
     }
+
+    /// Create an `OffsetDateTime` from a fractional [Julian date](https://en.wikipedia.org/wiki/Julian_day)
+    /// in UTC. Calling `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(datetime!(2000-01-01 12:00 UTC))
+    /// );
+    /// ```
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        Ok(PrimitiveDateTime::from_julian_date(julian_date)?.assume_utc())
+    }
+
+    /// Create an `OffsetDateTime` from a fractional
+    /// [modified Julian date](https://en.wikipedia.org/wiki/Julian_day#Variants) in UTC. Calling
+    /// `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_modified_julian_date(59_579.75),
+    ///     Ok(datetime!(2021-12-31 18:00 UTC))
+    /// );
+    /// ```
+    pub fn from_modified_julian_date(
+        modified_julian_date: f64,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(PrimitiveDateTime::from_modified_julian_date(modified_julian_date)?.assume_utc())
+    }
+
+    /// Create an `OffsetDateTime` from a Windows `FILETIME`, the number of 100-nanosecond
+    /// intervals since January 1, 1601 UTC. Calling `.offset()` on the resulting value is
+    /// guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_windows_file_time(0),
+    ///     Ok(datetime!(1601-01-01 0:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_windows_file_time(116_444_736_000_000_000),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH)
+    /// );
+    /// ```
+    pub const fn from_windows_file_time(file_time: i128) -> Result<Self, error::ComponentRange> {
+        Self::from_ticks(file_time, 100, WINDOWS_FILE_TIME_EPOCH, "file_time")
+    }
+
+    /// Create an `OffsetDateTime` from a number of .NET ticks, the number of 100-nanosecond
+    /// intervals since January 1, 0001 UTC. Calling `.offset()` on the resulting value is
+    /// guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_dotnet_ticks(0),
+    ///     Ok(datetime!(0001-01-01 0:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_dotnet_ticks(621_355_968_000_000_000),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH)
+    /// );
+    /// ```
+    pub const fn from_dotnet_ticks(ticks: i128) -> Result<Self, error::ComponentRange> {
+        Self::from_ticks(ticks, 100, DOTNET_TICKS_EPOCH, "ticks")
+    }
+
+    /// Create an `OffsetDateTime` from a number of ticks of the provided length since the provided
+    /// Unix timestamp.
+    const fn from_ticks(
+        ticks: i128,
+        nanoseconds_per_tick: i128,
+        epoch: i64,
+        name: &'static str,
+    ) -> Result<Self, error::ComponentRange> {
+        #[allow(clippy::missing_docs_in_private_items)]
+        const MIN_TIMESTAMP_NANOS: i128 = Date::MIN.midnight().assume_utc().unix_timestamp_nanos();
+        #[allow(clippy::missing_docs_in_private_items)]
+        const MAX_TIMESTAMP_NANOS: i128 = Date::MAX
+            .with_time(Time::__from_hms_nanos_unchecked(23, 59, 59, 999_999_999))
+            .assume_utc()
+            .unix_timestamp_nanos();
+
+        let epoch_nanos = epoch as i128 * 1_000_000_000;
+        let minimum = -div_floor!(epoch_nanos - MIN_TIMESTAMP_NANOS, nanoseconds_per_tick);
+        let maximum = div_floor!(MAX_TIMESTAMP_NANOS - epoch_nanos, nanoseconds_per_tick);
+
+        if ticks < minimum || ticks > maximum {
+            /// Saturate the value to fit in an `i64`.
+            const fn saturate(value: i128) -> i64 {
+                if value > i64::MAX as i128 {
+                    i64::MAX
+                } else if value < i64::MIN as i128 {
+                    i64::MIN
+                } else {
+                    value as _
+                }
+            }
+
+            return Err(error::ComponentRange {
+                name,
+                minimum: saturate(minimum),
+                maximum: saturate(maximum),
+                value: saturate(ticks),
+                conditional_range: false,
+            });
+        }
+
+        Self::from_unix_timestamp_nanos(ticks * nanoseconds_per_tick + epoch_nanos)
+    }
+
+    /// Create an `OffsetDateTime` from an NTP era and 64-bit NTP timestamp. The upper 32 bits of
+    /// the timestamp are the seconds since the start of the era and the lower 32 bits are the
+    /// fraction of the second. Era 0 began on January 1, 1900 UTC and era 1 begins on February 7,
+    /// 2036. Calling `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// The fractional second is rounded to the nearest nanosecond. To infer the era from a nearby
+    /// moment, use [`OffsetDateTime::from_ntp_timestamp_near`].
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_ntp_timestamp(0, 0),
+    ///     Ok(datetime!(1900-01-01 0:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_ntp_timestamp(0, 3_849_984_000 << 32 | 1 << 31),
+    ///     Ok(datetime!(2022-01-01 0:00:00.5 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_ntp_timestamp(1, 0),
+    ///     Ok(datetime!(2036-02-07 6:28:16 UTC))
+    /// );
+    /// ```
+    pub const fn from_ntp_timestamp(
+        era: i32,
+        timestamp: u64,
+    ) -> Result<Self, error::ComponentRange> {
+        let (min_era, _) = Date::MIN.midnight().assume_utc().ntp_timestamp();
+        let (max_era, _) = Date::MAX.midnight().assume_utc().ntp_timestamp();
+        let seconds = era as i128 * (1 << 32) + (timestamp >> 32) as i128 + NTP_EPOCH as i128;
+        // Rounding the fraction may carry into the next second.
+        let nanoseconds = ((timestamp & 0xFFFF_FFFF) * 1_000_000_000 + (1 << 31)) >> 32;
+
+        match Self::from_unix_timestamp_nanos(seconds * 1_000_000_000 + nanoseconds as i128) {
+            Ok(datetime) => Ok(datetime),
+            // The range of valid timestamps does not end at an era boundary.
+            Err(_) => Err(error::ComponentRange {
+                name: "era",
+                minimum: min_era as _,
+                maximum: max_era as _,
+                value: era as _,
+                conditional_range: true,
+            }),
+        }
+    }
+
+    /// Create an `OffsetDateTime` from a 64-bit NTP timestamp, choosing the era that places the
+    /// result within 68 years of the provided reference. This is the approach recommended by
+    /// [RFC 5905](https://datatracker.ietf.org/doc/html/rfc5905#section-6). Calling `.offset()` on
+    /// the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_ntp_timestamp_near(1 << 32, datetime!(2040-01-01 0:00 UTC)),
+    ///     Ok(datetime!(2036-02-07 6:28:17 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_ntp_timestamp_near(1 << 32, datetime!(1950-01-01 0:00 UTC)),
+    ///     Ok(datetime!(1900-01-01 0:00:01 UTC))
+    /// );
+    /// ```
+    pub const fn from_ntp_timestamp_near(
+        timestamp: u64,
+        reference: Self,
+    ) -> Result<Self, error::ComponentRange> {
+        let (reference_era, reference_timestamp) = reference.ntp_timestamp();
+        let difference = (timestamp >> 32) as i64 - (reference_timestamp >> 32) as i64;
+        let era = if difference > i32::MAX as i64 {
+            reference_era - 1
+        } else if difference < i32::MIN as i64 {
+            reference_era + 1
+        } else {
+            reference_era
+        };
+        Self::from_ntp_timestamp(era, timestamp)
+    }
+
+    /// Create an `OffsetDateTime` from a Cocoa absolute time, the number of seconds since
+    /// January 1, 2001 UTC as used by `CFAbsoluteTime` and `NSDate`. The result is rounded to the
+    /// nearest nanosecond. Calling `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_cocoa_absolute_time(0.),
+    ///     Ok(datetime!(2001-01-01 0:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_cocoa_absolute_time(-978_307_199.5),
+    ///     Ok(datetime!(1970-01-01 0:00:00.5 UTC))
+    /// );
+    /// assert!(OffsetDateTime::from_cocoa_absolute_time(f64::NAN).is_err());
+    /// ```
+    pub fn from_cocoa_absolute_time(seconds: f64) -> Result<Self, error::ComponentRange> {
+        Self::from_fractional_seconds(seconds, COCOA_EPOCH, "absolute_time")
+    }
+
+    /// Create an `OffsetDateTime` from a fractional number of seconds since the provided Unix
+    /// timestamp, rounded to the nearest nanosecond.
+    pub(crate) fn from_fractional_seconds(
+        seconds: f64,
+        epoch: i64,
+        name: &'static str,
+    ) -> Result<Self, error::ComponentRange> {
+        #[allow(clippy::missing_docs_in_private_items)]
+        const MIN_TIMESTAMP: i64 = Date::MIN.midnight().assume_utc().unix_timestamp();
+        #[allow(clippy::missing_docs_in_private_items)]
+        const MAX_TIMESTAMP: i64 = Date::MAX
+            .with_time(Time::__from_hms_nanos_unchecked(23, 59, 59, 999_999_999))
+            .assume_utc()
+            .unix_timestamp();
+
+        let err = error::ComponentRange {
+            name,
+            minimum: MIN_TIMESTAMP - epoch,
+            maximum: MAX_TIMESTAMP - epoch,
+            value: seconds as _,
+            conditional_range: false,
+        };
+
+        // This comparison also rejects NaN.
+        if !(seconds >= err.minimum as f64 && seconds < (err.maximum + 1) as f64) {
+            return Err(err);
+        }
+
+        // `f64::mul_add` is not available in `core`.
+        #[allow(clippy::suboptimal_flops)]
+        let nanoseconds = if seconds < 0. {
+            (seconds * 1_000_000_000. - 0.5) as i128
+        } else {
+            (seconds * 1_000_000_000. + 0.5) as i128
+        };
+        // Rounding may have carried the value out of range.
+        Self::from_unix_timestamp_nanos(nanoseconds + epoch as i128 * 1_000_000_000)
+            .map_err(|_| err)
+    }
+
+    /// Create an `OffsetDateTime` from a serial date in the 1900 date system of Excel, which is
+    /// assumed to be in UTC. See [`PrimitiveDateTime::from_excel_1900_serial`] for details.
+    /// Calling `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_excel_1900_serial(44_562.75),
+    ///     Ok(datetime!(2022-01-01 18:00 UTC))
+    /// );
+    /// ```
+    pub fn from_excel_1900_serial(serial: f64) -> Result<Self, error::ComponentRange> {
+        Ok(PrimitiveDateTime::from_excel_1900_serial(serial)?.assume_utc())
+    }
+
+    /// Create an `OffsetDateTime` from a serial date in the 1904 date system of Excel, which is
+    /// assumed to be in UTC. See [`PrimitiveDateTime::from_excel_1904_serial`] for details.
+    /// Calling `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_excel_1904_serial(43_100.75),
+    ///     Ok(datetime!(2022-01-01 18:00 UTC))
+    /// );
+    /// ```
+    pub fn from_excel_1904_serial(serial: f64) -> Result<Self, error::ComponentRange> {
+        Ok(PrimitiveDateTime::from_excel_1904_serial(serial)?.assume_utc())
+    }
     // endregion constructors
 
     // region: getters
@@ -219,6 +547,34 @@ impl OffsetDateTime {
         days + hours + minutes + seconds
     }
 
+    /// Get the Unix timestamp in milliseconds.
+    ///
+    /// ```rust
+    /// use time::macros::datetime;
+    /// assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_millis(), 0);
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 0:00:00.123_456 -1).unix_timestamp_millis(),
+    ///     3_600_123,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_millis(self) -> i64 {
+        self.ticks(1_000_000, 0) as _
+    }
+
+    /// Get the Unix timestamp in microseconds.
+    ///
+    /// ```rust
+    /// use time::macros::datetime;
+    /// assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_micros(), 0);
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 0:00:00.123_456 -1).unix_timestamp_micros(),
+    ///     3_600_123_456,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_micros(self) -> i128 {
+        self.ticks(1_000, 0)
+    }
+
     /// Get the Unix timestamp in nanoseconds.
     ///
     /// ```rust
@@ -233,6 +589,137 @@ impl OffsetDateTime {
         self.unix_timestamp() as i128 * 1_000_000_000 + self.utc_datetime.nanosecond() as i128
     }
 
+    /// Get the fractional [Julian date](https://en.wikipedia.org/wiki/Julian_day) in UTC. See
+    /// [`PrimitiveDateTime::to_julian_date`] for details on its precision.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00 UTC).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 13:00 +1).to_julian_date(), 2_451_545.0);
+    /// ```
+    pub fn to_julian_date(self) -> f64 {
+        self.utc_datetime.to_julian_date()
+    }
+
+    /// Get the fractional [modified Julian date](https://en.wikipedia.org/wiki/Julian_day#Variants)
+    /// in UTC.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2021-12-31 18:00 UTC).to_modified_julian_date(), 59_579.75);
+    /// assert_eq!(datetime!(2021-12-31 12:00 -6).to_modified_julian_date(), 59_579.75);
+    /// ```
+    pub fn to_modified_julian_date(self) -> f64 {
+        self.utc_datetime.to_modified_julian_date()
+    }
+
+    /// Get the Windows `FILETIME`, the number of 100-nanosecond intervals since January 1, 1601
+    /// UTC. The value is negative for earlier moments.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(1601-01-01 0:00 UTC).windows_file_time(), 0);
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 1:00 +1).windows_file_time(),
+    ///     116_444_736_000_000_000
+    /// );
+    /// ```
+    pub const fn windows_file_time(self) -> i128 {
+        self.ticks(100, WINDOWS_FILE_TIME_EPOCH)
+    }
+
+    /// Get the number of .NET ticks, the number of 100-nanosecond intervals since January 1, 0001
+    /// UTC. The value is negative for earlier moments.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(0001-01-01 0:00 UTC).dotnet_ticks(), 0);
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 0:00 UTC).dotnet_ticks(),
+    ///     621_355_968_000_000_000
+    /// );
+    /// ```
+    pub const fn dotnet_ticks(self) -> i128 {
+        self.ticks(100, DOTNET_TICKS_EPOCH)
+    }
+
+    /// Get the number of whole ticks of the provided length since the provided Unix timestamp.
+    const fn ticks(self, nanoseconds_per_tick: i128, epoch: i64) -> i128 {
+        div_floor!(
+            self.unix_timestamp_nanos() - epoch as i128 * 1_000_000_000,
+            nanoseconds_per_tick
+        )
+    }
+
+    /// Get the NTP era and 64-bit NTP timestamp. The upper 32 bits of the timestamp are the
+    /// seconds since the start of the era and the lower 32 bits are the fraction of the second,
+    /// rounded to the nearest representable value.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(1900-01-01 0:00 UTC).ntp_timestamp(), (0, 0));
+    /// assert_eq!(
+    ///     datetime!(2022-01-01 0:00:00.5 UTC).ntp_timestamp(),
+    ///     (0, 3_849_984_000 << 32 | 1 << 31)
+    /// );
+    /// assert_eq!(datetime!(2036-02-07 6:28:16 UTC).ntp_timestamp(), (1, 0));
+    /// assert_eq!(datetime!(1899-12-31 23:59:59 UTC).ntp_timestamp(), (-1, 0xFFFF_FFFF << 32));
+    /// ```
+    pub const fn ntp_timestamp(self) -> (i32, u64) {
+        let seconds = self.unix_timestamp() - NTP_EPOCH;
+        let era = div_floor!(seconds, 1 << 32);
+        let fraction =
+            (((self.utc_datetime.nanosecond() as u64) << 32) + 500_000_000) / 1_000_000_000;
+        (
+            era as _,
+            ((seconds - era * (1 << 32)) as u64) << 32 | fraction,
+        )
+    }
+
+    /// Get the Cocoa absolute time, the number of seconds since January 1, 2001 UTC as used by
+    /// `CFAbsoluteTime` and `NSDate`.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2001-01-01 0:00 UTC).cocoa_absolute_time(), 0.);
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 0:00:00.5 UTC).cocoa_absolute_time(),
+    ///     -978_307_199.5
+    /// );
+    /// ```
+    pub fn cocoa_absolute_time(self) -> f64 {
+        self.fractional_seconds(COCOA_EPOCH)
+    }
+
+    /// Get the fractional number of seconds since the provided Unix timestamp.
+    pub(crate) fn fractional_seconds(self, epoch: i64) -> f64 {
+        (self.unix_timestamp() - epoch) as f64
+            + self.utc_datetime.nanosecond() as f64 / 1_000_000_000.
+    }
+
+    /// Get the serial date in the 1900 date system of Excel, in UTC. See
+    /// [`PrimitiveDateTime::excel_1900_serial`] for details.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2022-01-01 18:00 UTC).excel_1900_serial(), 44_562.75);
+    /// assert_eq!(datetime!(2022-01-01 19:00 +1).excel_1900_serial(), 44_562.75);
+    /// ```
+    pub fn excel_1900_serial(self) -> f64 {
+        self.utc_datetime.excel_1900_serial()
+    }
+
+    /// Get the serial date in the 1904 date system of Excel, in UTC. See
+    /// [`PrimitiveDateTime::excel_1904_serial`] for details.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2022-01-01 18:00 UTC).excel_1904_serial(), 43_100.75);
+    /// ```
+    pub fn excel_1904_serial(self) -> f64 {
+        self.utc_datetime.excel_1904_serial()
+    }
+
     /// Get the [`Date`] in the stored offset.
     ///
     /// ```rust
@@ -246,7 +733,10 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn date(self) -> Date {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
+        // A leap second is cascaded as the final second of the minute so that it is retained.
+        let is_leap_second = self.utc_datetime.time.is_leap_second();
+        let mut second = self.utc_datetime.second() as i8 - is_leap_second as i8
+            + self.offset.seconds_past_minute();
         let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
         let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
         let (mut year, mut ordinal) = self.utc_datetime.date.to_ordinal_date();
@@ -272,7 +762,9 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn time(self) -> Time {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
+        let is_leap_second = self.utc_datetime.time.is_leap_second();
+        let mut second = self.utc_datetime.second() as i8 - is_leap_second as i8
+            + self.offset.seconds_past_minute();
         let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
         let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
 
@@ -282,7 +774,7 @@ impl OffsetDateTime {
         Time::__from_hms_nanos_unchecked(
             hour.rem_euclid(24) as _,
             minute as _,
-            second as _,
+            (second + is_leap_second as i8) as _,
             self.utc_datetime.nanosecond(),
         )
     }
@@ -302,7 +794,9 @@ impl OffsetDateTime {
     /// assert_eq!(datetime!(2020-01-01 0:00 UTC).year(), 2020);
     /// ```
     pub const fn year(self) -> i32 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
+        let is_leap_second = self.utc_datetime.time.is_leap_second();
+        let mut second = self.utc_datetime.second() as i8 - is_leap_second as i8
+            + self.offset.seconds_past_minute();
         let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
         let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
         let (mut year, mut ordinal) = self.utc_datetime.date.to_ordinal_date();
@@ -365,7 +859,9 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn ordinal(self) -> u16 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
+        let is_leap_second = self.utc_datetime.time.is_leap_second();
+        let mut second = self.utc_datetime.second() as i8 - is_leap_second as i8
+            + self.offset.seconds_past_minute();
         let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
         let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
         let (mut year, mut ordinal) = self.utc_datetime.date.to_ordinal_date();
@@ -585,7 +1081,9 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn hour(self) -> u8 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
+        let is_leap_second = self.utc_datetime.time.is_leap_second();
+        let mut second = self.utc_datetime.second() as i8 - is_leap_second as i8
+            + self.offset.seconds_past_minute();
         let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
         let mut hour = self.utc_datetime.hour() as i8 + self.offset.whole_hours();
 
@@ -609,7 +1107,9 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn minute(self) -> u8 {
-        let mut second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
+        let is_leap_second = self.utc_datetime.time.is_leap_second();
+        let mut second = self.utc_datetime.second() as i8 - is_leap_second as i8
+            + self.offset.seconds_past_minute();
         let mut minute = self.utc_datetime.minute() as i8 + self.offset.minutes_past_hour();
 
         cascade!(second in 0..60 => minute);
@@ -618,7 +1118,8 @@ impl OffsetDateTime {
 
     /// Get the second within the minute in the stored offset.
     ///
-    /// The returned value will always be in the range `0..60`.
+    /// The returned value will always be in the range `0..60`, unless the value is a leap second,
+    /// in which case it is `60`.
     ///
     /// ```rust
     /// # use time::macros::{datetime, offset};
@@ -631,8 +1132,10 @@ impl OffsetDateTime {
     /// );
     /// ```
     pub const fn second(self) -> u8 {
-        let second = self.utc_datetime.second() as i8 + self.offset.seconds_past_minute();
-        second.rem_euclid(60) as _
+        let is_leap_second = self.utc_datetime.time.is_leap_second();
+        let second = self.utc_datetime.second() as i8 - is_leap_second as i8
+            + self.offset.seconds_past_minute();
+        second.rem_euclid(60) as u8 + is_leap_second as u8
     }
 
     // Because a `UtcOffset` is limited in resolution to one second, any subsecond value will not
@@ -949,6 +1452,28 @@ impl OffsetDateTime {
     ) -> Result<Self, error::Parse> {
         description.parse_offset_date_time(input.as_bytes())
     }
+
+    /// Parse a `OffsetDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the remainder of the input.
+    ///
+    /// Unlike [`OffsetDateTime::parse`], it is not an error for the input to continue after the
+    /// `OffsetDateTime`.
+    ///
+    /// ```rust
+    /// # use time::{format_description::well_known::Rfc3339, macros::datetime, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_prefix("2020-01-02T03:04:05Z rest of line", &Rfc3339)?,
+    ///     (datetime!(2020-01-02 03:04:05 UTC), " rest of line")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (value, remaining) = description.parse_offset_date_time_prefix(input.as_bytes())?;
+        Ok((value, parsing::remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for OffsetDateTime {
@@ -0,0 +1,191 @@
+//! Helpers for implementing parsing for the ASN.1 `UTCTime` and `GeneralizedTime` formats.
+
+use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+use crate::parsing::combinator::{any_digit, ascii_char, exactly_n_digits, sign};
+use crate::parsing::{Parsed, ParsedItem};
+use crate::{error, Month};
+
+/// Parse the month, day, and hour, which are common to both formats.
+fn month_day_hour<'a>(input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+    let input = exactly_n_digits::<_, 2>(input)
+        .ok_or(InvalidComponent("month"))?
+        .flat_map_res(Month::from_number)
+        .map_err(error::TryFromParsed::ComponentRange)?
+        .assign_value_to(&mut parsed.month);
+    let input = exactly_n_digits::<_, 2>(input)
+        .ok_or(InvalidComponent("day"))?
+        .assign_value_to(&mut parsed.day);
+    let input = exactly_n_digits::<_, 2>(input)
+        .ok_or(InvalidComponent("hour"))?
+        .assign_value_to(&mut parsed.hour_24);
+    Ok(input)
+}
+
+/// Parse a UTC offset of the form `Z`, `±hh`, or `±hhmm`. The minutes may only be omitted if
+/// `minutes_are_required` is false.
+fn offset<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    minutes_are_required: bool,
+) -> Result<&'a [u8], error::Parse> {
+    if let Some(ParsedItem(input, ())) = ascii_char::<b'Z'>(input) {
+        parsed.offset_hour = Some(0);
+        parsed.offset_minute = Some(0);
+        parsed.offset_second = Some(0);
+        return Ok(input);
+    }
+
+    let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+    let input = exactly_n_digits::<u8, 2>(input)
+        .ok_or(InvalidComponent("offset hour"))?
+        .map(|offset_hour| {
+            if offset_sign == b'-' {
+                -(offset_hour as i8)
+            } else {
+                offset_hour as _
+            }
+        })
+        .assign_value_to(&mut parsed.offset_hour);
+    let input = match exactly_n_digits::<_, 2>(input) {
+        Some(item) => item.assign_value_to(&mut parsed.offset_minute),
+        None if minutes_are_required => return Err(InvalidComponent("offset minute").into()),
+        None => {
+            parsed.offset_minute = Some(0);
+            input
+        }
+    };
+    parsed.offset_second = Some(0);
+
+    Ok(input)
+}
+
+/// Parse the `UTCTime` format.
+pub(crate) fn parse_utc_time<'a, const IS_DER: bool>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Parse> {
+    let input = exactly_n_digits::<u8, 2>(input)
+        .ok_or(InvalidComponent("year"))?
+        .map(|year| {
+            if year < 50 {
+                2000 + year as i32
+            } else {
+                1900 + year as i32
+            }
+        })
+        .assign_value_to(&mut parsed.year);
+    let input = month_day_hour(input, parsed)?;
+    let input = exactly_n_digits::<_, 2>(input)
+        .ok_or(InvalidComponent("minute"))?
+        .assign_value_to(&mut parsed.minute);
+    let input = match exactly_n_digits::<_, 2>(input) {
+        Some(item) => item.assign_value_to(&mut parsed.second),
+        None if IS_DER => return Err(InvalidComponent("second").into()),
+        None => {
+            parsed.second = Some(0);
+            input
+        }
+    };
+
+    if IS_DER {
+        let input = ascii_char::<b'Z'>(input)
+            .ok_or(InvalidLiteral)?
+            .into_inner();
+        parsed.offset_hour = Some(0);
+        parsed.offset_minute = Some(0);
+        parsed.offset_second = Some(0);
+        Ok(input)
+    } else {
+        offset(input, parsed, true)
+    }
+}
+
+/// Parse the `GeneralizedTime` format.
+pub(crate) fn parse_generalized_time<'a, const IS_DER: bool>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Parse> {
+    /// The number of nanoseconds in one second.
+    const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+    let input = exactly_n_digits::<u32, 4>(input)
+        .ok_or(InvalidComponent("year"))?
+        .map(|year| year as _)
+        .assign_value_to(&mut parsed.year);
+    let input = month_day_hour(input, parsed)?;
+
+    // The number of nanoseconds in the last unit present, which a fraction is applied to.
+    let (input, unit_nanos) = match exactly_n_digits::<_, 2>(input) {
+        Some(item) => {
+            let input = item.assign_value_to(&mut parsed.minute);
+            match exactly_n_digits::<_, 2>(input) {
+                Some(item) => (item.assign_value_to(&mut parsed.second), NANOS_PER_SECOND),
+                None if IS_DER => return Err(InvalidComponent("second").into()),
+                None => {
+                    parsed.second = Some(0);
+                    (input, 60 * NANOS_PER_SECOND)
+                }
+            }
+        }
+        None if IS_DER => return Err(InvalidComponent("minute").into()),
+        None => {
+            parsed.minute = Some(0);
+            parsed.second = Some(0);
+            (input, 3_600 * NANOS_PER_SECOND)
+        }
+    };
+
+    let separator = ascii_char::<b'.'>(input).or_else(|| {
+        if IS_DER {
+            None
+        } else {
+            ascii_char::<b','>(input)
+        }
+    });
+    let input = if let Some(ParsedItem(mut input, ())) = separator {
+        let mut numerator = 0_u64;
+        let mut denominator = 1_u64;
+        let mut last_digit = None;
+        while let Some(ParsedItem(remaining, digit)) = any_digit(input) {
+            // Digits beyond the precision that can be represented are ignored.
+            if denominator < 10_u64.pow(18) {
+                numerator = numerator * 10 + (digit - b'0') as u64;
+                denominator *= 10;
+            }
+            last_digit = Some(digit);
+            input = remaining;
+        }
+        // The fraction must not be empty, and DER forbids trailing zeros.
+        match last_digit {
+            None => return Err(InvalidComponent("subsecond").into()),
+            Some(b'0') if IS_DER => return Err(InvalidComponent("subsecond").into()),
+            Some(_) => {}
+        }
+
+        let nanos = (numerator as u128 * unit_nanos as u128 / denominator as u128) as u64;
+        if unit_nanos > 60 * NANOS_PER_SECOND {
+            parsed.minute = Some((nanos / (60 * NANOS_PER_SECOND)) as _);
+        }
+        if unit_nanos > NANOS_PER_SECOND {
+            parsed.second = Some((nanos / NANOS_PER_SECOND % 60) as _);
+        }
+        parsed.subsecond = Some((nanos % NANOS_PER_SECOND) as _);
+        input
+    } else {
+        input
+    };
+
+    if IS_DER {
+        let input = ascii_char::<b'Z'>(input)
+            .ok_or(InvalidLiteral)?
+            .into_inner();
+        parsed.offset_hour = Some(0);
+        parsed.offset_minute = Some(0);
+        parsed.offset_second = Some(0);
+        Ok(input)
+    } else if matches!(input, [b'Z' | b'+' | b'-', ..]) {
+        offset(input, parsed, false)
+    } else {
+        Ok(input)
+    }
+}
@@ -38,6 +38,32 @@ pub(crate) fn first_match<'a, T>(
     }
 }
 
+/// Consume the longest matching item, returning its associated value. If multiple items of the
+/// same length match, the first is used.
+pub(crate) fn longest_match<'a, T>(
+    options: impl IntoIterator<Item = (&'a [u8], T)>,
+    case_sensitive: bool,
+) -> impl FnOnce(&[u8]) -> Option<ParsedItem<'_, T>> {
+    move |input| {
+        let mut longest: Option<(usize, T)> = None;
+        for (expected, t) in options {
+            let n = expected.len();
+            if matches!(longest, Some((longest_n, _)) if longest_n >= n) {
+                continue;
+            }
+            let is_match = match input.get(..n) {
+                Some(head) if case_sensitive => head == expected,
+                Some(head) => head.eq_ignore_ascii_case(expected),
+                None => false,
+            };
+            if is_match {
+                longest = Some((n, t));
+            }
+        }
+        longest.map(|(n, t)| ParsedItem(&input[n..], t))
+    }
+}
+
 /// Consume zero or more instances of the provided parser. The parser must return the unit value.
 pub(crate) fn zero_or_more<'a, P: Fn(&'a [u8]) -> Option<ParsedItem<'a, ()>>>(
     parser: P,
@@ -190,3 +216,43 @@ pub(crate) fn opt<'a, T>(
         None => ParsedItem(input, None),
     }
 }
+
+/// Add a value in the given unit, written as its integer `digits` and decimal `fraction` digits,
+/// to a running total of nanoseconds. Digits beyond nanosecond precision are ignored.
+///
+/// Returns `None` if the total cannot be represented by a [`Duration`](crate::Duration) with the
+/// provided sign.
+pub(crate) fn add_duration_component(
+    nanoseconds: i128,
+    digits: &[u8],
+    fraction: &[u8],
+    nanos_per_unit: i128,
+    is_negative: bool,
+) -> Option<i128> {
+    /// The number of nanoseconds in one second.
+    const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+    // The magnitude of `Duration::MIN` is one second larger than that of `Duration::MAX`.
+    let max_whole_seconds = i64::MAX as i128 + is_negative as i128;
+
+    let mut value: i128 = 0;
+    for &digit in digits {
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as _)?;
+    }
+    let mut numerator: i128 = 0;
+    let mut denominator: i128 = 1;
+    for &digit in fraction.iter().take(9) {
+        numerator = numerator * 10 + (digit - b'0') as i128;
+        denominator *= 10;
+    }
+
+    let nanoseconds = value
+        .checked_mul(nanos_per_unit)?
+        .checked_add(numerator * nanos_per_unit / denominator)?
+        .checked_add(nanoseconds)?;
+    if nanoseconds / NANOS_PER_SECOND <= max_whole_seconds {
+        Some(nanoseconds)
+    } else {
+        None
+    }
+}
@@ -6,3 +6,4 @@
 
 pub(crate) mod rfc2234;
 pub(crate) mod rfc2822;
+pub(crate) mod rfc9110;
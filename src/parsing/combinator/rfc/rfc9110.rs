@@ -0,0 +1,60 @@
+//! Rules defined in [RFC 9110].
+//!
+//! [RFC 9110]: https://datatracker.ietf.org/doc/html/rfc9110
+
+use crate::parsing::combinator::first_match;
+use crate::parsing::ParsedItem;
+use crate::{Month, Weekday};
+
+/// Consume the `day-name` rule, which is a three-letter abbreviation.
+pub(crate) fn day_name(input: &[u8]) -> Option<ParsedItem<'_, Weekday>> {
+    first_match(
+        [
+            (&b"Mon"[..], Weekday::Monday),
+            (&b"Tue"[..], Weekday::Tuesday),
+            (&b"Wed"[..], Weekday::Wednesday),
+            (&b"Thu"[..], Weekday::Thursday),
+            (&b"Fri"[..], Weekday::Friday),
+            (&b"Sat"[..], Weekday::Saturday),
+            (&b"Sun"[..], Weekday::Sunday),
+        ],
+        true,
+    )(input)
+}
+
+/// Consume the `day-name-l` rule, which is the full name of the day.
+pub(crate) fn day_name_l(input: &[u8]) -> Option<ParsedItem<'_, Weekday>> {
+    first_match(
+        [
+            (&b"Monday"[..], Weekday::Monday),
+            (&b"Tuesday"[..], Weekday::Tuesday),
+            (&b"Wednesday"[..], Weekday::Wednesday),
+            (&b"Thursday"[..], Weekday::Thursday),
+            (&b"Friday"[..], Weekday::Friday),
+            (&b"Saturday"[..], Weekday::Saturday),
+            (&b"Sunday"[..], Weekday::Sunday),
+        ],
+        true,
+    )(input)
+}
+
+/// Consume the `month` rule, which is a three-letter abbreviation.
+pub(crate) fn month(input: &[u8]) -> Option<ParsedItem<'_, Month>> {
+    first_match(
+        [
+            (&b"Jan"[..], Month::January),
+            (&b"Feb"[..], Month::February),
+            (&b"Mar"[..], Month::March),
+            (&b"Apr"[..], Month::April),
+            (&b"May"[..], Month::May),
+            (&b"Jun"[..], Month::June),
+            (&b"Jul"[..], Month::July),
+            (&b"Aug"[..], Month::August),
+            (&b"Sep"[..], Month::September),
+            (&b"Oct"[..], Month::October),
+            (&b"Nov"[..], Month::November),
+            (&b"Dec"[..], Month::December),
+        ],
+        true,
+    )(input)
+}
@@ -3,10 +3,12 @@
 use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::format_description::modifier;
+use crate::locale::Locale;
 #[cfg(feature = "large-dates")]
 use crate::parsing::combinator::n_to_m_digits_padded;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, opt, sign,
+    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, longest_match,
+    n_to_m_digits, opt, sign,
 };
 use crate::parsing::ParsedItem;
 use crate::{Month, Weekday};
@@ -36,49 +38,34 @@ pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<Pars
 }
 
 /// Parse the "month" component of a `Date`.
-pub(crate) fn parse_month(
-    input: &[u8],
+pub(crate) fn parse_month<'a>(
+    input: &'a [u8],
     modifiers: modifier::Month,
-) -> Option<ParsedItem<'_, Month>> {
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, Month>> {
     use Month::*;
-    let ParsedItem(remaining, value) = first_match(
-        match modifiers.repr {
-            modifier::MonthRepr::Numerical => {
-                return exactly_n_digits_padded::<_, 2>(modifiers.padding)(input)?
-                    .flat_map(|n| Month::from_number(n).ok());
-            }
-            modifier::MonthRepr::Long => [
-                (&b"January"[..], January),
-                (&b"February"[..], February),
-                (&b"March"[..], March),
-                (&b"April"[..], April),
-                (&b"May"[..], May),
-                (&b"June"[..], June),
-                (&b"July"[..], July),
-                (&b"August"[..], August),
-                (&b"September"[..], September),
-                (&b"October"[..], October),
-                (&b"November"[..], November),
-                (&b"December"[..], December),
-            ],
-            modifier::MonthRepr::Short => [
-                (&b"Jan"[..], January),
-                (&b"Feb"[..], February),
-                (&b"Mar"[..], March),
-                (&b"Apr"[..], April),
-                (&b"May"[..], May),
-                (&b"Jun"[..], June),
-                (&b"Jul"[..], July),
-                (&b"Aug"[..], August),
-                (&b"Sep"[..], September),
-                (&b"Oct"[..], October),
-                (&b"Nov"[..], November),
-                (&b"Dec"[..], December),
-            ],
-        },
-        modifiers.case_sensitive,
-    )(input)?;
-    Some(ParsedItem(remaining, value))
+    let months = [
+        January, February, March, April, May, June, July, August, September, October, November,
+        December,
+    ];
+    match modifiers.repr {
+        modifier::MonthRepr::Numerical => {
+            exactly_n_digits_padded::<_, 2>(modifiers.padding)(input)?
+                .flat_map(|n| Month::from_number(n).ok())
+        }
+        modifier::MonthRepr::Long => longest_match(
+            months
+                .iter()
+                .map(|&month| (locale.month_name(month).as_bytes(), month)),
+            modifiers.case_sensitive,
+        )(input),
+        modifier::MonthRepr::Short => longest_match(
+            months
+                .iter()
+                .map(|&month| (locale.month_abbreviation(month).as_bytes(), month)),
+            modifiers.case_sensitive,
+        )(input),
+    }
 }
 
 /// Parse the "week number" component of a `Date`.
@@ -90,30 +77,38 @@ pub(crate) fn parse_week_number(
 }
 
 /// Parse the "weekday" component of a `Date`.
-pub(crate) fn parse_weekday(
-    input: &[u8],
+pub(crate) fn parse_weekday<'a>(
+    input: &'a [u8],
     modifiers: modifier::Weekday,
-) -> Option<ParsedItem<'_, Weekday>> {
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, Weekday>> {
+    let weekdays = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
     first_match(
         match (modifiers.repr, modifiers.one_indexed) {
-            (modifier::WeekdayRepr::Short, _) => [
-                (&b"Mon"[..], Weekday::Monday),
-                (&b"Tue"[..], Weekday::Tuesday),
-                (&b"Wed"[..], Weekday::Wednesday),
-                (&b"Thu"[..], Weekday::Thursday),
-                (&b"Fri"[..], Weekday::Friday),
-                (&b"Sat"[..], Weekday::Saturday),
-                (&b"Sun"[..], Weekday::Sunday),
-            ],
-            (modifier::WeekdayRepr::Long, _) => [
-                (&b"Monday"[..], Weekday::Monday),
-                (&b"Tuesday"[..], Weekday::Tuesday),
-                (&b"Wednesday"[..], Weekday::Wednesday),
-                (&b"Thursday"[..], Weekday::Thursday),
-                (&b"Friday"[..], Weekday::Friday),
-                (&b"Saturday"[..], Weekday::Saturday),
-                (&b"Sunday"[..], Weekday::Sunday),
-            ],
+            (modifier::WeekdayRepr::Short, _) => {
+                return longest_match(
+                    weekdays
+                        .iter()
+                        .map(|&weekday| (locale.weekday_abbreviation(weekday).as_bytes(), weekday)),
+                    modifiers.case_sensitive,
+                )(input);
+            }
+            (modifier::WeekdayRepr::Long, _) => {
+                return longest_match(
+                    weekdays
+                        .iter()
+                        .map(|&weekday| (locale.weekday_name(weekday).as_bytes(), weekday)),
+                    modifiers.case_sensitive,
+                )(input);
+            }
             (modifier::WeekdayRepr::Sunday, false) => [
                 (&b"1"[..], Weekday::Monday),
                 (&b"2"[..], Weekday::Tuesday),
@@ -164,11 +159,18 @@ pub(crate) fn parse_ordinal(
 }
 
 /// Parse the "day" component of a `Date`.
-pub(crate) fn parse_day(
-    input: &[u8],
+pub(crate) fn parse_day<'a>(
+    input: &'a [u8],
     modifiers: modifier::Day,
-) -> Option<ParsedItem<'_, NonZeroU8>> {
-    exactly_n_digits_padded::<_, 2>(modifiers.padding)(input)
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, NonZeroU8>> {
+    let item = exactly_n_digits_padded::<NonZeroU8, 2>(modifiers.padding)(input)?;
+    if modifiers.ordinal_suffix {
+        let suffix = locale.ordinal_suffix(item.1.get());
+        Some(ParsedItem(item.0.strip_prefix(suffix.as_bytes())?, item.1))
+    } else {
+        Some(item)
+    }
 }
 // endregion date components
 
@@ -204,16 +206,22 @@ pub(crate) fn parse_second(
 }
 
 /// Parse the "period" component of a `Time`. Required if the hour is on a 12-hour clock.
-pub(crate) fn parse_period(
-    input: &[u8],
+pub(crate) fn parse_period<'a>(
+    input: &'a [u8],
     modifiers: modifier::Period,
-) -> Option<ParsedItem<'_, Period>> {
-    first_match(
-        if modifiers.is_uppercase {
-            [(&b"AM"[..], Period::Am), (&b"PM"[..], Period::Pm)]
-        } else {
-            [(&b"am"[..], Period::Am), (&b"pm"[..], Period::Pm)]
-        },
+    locale: &dyn Locale,
+) -> Option<ParsedItem<'a, Period>> {
+    longest_match(
+        [
+            (
+                locale.period_name(false, modifiers.is_uppercase).as_bytes(),
+                Period::Am,
+            ),
+            (
+                locale.period_name(true, modifiers.is_uppercase).as_bytes(),
+                Period::Pm,
+            ),
+        ],
         modifiers.case_sensitive,
     )(input)
 }
@@ -282,3 +290,58 @@ pub(crate) fn parse_offset_second(
     exactly_n_digits_padded::<_, 2>(modifiers.padding)(input)
 }
 // endregion offset components
+
+// region: timestamp components
+/// Parse the "unix_timestamp" component, returning the number of nanoseconds since the Unix
+/// epoch.
+pub(crate) fn parse_unix_timestamp(
+    input: &[u8],
+    modifiers: modifier::UnixTimestamp,
+) -> Option<ParsedItem<'_, i128>> {
+    let ParsedItem(input, sign) = opt(sign)(input);
+    let ParsedItem(input, nanos) = match modifiers.precision {
+        modifier::UnixTimestampPrecision::Second => {
+            n_to_m_digits::<u128, 1, 14>(input)?.map(|value| value * 1_000_000_000)
+        }
+        modifier::UnixTimestampPrecision::Millisecond => {
+            n_to_m_digits::<u128, 1, 17>(input)?.map(|value| value * 1_000_000)
+        }
+        modifier::UnixTimestampPrecision::Microsecond => {
+            n_to_m_digits::<u128, 1, 20>(input)?.map(|value| value * 1_000)
+        }
+        modifier::UnixTimestampPrecision::Nanosecond => n_to_m_digits::<u128, 1, 23>(input)?,
+    };
+    match sign {
+        Some(b'-') => Some(ParsedItem(input, -(nanos as i128))),
+        None if modifiers.sign_is_mandatory => None,
+        _ => Some(ParsedItem(input, nanos as i128)),
+    }
+}
+// endregion timestamp components
+
+// region: special components
+/// Skip the bytes indicated by the "ignore" component.
+pub(crate) fn parse_ignore(
+    input: &[u8],
+    modifiers: modifier::Ignore,
+) -> Option<ParsedItem<'_, ()>> {
+    let count = modifiers.count.map(|count| count.get() as usize);
+    let len = match modifiers.until {
+        Some(until) => input
+            .iter()
+            .take(count.map_or(input.len(), |count| count + 1))
+            .position(|&byte| byte == until)?,
+        None => count.unwrap_or(0),
+    };
+    Some(ParsedItem(input.get(len..)?, ()))
+}
+
+/// Ensure that there is no input remaining.
+pub(crate) const fn parse_end(input: &[u8], _: modifier::End) -> Option<ParsedItem<'_, ()>> {
+    if input.is_empty() {
+        Some(ParsedItem(input, ()))
+    } else {
+        None
+    }
+}
+// endregion special components
@@ -0,0 +1,136 @@
+//! Parsing of human-friendly durations, such as `1h 30m`.
+
+use crate::duration_format::DurationUnit;
+use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+use crate::parsing::combinator::{
+    add_duration_component, any_digit, ascii_char, longest_match, n_to_m, sign,
+};
+use crate::parsing::ParsedItem;
+use crate::{error, Duration};
+
+/// The names accepted for each unit, including the abbreviated and full names used when formatting.
+const UNIT_NAMES: [(&str, DurationUnit); 34] = [
+    ("ns", DurationUnit::Nanosecond),
+    ("nsec", DurationUnit::Nanosecond),
+    ("nanosecond", DurationUnit::Nanosecond),
+    ("nanoseconds", DurationUnit::Nanosecond),
+    ("us", DurationUnit::Microsecond),
+    ("µs", DurationUnit::Microsecond),
+    ("usec", DurationUnit::Microsecond),
+    ("microsecond", DurationUnit::Microsecond),
+    ("microseconds", DurationUnit::Microsecond),
+    ("ms", DurationUnit::Millisecond),
+    ("msec", DurationUnit::Millisecond),
+    ("millisecond", DurationUnit::Millisecond),
+    ("milliseconds", DurationUnit::Millisecond),
+    ("s", DurationUnit::Second),
+    ("sec", DurationUnit::Second),
+    ("secs", DurationUnit::Second),
+    ("second", DurationUnit::Second),
+    ("seconds", DurationUnit::Second),
+    ("m", DurationUnit::Minute),
+    ("min", DurationUnit::Minute),
+    ("mins", DurationUnit::Minute),
+    ("minute", DurationUnit::Minute),
+    ("minutes", DurationUnit::Minute),
+    ("h", DurationUnit::Hour),
+    ("hr", DurationUnit::Hour),
+    ("hrs", DurationUnit::Hour),
+    ("hour", DurationUnit::Hour),
+    ("hours", DurationUnit::Hour),
+    ("d", DurationUnit::Day),
+    ("day", DurationUnit::Day),
+    ("days", DurationUnit::Day),
+    ("w", DurationUnit::Week),
+    ("week", DurationUnit::Week),
+    ("weeks", DurationUnit::Week),
+];
+
+/// Consume any ASCII whitespace.
+fn whitespace(input: &[u8]) -> &[u8] {
+    let len = input
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    &input[len..]
+}
+
+/// Parse a [`Duration`] written as a sequence of values with units, such as `1h 30m`.
+///
+/// Each value may have a decimal fraction and may be separated from its unit by whitespace.
+/// Values may be separated from each other by whitespace, a comma, or nothing at all. Units may
+/// appear in any order and may be repeated, in which case the values are added together.
+pub(crate) fn parse_duration(input: &[u8]) -> Result<Duration, error::Parse> {
+    /// The number of nanoseconds in one second.
+    const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+    let (mut input, is_negative) = match sign(input) {
+        Some(ParsedItem(input, sign)) => (input, sign == b'-'),
+        None => (input, false),
+    };
+
+    let mut nanoseconds: i128 = 0;
+    let mut has_component = false;
+
+    loop {
+        let mut remaining = input;
+        if has_component {
+            remaining = whitespace(remaining);
+            if let Some(ParsedItem(after_comma, ())) = ascii_char::<b','>(remaining) {
+                remaining = whitespace(after_comma);
+            }
+        }
+
+        let ParsedItem(remaining, digits) =
+            match n_to_m::<_, _, 1, { u8::MAX }>(any_digit)(remaining) {
+                Some(item) => item,
+                None => break,
+            };
+        let (remaining, fraction) = match ascii_char::<b'.'>(remaining) {
+            Some(ParsedItem(remaining, ())) => {
+                let ParsedItem(remaining, fraction) =
+                    n_to_m::<_, _, 1, { u8::MAX }>(any_digit)(remaining).ok_or(InvalidLiteral)?;
+                (remaining, fraction)
+            }
+            None => (remaining, &[][..]),
+        };
+
+        let remaining = whitespace(remaining);
+        let ParsedItem(remaining, unit) = longest_match(
+            UNIT_NAMES
+                .iter()
+                .map(|&(name, unit)| (name.as_bytes(), unit)),
+            false,
+        )(remaining)
+        .ok_or(InvalidLiteral)?;
+        // A unit must not be directly followed by a letter, as in `5 months`.
+        if matches!(remaining.first(), Some(byte) if byte.is_ascii_alphabetic()) {
+            return Err(InvalidLiteral.into());
+        }
+        nanoseconds = add_duration_component(
+            nanoseconds,
+            digits,
+            fraction,
+            unit.nanoseconds() as _,
+            is_negative,
+        )
+        .ok_or(InvalidComponent("duration"))?;
+        input = remaining;
+        has_component = true;
+    }
+
+    if !has_component {
+        return Err(InvalidLiteral.into());
+    }
+    if !input.is_empty() {
+        return Err(error::Parse::UnexpectedTrailingCharacters);
+    }
+
+    if is_negative {
+        nanoseconds = -nanoseconds;
+    }
+    Ok(Duration::new(
+        (nanoseconds / NANOS_PER_SECOND) as _,
+        (nanoseconds % NANOS_PER_SECOND) as _,
+    ))
+}
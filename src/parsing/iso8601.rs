@@ -0,0 +1,371 @@
+//! Helpers for implementing parsing for ISO 8601.
+
+use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+use crate::parsing::combinator::{
+    add_duration_component, any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits,
+    n_to_m, sign,
+};
+use crate::parsing::{Parsed, ParsedItem};
+use crate::{error, Duration, Month, Weekday};
+
+/// The notation used by the input.
+///
+/// The basic and extended notations may not be mixed in a single value. As the notation can only be
+/// determined from the separators, it is not known until the first place a separator may appear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Notation {
+    /// The notation has not yet been determined.
+    Unknown,
+    /// The basic notation, without separators.
+    Basic,
+    /// The extended notation, with separators.
+    Extended,
+}
+
+impl Notation {
+    /// Determine the notation from the presence or absence of a separator. Returns `None` if this
+    /// conflicts with the notation previously determined.
+    #[allow(clippy::missing_const_for_fn)] // const `&mut` requires Rust 1.83
+    fn coerce(&mut self, separator_is_present: bool) -> Option<()> {
+        match (*self, separator_is_present) {
+            (Self::Unknown, true) => *self = Self::Extended,
+            (Self::Unknown, false) => *self = Self::Basic,
+            (Self::Basic, false) | (Self::Extended, true) => {}
+            (Self::Basic, true) | (Self::Extended, false) => return None,
+        }
+        Some(())
+    }
+}
+
+/// Consume the separator, which is only present in the extended notation.
+fn separator<'a, const CHAR: u8>(
+    input: &'a [u8],
+    notation: &mut Notation,
+) -> Result<&'a [u8], error::Parse> {
+    let (input, separator_is_present) = match ascii_char::<CHAR>(input) {
+        Some(ParsedItem(input, ())) => (input, true),
+        None => (input, false),
+    };
+    notation
+        .coerce(separator_is_present)
+        .ok_or(InvalidLiteral)?;
+    Ok(input)
+}
+
+/// Parse the date portion of ISO 8601, which may be a calendar, week, or ordinal date.
+pub(crate) fn parse_date<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    notation: &mut Notation,
+) -> Result<&'a [u8], error::Parse> {
+    let ParsedItem(input, year) = match sign(input) {
+        Some(ParsedItem(input, sign)) => exactly_n_digits::<u32, 6>(input)
+            .ok_or(InvalidComponent("year"))?
+            .map(|year| {
+                if sign == b'-' {
+                    -(year as i32)
+                } else {
+                    year as _
+                }
+            }),
+        None => exactly_n_digits::<u32, 4>(input)
+            .ok_or(InvalidComponent("year"))?
+            .map(|year| year as _),
+    };
+    let input = separator::<b'-'>(input, notation)?;
+
+    // Week date
+    if let Some(ParsedItem(input, ())) = ascii_char::<b'W'>(input) {
+        parsed.iso_year = Some(year);
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("week number"))?
+            .assign_value_to(&mut parsed.iso_week_number);
+        let input = separator::<b'-'>(input, notation)?;
+        let input = any_digit(input)
+            .and_then(|item| {
+                item.flat_map(|digit| match digit {
+                    b'1' => Some(Weekday::Monday),
+                    b'2' => Some(Weekday::Tuesday),
+                    b'3' => Some(Weekday::Wednesday),
+                    b'4' => Some(Weekday::Thursday),
+                    b'5' => Some(Weekday::Friday),
+                    b'6' => Some(Weekday::Saturday),
+                    b'7' => Some(Weekday::Sunday),
+                    _ => None,
+                })
+            })
+            .ok_or(InvalidComponent("weekday"))?
+            .assign_value_to(&mut parsed.weekday);
+        return Ok(input);
+    }
+
+    // A calendar date has a month and day, which is one digit more than the day of an ordinal date
+    // in the basic notation. In the extended notation, they are separated.
+    let is_calendar_date = if *notation == Notation::Extended {
+        exactly_n_digits::<u8, 2>(input)
+            .and_then(|ParsedItem(input, _)| ascii_char::<b'-'>(input))
+            .is_some()
+    } else {
+        exactly_n_digits::<u16, 4>(input).is_some()
+    };
+
+    parsed.year = Some(year);
+    if is_calendar_date {
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("month"))?
+            .flat_map_res(Month::from_number)
+            .map_err(error::TryFromParsed::ComponentRange)?
+            .assign_value_to(&mut parsed.month);
+        let input = separator::<b'-'>(input, notation)?;
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("day"))?
+            .assign_value_to(&mut parsed.day);
+        Ok(input)
+    } else {
+        let input = exactly_n_digits::<_, 3>(input)
+            .ok_or(InvalidComponent("ordinal"))?
+            .assign_value_to(&mut parsed.ordinal);
+        Ok(input)
+    }
+}
+
+/// Parse the time portion of ISO 8601, excluding the leading `T`.
+///
+/// The time may be reduced to the hour or minute, and the smallest unit present may have a decimal
+/// fraction, which is separated by either a period or a comma.
+pub(crate) fn parse_time<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    notation: &mut Notation,
+) -> Result<&'a [u8], error::Parse> {
+    /// The number of nanoseconds in one second.
+    const NANOS_PER_SECOND: u64 = 1_000_000_000;
+    /// The number of nanoseconds in one minute.
+    const NANOS_PER_MINUTE: u64 = 60 * NANOS_PER_SECOND;
+    /// The number of nanoseconds in one hour.
+    const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MINUTE;
+
+    let ParsedItem(input, hour) =
+        exactly_n_digits::<u8, 2>(input).ok_or(InvalidComponent("hour"))?;
+    let mut minute = 0;
+    let mut nanos_past_minute = 0;
+
+    // Each smallest unit is followed by either a larger unit or the fraction, if any.
+    let (input, nanos_per_unit) = match next_unit(input, notation)? {
+        None => (input, NANOS_PER_HOUR),
+        Some(input) => {
+            let ParsedItem(input, value) =
+                exactly_n_digits::<u8, 2>(input).ok_or(InvalidComponent("minute"))?;
+            minute = value;
+            match next_unit(input, notation)? {
+                None => (input, NANOS_PER_MINUTE),
+                Some(input) => {
+                    let ParsedItem(input, second) =
+                        exactly_n_digits::<u8, 2>(input).ok_or(InvalidComponent("second"))?;
+                    nanos_past_minute = second as u64 * NANOS_PER_SECOND;
+                    (input, NANOS_PER_SECOND)
+                }
+            }
+        }
+    };
+
+    let input = match ascii_char::<b'.'>(input).or_else(|| ascii_char::<b','>(input)) {
+        Some(ParsedItem(input, ())) => {
+            let ParsedItem(mut input, digit) =
+                any_digit(input).ok_or(InvalidComponent("subsecond"))?;
+            let mut numerator = (digit - b'0') as u64;
+            let mut denominator = 10;
+            while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
+                // Digits beyond nanosecond precision are ignored.
+                if denominator < NANOS_PER_SECOND {
+                    numerator = numerator * 10 + (digit - b'0') as u64;
+                    denominator *= 10;
+                }
+                input = new_input;
+            }
+
+            let nanos = (numerator as u128 * nanos_per_unit as u128 / denominator as u128) as u64;
+            minute += (nanos / NANOS_PER_MINUTE) as u8;
+            nanos_past_minute += nanos % NANOS_PER_MINUTE;
+            input
+        }
+        None => input,
+    };
+
+    parsed.hour_24 = Some(hour);
+    parsed.minute = Some(minute);
+    parsed.second = Some((nanos_past_minute / NANOS_PER_SECOND) as _);
+    parsed.subsecond = Some((nanos_past_minute % NANOS_PER_SECOND) as _);
+
+    Ok(input)
+}
+
+/// Determine whether another unit of the time follows, consuming the separator if present. The
+/// notation must be consistent with that previously determined.
+fn next_unit<'a>(
+    input: &'a [u8],
+    notation: &mut Notation,
+) -> Result<Option<&'a [u8]>, error::Parse> {
+    let (input, separator_is_present) = match ascii_char::<b':'>(input) {
+        Some(ParsedItem(input, ())) => (input, true),
+        None if any_digit(input).is_some() => (input, false),
+        None => return Ok(None),
+    };
+    notation
+        .coerce(separator_is_present)
+        .ok_or(InvalidLiteral)?;
+    Ok(Some(input))
+}
+
+/// Parse the UTC offset portion of ISO 8601, which is either `Z` or an offset in hours, optionally
+/// followed by minutes.
+pub(crate) fn parse_offset<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    notation: &mut Notation,
+) -> Result<&'a [u8], error::Parse> {
+    if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
+        parsed.offset_hour = Some(0);
+        parsed.offset_minute = Some(0);
+        parsed.offset_second = Some(0);
+        return Ok(input);
+    }
+
+    let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+    let input = exactly_n_digits::<u8, 2>(input)
+        .ok_or(InvalidComponent("offset hour"))?
+        .map(|offset_hour| {
+            if offset_sign == b'-' {
+                -(offset_hour as i8)
+            } else {
+                offset_hour as _
+            }
+        })
+        .assign_value_to(&mut parsed.offset_hour);
+
+    let input = match next_unit(input, notation)? {
+        Some(input) => exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("offset minute"))?
+            .assign_value_to(&mut parsed.offset_minute),
+        None => {
+            parsed.offset_minute = Some(0);
+            input
+        }
+    };
+    parsed.offset_second = Some(0);
+
+    Ok(input)
+}
+
+/// Parse the `T` that precedes the time. It is optional when the time is not preceded by a date.
+pub(crate) fn parse_time_designator(
+    input: &[u8],
+    is_required: bool,
+) -> Result<&[u8], error::Parse> {
+    match ascii_char_ignore_case::<b'T'>(input) {
+        Some(ParsedItem(input, ())) => Ok(input),
+        None if is_required => Err(InvalidLiteral.into()),
+        None => Ok(input),
+    }
+}
+
+/// Parse a [`Duration`] from the ISO 8601 duration format.
+///
+/// Years and months are rejected, as their length is not fixed. Weeks may be combined with the
+/// other units. Only the last unit may have a decimal fraction.
+pub(crate) fn parse_duration(input: &[u8]) -> Result<Duration, error::Parse> {
+    /// The number of nanoseconds in one second.
+    const NANOS_PER_SECOND: i128 = 1_000_000_000;
+    /// The units of the date portion, with the number of nanoseconds in each.
+    const DATE_UNITS: [(u8, &str, i128); 4] = [
+        (b'Y', "years", 0),
+        (b'M', "months", 0),
+        (b'W', "weeks", 604_800 * NANOS_PER_SECOND),
+        (b'D', "days", 86_400 * NANOS_PER_SECOND),
+    ];
+    /// The units of the time portion, with the number of nanoseconds in each.
+    const TIME_UNITS: [(u8, &str, i128); 3] = [
+        (b'H', "hours", 3_600 * NANOS_PER_SECOND),
+        (b'M', "minutes", 60 * NANOS_PER_SECOND),
+        (b'S', "seconds", NANOS_PER_SECOND),
+    ];
+
+    let (input, is_negative) = match sign(input) {
+        Some(ParsedItem(input, sign)) => (input, sign == b'-'),
+        None => (input, false),
+    };
+    let mut input = ascii_char::<b'P'>(input)
+        .ok_or(InvalidLiteral)?
+        .into_inner();
+
+    let mut nanoseconds: i128 = 0;
+    let mut units = &DATE_UNITS[..];
+    let mut in_time = false;
+    let mut has_component = false;
+    let mut has_fraction = false;
+
+    loop {
+        if !in_time {
+            if let Some(ParsedItem(remaining, ())) = ascii_char::<b'T'>(input) {
+                input = remaining;
+                units = &TIME_UNITS;
+                in_time = true;
+                has_component = false;
+                continue;
+            }
+        }
+
+        let ParsedItem(remaining, digits) = match n_to_m::<_, _, 1, { u8::MAX }>(any_digit)(input) {
+            Some(item) => item,
+            None => break,
+        };
+        // Only the last unit may have a decimal fraction.
+        if has_fraction {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+        let (remaining, fraction) = match ascii_char::<b'.'>(remaining)
+            .or_else(|| ascii_char::<b','>(remaining))
+        {
+            Some(ParsedItem(remaining, ())) => {
+                let ParsedItem(remaining, fraction) =
+                    n_to_m::<_, _, 1, { u8::MAX }>(any_digit)(remaining).ok_or(InvalidLiteral)?;
+                has_fraction = true;
+                (remaining, fraction)
+            }
+            None => (remaining, &[][..]),
+        };
+
+        let (&designator, remaining) = remaining.split_first().ok_or(InvalidLiteral)?;
+        let position = units
+            .iter()
+            .position(|&(unit, ..)| unit == designator)
+            .ok_or(InvalidLiteral)?;
+        let (_, name, nanos_per_unit) = units[position];
+        units = &units[position + 1..];
+        if nanos_per_unit == 0 {
+            return Err(InvalidComponent(name).into());
+        }
+
+        nanoseconds =
+            add_duration_component(nanoseconds, digits, fraction, nanos_per_unit, is_negative)
+                .ok_or(InvalidComponent(name))?;
+        input = remaining;
+        has_component = true;
+    }
+
+    // Either portion, if present, must have at least one component.
+    if !has_component {
+        return Err(InvalidLiteral.into());
+    }
+    if !input.is_empty() {
+        return Err(error::Parse::UnexpectedTrailingCharacters);
+    }
+
+    if is_negative {
+        nanoseconds = -nanoseconds;
+    }
+    Ok(Duration::new(
+        (nanoseconds / NANOS_PER_SECOND) as _,
+        (nanoseconds % NANOS_PER_SECOND) as _,
+    ))
+}
@@ -0,0 +1,153 @@
+//! Lenient matching of literals in format descriptions.
+
+use crate::error;
+use crate::locale::{English, Locale};
+use crate::parsing::{Parsable, Parsed};
+
+/// How literals in a format description are matched against the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LiteralMatching {
+    /// Whether ASCII letters match regardless of their case.
+    pub(crate) ignore_case: bool,
+    /// Whether a run of whitespace matches any nonempty run of whitespace in the input.
+    pub(crate) collapse_whitespace: bool,
+}
+
+impl LiteralMatching {
+    /// Literals must match the input exactly.
+    pub(crate) const EXACT: Self = Self {
+        ignore_case: false,
+        collapse_whitespace: false,
+    };
+
+    /// Match a literal against the start of the input, returning the remaining input on success.
+    pub(crate) fn match_literal<'a>(
+        self,
+        mut input: &'a [u8],
+        mut literal: &[u8],
+    ) -> Option<&'a [u8]> {
+        while let Some((&expected, remaining_literal)) = literal.split_first() {
+            if self.collapse_whitespace && expected.is_ascii_whitespace() {
+                let whitespace_len = input
+                    .iter()
+                    .position(|byte| !byte.is_ascii_whitespace())
+                    .unwrap_or(input.len());
+                if whitespace_len == 0 {
+                    return None;
+                }
+                input = &input[whitespace_len..];
+                literal = remaining_literal;
+                while let Some((byte, remaining_literal)) = literal.split_first() {
+                    if !byte.is_ascii_whitespace() {
+                        break;
+                    }
+                    literal = remaining_literal;
+                }
+                continue;
+            }
+
+            let (&actual, remaining_input) = input.split_first()?;
+            let is_match = if self.ignore_case {
+                actual.eq_ignore_ascii_case(&expected)
+            } else {
+                actual == expected
+            };
+            if !is_match {
+                return None;
+            }
+            input = remaining_input;
+            literal = remaining_literal;
+        }
+
+        Some(input)
+    }
+}
+
+/// A format description whose literals are matched leniently when parsing.
+///
+/// Literals in a format description normally have to match the input byte-for-byte. When wrapped
+/// in `Lenient`, ASCII letters in literals match regardless of their case, and any run of
+/// whitespace in a literal matches one or more whitespace characters in the input. Either behavior
+/// can be disabled individually. As whitespace is consumed greedily, a component following it
+/// should not be padded with spaces. Only literals are affected; components such as `[month]` have
+/// a `case_sensitive` modifier of their own.
+///
+/// Well-known formats are not affected by this type and should not be wrapped in it.
+///
+/// ```rust
+/// # use time::{macros::{datetime, format_description}, parsing::Lenient, PrimitiveDateTime};
+/// let description = Lenient::new(format_description!("[year]-[month]-[day]T[hour]:[minute] UTC"));
+/// assert_eq!(
+///     PrimitiveDateTime::parse("2021-01-02t03:04   utc", &description)?,
+///     datetime!(2021-01-02 03:04)
+/// );
+/// assert!(
+///     PrimitiveDateTime::parse("2021-01-02t03:04 UTC", &description.set_ignore_case(false))
+///         .is_err()
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lenient<T> {
+    /// The format description being wrapped.
+    description: T,
+    /// How literals in the format description are matched.
+    matching: LiteralMatching,
+}
+
+impl<T> Lenient<T> {
+    /// Wrap the format description, ignoring the case of literals and collapsing whitespace.
+    pub const fn new(description: T) -> Self {
+        Self {
+            description,
+            matching: LiteralMatching {
+                ignore_case: true,
+                collapse_whitespace: true,
+            },
+        }
+    }
+
+    /// Set whether ASCII letters in literals match regardless of their case.
+    pub const fn set_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.matching.ignore_case = ignore_case;
+        self
+    }
+
+    /// Set whether a run of whitespace in a literal matches one or more whitespace characters in
+    /// the input, rather than exactly the same characters.
+    pub const fn set_collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.matching.collapse_whitespace = collapse_whitespace;
+        self
+    }
+
+    /// Obtain the wrapped format description.
+    pub fn into_inner(self) -> T {
+        self.description
+    }
+}
+
+impl<T: Parsable> Parsable for Lenient<T> {}
+
+impl<T: Parsable> crate::parsing::parsable::sealed::Sealed for Lenient<T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.parse_into_localized(input, parsed, &English)
+    }
+
+    fn parse_into_localized<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        let previous_matching = parsed.literal_matching;
+        parsed.literal_matching = self.matching;
+        let result = self.description.parse_into_localized(input, parsed, locale);
+        parsed.literal_matching = previous_matching;
+        result
+    }
+}
@@ -0,0 +1,67 @@
+//! Reporting where in the input parsing a format description failed.
+
+use crate::error;
+use crate::locale::{English, Locale};
+use crate::parsing::{Parsable, Parsed};
+
+/// A format description whose parse errors record where in the input they occurred.
+///
+/// When parsing with a format description fails, the error is normally an
+/// [`error::Parse::ParseFromDescription`], which only states what went wrong. When wrapped in
+/// `Located`, an [`error::Parse::InvalidInput`] is returned instead, which additionally contains
+/// the byte offset into the input, the item that was expected, and the input that was found in its
+/// place.
+///
+/// Well-known formats are not affected by this type and should not be wrapped in it.
+///
+/// ```rust
+/// # use time::{error, macros::format_description, parsing::Located, Date};
+/// let description = Located::new(format_description!("[year]-[month]-[day]"));
+/// match Date::parse("2021-13-01", &description) {
+///     Err(error::Parse::InvalidInput(err)) => assert_eq!(err.index(), 5),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Located<T> {
+    /// The format description being wrapped.
+    description: T,
+}
+
+impl<T> Located<T> {
+    /// Wrap the format description, recording the location of any parse error.
+    pub const fn new(description: T) -> Self {
+        Self { description }
+    }
+
+    /// Obtain the wrapped format description.
+    pub fn into_inner(self) -> T {
+        self.description
+    }
+}
+
+impl<T: Parsable> Parsable for Located<T> {}
+
+impl<T: Parsable> crate::parsing::parsable::sealed::Sealed for Located<T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.parse_into_localized(input, parsed, &English)
+    }
+
+    fn parse_into_localized<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        let previous_report_location = parsed.report_location;
+        parsed.report_location = true;
+        let result = self.description.parse_into_localized(input, parsed, locale);
+        parsed.report_location = previous_report_location;
+        result
+    }
+}
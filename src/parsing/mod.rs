@@ -1,13 +1,35 @@
 //! Parsing for various types.
 
+mod asn1;
 pub(crate) mod combinator;
 pub(crate) mod component;
+pub(crate) mod duration;
+pub(crate) mod iso8601;
+mod lenient;
+mod located;
 pub(crate) mod parsable;
 mod parsed;
+pub(crate) mod rfc9557;
 pub(crate) mod shim;
 
+pub use self::lenient::Lenient;
+pub use self::located::Located;
 pub use self::parsable::Parsable;
 pub use self::parsed::Parsed;
+use crate::error;
+
+/// Obtain the unparsed remainder of `input`, where `remaining` is a suffix of its bytes.
+///
+/// The remainder can only fail to start on a character boundary if a literal or `[ignore]`
+/// component in the format description ends partway through a character, in which case the
+/// error is reported as an invalid literal.
+pub(crate) fn remaining_str<'a>(input: &'a str, remaining: &[u8]) -> Result<&'a str, error::Parse> {
+    input
+        .get(input.len() - remaining.len()..)
+        .ok_or(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidLiteral,
+        ))
+}
 
 /// An item that has been parsed. Represented as a `(remaining, value)` pair.
 #[derive(Debug)]
@@ -4,9 +4,15 @@ use core::convert::TryInto;
 use core::ops::Deref;
 
 use crate::error::TryFromParsed;
-use crate::format_description::well_known::{Rfc2822, Rfc3339};
+use crate::format_description::well_known::iso8601::EncodedConfig;
+use crate::format_description::well_known::{
+    GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424, Rfc9557, UtcTime,
+};
 use crate::format_description::FormatItem;
-use crate::parsing::{Parsed, ParsedItem};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::locale::{English, Locale};
+use crate::parsing::{asn1, iso8601, rfc9557, Parsed, ParsedItem};
 use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 /// A type that can be parsed.
@@ -14,13 +20,24 @@ use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffs
 pub trait Parsable: sealed::Sealed {}
 impl Parsable for FormatItem<'_> {}
 impl Parsable for [FormatItem<'_>] {}
+#[cfg(feature = "alloc")]
+impl Parsable for OwnedFormatItem {}
+#[cfg(feature = "alloc")]
+impl Parsable for [OwnedFormatItem] {}
 impl Parsable for Rfc2822 {}
+impl Parsable for HttpDate {}
+impl Parsable for Rfc3164 {}
 impl Parsable for Rfc3339 {}
+impl Parsable for Rfc5424 {}
+impl Parsable for Rfc9557 {}
+impl<const CONFIG: EncodedConfig> Parsable for Iso8601<CONFIG> {}
+impl<const IS_DER: bool> Parsable for UtcTime<IS_DER> {}
+impl<const IS_DER: bool> Parsable for GeneralizedTime<IS_DER> {}
 impl<T: Deref> Parsable for T where T::Target: Parsable {}
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
 /// exist in generic bounds.
-mod sealed {
+pub(crate) mod sealed {
 
     #[allow(clippy::wildcard_imports)]
     use super::*;
@@ -37,19 +54,41 @@ mod sealed {
             parsed: &mut Parsed,
         ) -> Result<&'a [u8], error::Parse>;
 
+        /// Parse the item into the provided [`Parsed`] struct, using the names of the provided
+        /// locale.
+        ///
+        /// Only custom format descriptions are affected by the locale.
+        fn parse_into_localized<'a>(
+            &self,
+            input: &'a [u8],
+            parsed: &mut Parsed,
+            _locale: &dyn Locale,
+        ) -> Result<&'a [u8], error::Parse> {
+            self.parse_into(input, parsed)
+        }
+
         /// Parse the item into a new [`Parsed`] struct.
         ///
         /// This method can only be used to parse a complete value of a type. If any characters
         /// remain after parsing, an error will be returned.
         fn parse(&self, input: &[u8]) -> Result<Parsed, error::Parse> {
-            let mut parsed = Parsed::new();
-            if self.parse_into(input, &mut parsed)?.is_empty() {
+            let (parsed, remaining) = self.parse_prefix(input)?;
+            if remaining.is_empty() {
                 Ok(parsed)
             } else {
                 Err(error::Parse::UnexpectedTrailingCharacters)
             }
         }
 
+        /// Parse the item into a new [`Parsed`] struct, returning the input that remains.
+        ///
+        /// Unlike [`parse`](Self::parse), it is not an error for input to remain after parsing.
+        fn parse_prefix<'a>(&self, input: &'a [u8]) -> Result<(Parsed, &'a [u8]), error::Parse> {
+            let mut parsed = Parsed::new();
+            let remaining = self.parse_into(input, &mut parsed)?;
+            Ok((parsed, remaining))
+        }
+
         /// Parse a [`Date`] from the format description.
         fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
             Ok(self.parse(input)?.try_into()?)
@@ -74,6 +113,16 @@ mod sealed {
         fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
             Ok(self.parse(input)?.try_into()?)
         }
+
+        /// Parse a [`OffsetDateTime`] from the start of the input, returning the input that
+        /// remains.
+        fn parse_offset_date_time_prefix<'a>(
+            &self,
+            input: &'a [u8],
+        ) -> Result<(OffsetDateTime, &'a [u8]), error::Parse> {
+            let (parsed, remaining) = self.parse_prefix(input)?;
+            Ok((parsed.try_into()?, remaining))
+        }
     }
 }
 
@@ -84,7 +133,19 @@ impl sealed::Sealed for FormatItem<'_> {
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_item(input, self)?)
+        self.parse_into_localized(input, parsed, &English)
+    }
+
+    fn parse_into_localized<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        let report_location = parsed.report_location;
+        parsed
+            .parse_item_located(input, self, locale)
+            .map_err(|err| err.into_parse(input, report_location))
     }
 }
 
@@ -94,7 +155,65 @@ impl sealed::Sealed for [FormatItem<'_>] {
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_items(input, self)?)
+        self.parse_into_localized(input, parsed, &English)
+    }
+
+    fn parse_into_localized<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        let report_location = parsed.report_location;
+        parsed
+            .parse_items_located(input, self, locale)
+            .map_err(|err| err.into_parse(input, report_location))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for OwnedFormatItem {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.parse_into_localized(input, parsed, &English)
+    }
+
+    fn parse_into_localized<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        let report_location = parsed.report_location;
+        parsed
+            .parse_item_located(input, self, locale)
+            .map_err(|err| err.into_parse(input, report_location))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for [OwnedFormatItem] {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.parse_into_localized(input, parsed, &English)
+    }
+
+    fn parse_into_localized<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        let report_location = parsed.report_location;
+        parsed
+            .parse_items_located(input, self, locale)
+            .map_err(|err| err.into_parse(input, report_location))
     }
 }
 
@@ -109,6 +228,15 @@ where
     ) -> Result<&'a [u8], error::Parse> {
         self.deref().parse_into(input, parsed)
     }
+
+    fn parse_into_localized<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.deref().parse_into_localized(input, parsed, locale)
+    }
 }
 // endregion custom formats
 
@@ -217,13 +345,6 @@ impl sealed::Sealed for Rfc2822 {
             cfws(input).ok_or(InvalidLiteral)?.into_inner()
         };
 
-        // The RFC explicitly allows leap seconds. We don't currently support them, so treat it as
-        // the previous moment.
-        if parsed.second == Some(60) {
-            parsed.second = Some(59);
-            parsed.subsecond = Some(999_999_999);
-        }
-
         let zone_literal = first_match(
             [
                 (&b"UT"[..], 0),
@@ -240,10 +361,9 @@ impl sealed::Sealed for Rfc2822 {
             false,
         )(input)
         .or_else(|| match input {
-            [
-                b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z',
-                rest @ ..,
-            ] => Some(ParsedItem(rest, 0)),
+            [b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z', rest @ ..] => {
+                Some(ParsedItem(rest, 0))
+            }
             _ => None,
         });
         if let Some(zone_literal) = zone_literal {
@@ -272,6 +392,166 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
+impl sealed::Sealed for HttpDate {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::rfc::rfc9110::{day_name, day_name_l, month};
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+
+        /// Interpret a two-digit year. A year that appears to be more than 50 years in the future
+        /// is the most recent year in the past with the same last two digits.
+        fn expand_two_digit_year(year: u8) -> i32 {
+            #[cfg(feature = "std")]
+            let current_year = OffsetDateTime::now_utc().year();
+            // Without the standard library, the current date is not available.
+            #[cfg(not(feature = "std"))]
+            let current_year: i32 = 2000;
+
+            let year = current_year - current_year.rem_euclid(100) + year as i32;
+            if year > current_year + 50 {
+                year - 100
+            } else {
+                year
+            }
+        }
+
+        let sp = ascii_char::<b' '>;
+        let dash = ascii_char::<b'-'>;
+        let comma = ascii_char::<b','>;
+        let colon = ascii_char::<b':'>;
+
+        let (input, is_asctime) = if let Some(item) = day_name_l(input) {
+            // The obsolete RFC 850 form, such as `Sunday, 06-Nov-94`.
+            let input = item.assign_value_to(&mut parsed.weekday);
+            let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = exactly_n_digits::<_, 2>(input)
+                .ok_or(InvalidComponent("day"))?
+                .assign_value_to(&mut parsed.day);
+            let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = month(input)
+                .ok_or(InvalidComponent("month"))?
+                .assign_value_to(&mut parsed.month);
+            let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = exactly_n_digits::<u8, 2>(input)
+                .ok_or(InvalidComponent("year"))?
+                .map(expand_two_digit_year)
+                .assign_value_to(&mut parsed.year);
+            (input, false)
+        } else {
+            let input = day_name(input)
+                .ok_or(InvalidComponent("weekday"))?
+                .assign_value_to(&mut parsed.weekday);
+            if let Some(ParsedItem(input, ())) = comma(input) {
+                // The preferred IMF-fixdate form, such as `Sun, 06 Nov 1994`.
+                let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = exactly_n_digits::<_, 2>(input)
+                    .ok_or(InvalidComponent("day"))?
+                    .assign_value_to(&mut parsed.day);
+                let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = month(input)
+                    .ok_or(InvalidComponent("month"))?
+                    .assign_value_to(&mut parsed.month);
+                let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = exactly_n_digits::<u32, 4>(input)
+                    .ok_or(InvalidComponent("year"))?
+                    .map(|year| year as _)
+                    .assign_value_to(&mut parsed.year);
+                (input, false)
+            } else {
+                // The obsolete asctime form, such as `Sun Nov  6`. The year follows the time.
+                let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = month(input)
+                    .ok_or(InvalidComponent("month"))?
+                    .assign_value_to(&mut parsed.month);
+                let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = match sp(input) {
+                    Some(ParsedItem(input, ())) => exactly_n_digits::<_, 1>(input),
+                    None => exactly_n_digits::<_, 2>(input),
+                }
+                .ok_or(InvalidComponent("day"))?
+                .assign_value_to(&mut parsed.day);
+                (input, true)
+            }
+        };
+
+        let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("hour"))?
+            .assign_value_to(&mut parsed.hour_24);
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("minute"))?
+            .assign_value_to(&mut parsed.minute);
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("second"))?
+            .assign_value_to(&mut parsed.second);
+        // The RFC explicitly allows leap seconds, which are kept as the second `60`.
+
+        let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = if is_asctime {
+            exactly_n_digits::<u32, 4>(input)
+                .ok_or(InvalidComponent("year"))?
+                .map(|year| year as _)
+                .assign_value_to(&mut parsed.year)
+        } else {
+            input.strip_prefix(b"GMT").ok_or(InvalidLiteral)?
+        };
+
+        parsed.offset_hour = Some(0);
+        parsed.offset_minute = Some(0);
+        parsed.offset_second = Some(0);
+
+        Ok(input)
+    }
+}
+
+impl sealed::Sealed for Rfc3164 {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::rfc::rfc9110::month;
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+
+        let sp = ascii_char::<b' '>;
+        let colon = ascii_char::<b':'>;
+
+        let input = month(input)
+            .ok_or(InvalidComponent("month"))?
+            .assign_value_to(&mut parsed.month);
+        let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+        // The day is padded with a space, but a leading zero is also accepted.
+        let input = match sp(input) {
+            Some(ParsedItem(input, ())) => exactly_n_digits::<_, 1>(input),
+            None => exactly_n_digits::<_, 2>(input),
+        }
+        .ok_or(InvalidComponent("day"))?
+        .assign_value_to(&mut parsed.day);
+        let input = sp(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("hour"))?
+            .assign_value_to(&mut parsed.hour_24);
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("minute"))?
+            .assign_value_to(&mut parsed.minute);
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<_, 2>(input)
+            .ok_or(InvalidComponent("second"))?
+            .assign_value_to(&mut parsed.second);
+
+        Ok(input)
+    }
+}
+
 impl sealed::Sealed for Rfc3339 {
     fn parse_into<'a>(
         &self,
@@ -331,13 +611,6 @@ impl sealed::Sealed for Rfc3339 {
             input
         };
 
-        // The RFC explicitly allows leap seconds. We don't currently support them, so treat it as
-        // the previous moment.
-        if parsed.second == Some(60) {
-            parsed.second = Some(59);
-            parsed.subsecond = Some(999_999_999);
-        }
-
         if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
             parsed.offset_hour = Some(0);
             parsed.offset_minute = Some(0);
@@ -365,6 +638,16 @@ impl sealed::Sealed for Rfc3339 {
     }
 
     fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        match self.parse_offset_date_time_prefix(input)? {
+            (value, []) => Ok(value),
+            _ => Err(error::Parse::UnexpectedTrailingCharacters),
+        }
+    }
+
+    fn parse_offset_date_time_prefix<'a>(
+        &self,
+        input: &'a [u8],
+    ) -> Result<(OffsetDateTime, &'a [u8]), error::Parse> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
         use crate::parsing::combinator::{
             any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
@@ -390,9 +673,9 @@ impl sealed::Sealed for Rfc3339 {
         let ParsedItem(input, minute) =
             exactly_n_digits::<_, 2>(input).ok_or(InvalidComponent("minute"))?;
         let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, mut second) =
+        let ParsedItem(input, second) =
             exactly_n_digits::<_, 2>(input).ok_or(InvalidComponent("second"))?;
-        let ParsedItem(input, mut nanosecond) =
+        let ParsedItem(input, nanosecond) =
             if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
                 let ParsedItem(mut input, mut value) = any_digit(input)
                     .ok_or(InvalidComponent("subsecond"))?
@@ -443,22 +726,113 @@ impl sealed::Sealed for Rfc3339 {
             }
         };
 
-        if !input.is_empty() {
-            return Err(error::Parse::UnexpectedTrailingCharacters);
+        // The RFC explicitly allows leap seconds, which are represented as the second `60`.
+        let time = if second == 60 {
+            Time::from_leap_second(hour, minute, nanosecond)
+        } else {
+            Time::from_hms_nano(hour, minute, second, nanosecond)
+        };
+
+        let value = Month::from_number(month)
+            .and_then(|month| Date::from_calendar_date(year as _, month, day))
+            .and_then(|date| Ok(date.with_time(time?)))
+            .map(|date| date.assume_offset(offset))
+            .map_err(TryFromParsed::ComponentRange)?;
+        Ok((value, input))
+    }
+}
+
+impl sealed::Sealed for Rfc5424 {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+
+        let remaining = Rfc3339.parse_into(input, parsed)?;
+
+        // RFC 3339 is fixed-width up to the fractional second, so the positions of the `T` and
+        // the fractional second are known.
+        let consumed = &input[..input.len() - remaining.len()];
+        if consumed[10] != b'T' || consumed.ends_with(b"z") {
+            return Err(InvalidLiteral.into());
+        }
+        if consumed[19] == b'.'
+            && consumed[20..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count()
+                > 6
+        {
+            return Err(InvalidComponent("subsecond").into());
+        }
+        if parsed.second == Some(60) {
+            return Err(InvalidComponent("second").into());
         }
 
-        // The RFC explicitly allows leap seconds. We don't currently support them, so treat it as
-        // the previous moment.
-        if second == 60 {
-            second = 59;
-            nanosecond = 999_999_999;
+        Ok(remaining)
+    }
+}
+
+impl sealed::Sealed for Rfc9557 {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        let remaining = Rfc3339.parse_into(input, parsed)?;
+        // A `Z` indicates that the local offset is unknown, so any time zone is consistent with it.
+        let offset = match input[..input.len() - remaining.len()].last() {
+            Some(b'Z' | b'z') => None,
+            _ => Some((*parsed).try_into()?),
+        };
+
+        Ok(rfc9557::parse_annotations(remaining, offset)?.0)
+    }
+}
+
+impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+    fn parse_into<'a>(
+        &self,
+        mut input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        let formatted_components = Self::CONFIG.formatted_components;
+        let mut notation = iso8601::Notation::Unknown;
+
+        if formatted_components.date_is_present() {
+            input = iso8601::parse_date(input, parsed, &mut notation)?;
+        }
+        if formatted_components.time_is_present() {
+            input = iso8601::parse_time_designator(input, formatted_components.date_is_present())?;
+            input = iso8601::parse_time(input, parsed, &mut notation)?;
+        }
+        if formatted_components.offset_is_present() {
+            input = iso8601::parse_offset(input, parsed, &mut notation)?;
         }
 
-        Ok(Month::from_number(month)
-            .and_then(|month| Date::from_calendar_date(year as _, month, day))
-            .and_then(|date| date.with_hms_nano(hour, minute, second, nanosecond))
-            .map(|date| date.assume_offset(offset))
-            .map_err(TryFromParsed::ComponentRange)?)
+        Ok(input)
+    }
+}
+
+impl<const IS_DER: bool> sealed::Sealed for UtcTime<IS_DER> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        asn1::parse_utc_time::<IS_DER>(input, parsed)
+    }
+}
+
+impl<const IS_DER: bool> sealed::Sealed for GeneralizedTime<IS_DER> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        asn1::parse_generalized_time::<IS_DER>(input, parsed)
     }
 }
 // endregion well-known formats
@@ -5,12 +5,16 @@ use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::error::TryFromParsed::InsufficientInformation;
 use crate::format_description::modifier::{WeekNumberRepr, YearRepr};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
 use crate::format_description::{Component, FormatItem};
+use crate::locale::{English, Locale};
 use crate::parsing::component::{
-    parse_day, parse_hour, parse_minute, parse_month, parse_offset_hour, parse_offset_minute,
-    parse_offset_second, parse_ordinal, parse_period, parse_second, parse_subsecond,
-    parse_week_number, parse_weekday, parse_year, Period,
+    parse_day, parse_end, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset_hour,
+    parse_offset_minute, parse_offset_second, parse_ordinal, parse_period, parse_second,
+    parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday, parse_year, Period,
 };
+use crate::parsing::lenient::LiteralMatching;
 use crate::parsing::ParsedItem;
 use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
@@ -63,6 +67,94 @@ pub struct Parsed {
     pub(crate) offset_minute: Option<u8>,
     /// Seconds within the minute of the UTC offset.
     pub(crate) offset_second: Option<u8>,
+    /// The Unix timestamp in nanoseconds.
+    pub(crate) unix_timestamp_nanos: Option<i128>,
+    /// How literals are matched against the input. This is only changed by
+    /// [`Lenient`](crate::parsing::Lenient).
+    pub(crate) literal_matching: LiteralMatching,
+    /// Whether errors record where in the input they occurred. This is only changed by
+    /// [`Located`](crate::parsing::Located).
+    pub(crate) report_location: bool,
+}
+
+/// A format item that can be parsed.
+pub(crate) trait AnyFormatItem {
+    /// Parse a single item using the names of the provided locale, returning the remaining
+    /// input on success.
+    fn parse_item<'a>(
+        &self,
+        parsed: &mut Parsed,
+        input: &'a [u8],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], ItemError<'a>>;
+}
+
+/// An error that occurred while parsing a format item, along with the input and the item that
+/// failed to parse.
+#[derive(Debug)]
+pub(crate) struct ItemError<'a> {
+    /// The underlying error.
+    pub(crate) error: error::ParseFromDescription,
+    /// The input at the point of the error.
+    pub(crate) input: &'a [u8],
+    /// The item that was expected.
+    pub(crate) expected: error::Expected,
+}
+
+impl ItemError<'_> {
+    /// Obtain an [`error::Parse`], where `input` is the complete input that the item was parsed
+    /// from. The location of the error is only retained if `report_location` is set.
+    pub(crate) fn into_parse(self, input: &[u8], report_location: bool) -> error::Parse {
+        if !report_location {
+            return self.error.into();
+        }
+        error::InvalidInput {
+            error: self.error,
+            index: input.len() - self.input.len(),
+            expected: self.expected,
+            found: error::Snippet::new(self.input),
+        }
+        .into()
+    }
+}
+
+impl AnyFormatItem for FormatItem<'_> {
+    fn parse_item<'a>(
+        &self,
+        parsed: &mut Parsed,
+        input: &'a [u8],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], ItemError<'a>> {
+        match self {
+            Self::Literal(literal) => parsed.parse_literal_located(input, literal),
+            Self::Component(component) => parsed.parse_component_located(input, *component, locale),
+            Self::Compound(compound) => parsed.parse_items_located(input, compound, locale),
+            Self::Optional(item) => parsed
+                .parse_item_located(input, *item, locale)
+                .or(Ok(input)),
+            Self::First(items) => parsed.parse_first(input, items, locale),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AnyFormatItem for OwnedFormatItem {
+    fn parse_item<'a>(
+        &self,
+        parsed: &mut Parsed,
+        input: &'a [u8],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], ItemError<'a>> {
+        match self {
+            Self::Literal(literal) => parsed.parse_literal_located(input, literal),
+            Self::Component(component) => parsed.parse_component_located(input, *component, locale),
+            Self::Compound(compound) => parsed.parse_items_located(input, compound, locale),
+            Self::Optional(item) => parsed
+                .parse_item_located(input, item.as_ref(), locale)
+                .or(Ok(input)),
+            Self::First(items) => parsed.parse_first(input, items, locale),
+        }
+    }
 }
 
 impl Parsed {
@@ -89,6 +181,9 @@ impl Parsed {
             offset_hour: None,
             offset_minute: None,
             offset_second: None,
+            unix_timestamp_nanos: None,
+            literal_matching: LiteralMatching::EXACT,
+            report_location: false,
         }
     }
 
@@ -102,30 +197,34 @@ impl Parsed {
         input: &'a [u8],
         item: &FormatItem<'_>,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        match item {
-            FormatItem::Literal(literal) => Self::parse_literal(input, literal),
-            FormatItem::Component(component) => self.parse_component(input, *component),
-            FormatItem::Compound(compound) => self.parse_items(input, compound),
-            FormatItem::Optional(item) => self.parse_item(input, item).or(Ok(input)),
-            FormatItem::First(items) => {
-                let mut first_err = None;
-
-                for item in items.iter() {
-                    match self.parse_item(input, item) {
-                        Ok(remaining_input) => return Ok(remaining_input),
-                        Err(err) if first_err.is_none() => first_err = Some(err),
-                        Err(_) => {}
-                    }
-                }
+        self.parse_item_located(input, item, &English)
+            .map_err(|err| err.error)
+    }
 
-                match first_err {
-                    Some(err) => Err(err),
-                    // This location will be reached if the slice is empty, skipping the `for` loop.
-                    // As this case is expected to be uncommon, there's no need to check up front.
-                    None => Ok(input),
-                }
-            }
-        }
+    /// Parse a single [`OwnedFormatItem`], mutating the struct. The remaining input is returned as
+    /// the `Ok` value.
+    ///
+    /// If an [`OwnedFormatItem::Optional`] is passed, parsing will not fail; the input will be
+    /// returned as-is if the expected format is not present.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn parse_owned_item<'a>(
+        &mut self,
+        input: &'a [u8],
+        item: &OwnedFormatItem,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_item_located(input, item, &English)
+            .map_err(|err| err.error)
+    }
+
+    /// Parse a single [`FormatItem`] or [`OwnedFormatItem`], retaining the location of any error.
+    pub(crate) fn parse_item_located<'a>(
+        &mut self,
+        input: &'a [u8],
+        item: &impl AnyFormatItem,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], ItemError<'a>> {
+        item.parse_item(self, input, locale)
     }
 
     /// Parse a sequence of [`FormatItem`]s, mutating the struct. The remaining input is returned as
@@ -135,19 +234,105 @@ impl Parsed {
     /// be mutated in this instance.
     pub fn parse_items<'a>(
         &mut self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         items: &[FormatItem<'_>],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_items_located(input, items, &English)
+            .map_err(|err| err.error)
+    }
+
+    /// Parse a sequence of [`OwnedFormatItem`]s, mutating the struct. The remaining input is
+    /// returned as the `Ok` value.
+    ///
+    /// This method will fail if any of the contained [`OwnedFormatItem`]s fail to parse. `self`
+    /// will not be mutated in this instance.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn parse_owned_items<'a>(
+        &mut self,
+        input: &'a [u8],
+        items: &[OwnedFormatItem],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_items_located(input, items, &English)
+            .map_err(|err| err.error)
+    }
+
+    /// Parse a sequence of [`FormatItem`]s or [`OwnedFormatItem`]s, retaining the location of any
+    /// error.
+    pub(crate) fn parse_items_located<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        items: &[impl AnyFormatItem],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], ItemError<'a>> {
         // Make a copy that we can mutate. It will only be set to the user's copy if everything
         // succeeds.
         let mut this = *self;
         for item in items {
-            input = this.parse_item(input, item)?;
+            input = this.parse_item_located(input, item, locale)?;
         }
         *self = this;
         Ok(input)
     }
 
+    /// Parse the first of the items that succeeds, mutating the struct. The remaining input is
+    /// returned as the `Ok` value.
+    fn parse_first<'a>(
+        &mut self,
+        input: &'a [u8],
+        items: &[impl AnyFormatItem],
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], ItemError<'a>> {
+        let mut first_err = None;
+
+        for item in items {
+            match self.parse_item_located(input, item, locale) {
+                Ok(remaining_input) => return Ok(remaining_input),
+                Err(err) if first_err.is_none() => first_err = Some(err),
+                Err(_) => {}
+            }
+        }
+
+        match first_err {
+            Some(err) => Err(err),
+            // This location will be reached if the slice is empty, skipping the `for` loop.
+            // As this case is expected to be uncommon, there's no need to check up front.
+            None => Ok(input),
+        }
+    }
+
+    /// Parse a literal byte sequence, retaining the location of any error. Unlike
+    /// [`Parsed::parse_literal`], this respects any leniency requested via
+    /// [`Lenient`](crate::parsing::Lenient).
+    fn parse_literal_located<'a>(
+        &self,
+        input: &'a [u8],
+        literal: &[u8],
+    ) -> Result<&'a [u8], ItemError<'a>> {
+        self.literal_matching
+            .match_literal(input, literal)
+            .ok_or_else(|| ItemError {
+                error: error::ParseFromDescription::InvalidLiteral,
+                input,
+                expected: error::Expected::Literal(error::Snippet::new(literal)),
+            })
+    }
+
+    /// Parse a single component, retaining the location of any error.
+    fn parse_component_located<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+        locale: &dyn Locale,
+    ) -> Result<&'a [u8], ItemError<'a>> {
+        self.parse_component_localized(input, component, locale)
+            .map_err(|error| ItemError {
+                error,
+                input,
+                expected: error::Expected::Component(component),
+            })
+    }
+
     /// Parse a literal byte sequence. The remaining input is returned as the `Ok` value.
     pub fn parse_literal<'a>(
         input: &'a [u8],
@@ -164,20 +349,30 @@ impl Parsed {
         &mut self,
         input: &'a [u8],
         component: Component,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_component_localized(input, component, &English)
+    }
+
+    /// Parse a single component using the names of the provided locale, mutating the struct.
+    fn parse_component_localized<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+        locale: &dyn Locale,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         use error::ParseFromDescription::InvalidComponent;
 
         match component {
-            Component::Day(modifiers) => Ok(parse_day(input, modifiers)
+            Component::Day(modifiers) => Ok(parse_day(input, modifiers, locale)
                 .ok_or(InvalidComponent("day"))?
                 .assign_value_to(&mut self.day)),
-            Component::Month(modifiers) => Ok(parse_month(input, modifiers)
+            Component::Month(modifiers) => Ok(parse_month(input, modifiers, locale)
                 .ok_or(InvalidComponent("month"))?
                 .assign_value_to(&mut self.month)),
             Component::Ordinal(modifiers) => Ok(parse_ordinal(input, modifiers)
                 .ok_or(InvalidComponent("ordinal"))?
                 .assign_value_to(&mut self.ordinal)),
-            Component::Weekday(modifiers) => Ok(parse_weekday(input, modifiers)
+            Component::Weekday(modifiers) => Ok(parse_weekday(input, modifiers, locale)
                 .ok_or(InvalidComponent("weekday"))?
                 .assign_value_to(&mut self.weekday)),
             Component::WeekNumber(modifiers) => {
@@ -217,7 +412,7 @@ impl Parsed {
             Component::Minute(modifiers) => Ok(parse_minute(input, modifiers)
                 .ok_or(InvalidComponent("minute"))?
                 .assign_value_to(&mut self.minute)),
-            Component::Period(modifiers) => Ok(parse_period(input, modifiers)
+            Component::Period(modifiers) => Ok(parse_period(input, modifiers, locale)
                 .ok_or(InvalidComponent("period"))?
                 .map(|period| period == Period::Pm)
                 .assign_value_to(&mut self.hour_12_is_pm)),
@@ -236,6 +431,15 @@ impl Parsed {
             Component::OffsetSecond(modifiers) => Ok(parse_offset_second(input, modifiers)
                 .ok_or(InvalidComponent("offset second"))?
                 .assign_value_to(&mut self.offset_second)),
+            Component::UnixTimestamp(modifiers) => Ok(parse_unix_timestamp(input, modifiers)
+                .ok_or(InvalidComponent("unix timestamp"))?
+                .assign_value_to(&mut self.unix_timestamp_nanos)),
+            Component::Ignore(modifiers) => Ok(parse_ignore(input, modifiers)
+                .ok_or(InvalidComponent("ignore"))?
+                .into_inner()),
+            Component::End(modifiers) => Ok(parse_end(input, modifiers)
+                .ok_or(InvalidComponent("end"))?
+                .into_inner()),
         }
     }
 }
@@ -273,6 +477,7 @@ impl Parsed {
         offset_hour: i8,
         offset_minute: u8,
         offset_second: u8,
+        unix_timestamp_nanos: i128,
     }
 }
 
@@ -315,6 +520,7 @@ impl Parsed {
         set_offset_hour offset_hour: i8,
         set_offset_minute offset_minute: u8,
         set_offset_second offset_second: u8,
+        set_unix_timestamp_nanos unix_timestamp_nanos: i128,
     }
 }
 
@@ -357,6 +563,7 @@ impl Parsed {
         with_offset_hour offset_hour: i8,
         with_offset_minute offset_minute: u8,
         with_offset_second offset_second: u8,
+        with_unix_timestamp_nanos unix_timestamp_nanos: i128,
     }
 }
 
@@ -385,6 +592,10 @@ impl TryFrom<Parsed> for Date {
             }
         }
 
+        if parsed.unix_timestamp_nanos.is_some() {
+            return Ok(OffsetDateTime::try_from(parsed)?.date());
+        }
+
         // TODO Only the basics have been covered. There are many other valid values that are not
         // currently constructed from the information known.
 
@@ -417,6 +628,10 @@ impl TryFrom<Parsed> for Time {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        if parsed.unix_timestamp_nanos.is_some() {
+            return Ok(OffsetDateTime::try_from(parsed)?.time());
+        }
+
         let hour = match (parsed.hour_24, parsed.hour_12, parsed.hour_12_is_pm) {
             (Some(hour), _, _) => hour,
             (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
@@ -437,6 +652,9 @@ impl TryFrom<Parsed> for Time {
         let minute = parsed.minute.ok_or(InsufficientInformation)?;
         let second = parsed.second.unwrap_or(0);
         let subsecond = parsed.subsecond.unwrap_or(0);
+        if second == 60 {
+            return Ok(Self::from_leap_second(hour, minute, subsecond)?);
+        }
         Ok(Self::from_hms_nano(hour, minute, second, subsecond)?)
     }
 }
@@ -466,6 +684,11 @@ impl TryFrom<Parsed> for PrimitiveDateTime {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        if parsed.unix_timestamp_nanos.is_some() {
+            let date_time = OffsetDateTime::try_from(parsed)?;
+            return Ok(Self::new(date_time.date(), date_time.time()));
+        }
+
         Ok(Self::new(parsed.try_into()?, parsed.try_into()?))
     }
 }
@@ -474,6 +697,21 @@ impl TryFrom<Parsed> for OffsetDateTime {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        // A timestamp takes precedence over any other date and time components. The date and time
+        // of the other types are obtained from it as well.
+        if let Some(timestamp) = parsed.unix_timestamp_nanos {
+            // A subsecond replaces that of the timestamp, permitting descriptions such as
+            // `[unix_timestamp].[subsecond]`.
+            let timestamp = parsed.subsecond.map_or(timestamp, |subsecond| {
+                timestamp - timestamp.rem_euclid(1_000_000_000) + subsecond as i128
+            });
+            let value = Self::from_unix_timestamp_nanos(timestamp)?;
+            return match parsed.offset_hour {
+                Some(_) => Ok(value.to_offset(parsed.try_into()?)),
+                None => Ok(value),
+            };
+        }
+
         Ok(PrimitiveDateTime::try_from(parsed)?.assume_offset(parsed.try_into()?))
     }
 }
@@ -0,0 +1,148 @@
+//! Helpers for implementing parsing for RFC 9557.
+
+use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+use crate::format_description::well_known::rfc9557::{
+    is_valid_annotation_key, is_valid_annotation_value, is_valid_time_zone_name,
+};
+use crate::parsing::combinator::{ascii_char, exactly_n_digits, sign};
+use crate::parsing::ParsedItem;
+use crate::{error, UtcOffset};
+
+/// A time zone annotation, borrowed from the input.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TimeZone<'a> {
+    /// A time zone identifier, such as `Europe/Paris`. Only an annotated date-time keeps it.
+    Name(#[cfg_attr(not(feature = "alloc"), allow(dead_code))] &'a [u8]),
+    /// A UTC offset, such as `+01:00`.
+    Offset(UtcOffset),
+}
+
+/// The annotations that are recognized, borrowed from the input. Each annotation is paired with
+/// whether it is critical.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Annotations<'a> {
+    /// The time zone annotation, which is always the first annotation if present.
+    pub(crate) time_zone: Option<(TimeZone<'a>, bool)>,
+    /// The calendar (`u-ca`) annotation.
+    pub(crate) calendar: Option<(&'a [u8], bool)>,
+}
+
+/// A single annotation, whose contents have not been validated.
+struct Annotation<'a> {
+    /// The text between the brackets, excluding the critical flag.
+    contents: &'a [u8],
+    /// Whether the annotation is critical.
+    is_critical: bool,
+}
+
+/// Parse a single annotation, consisting of an optional critical flag and its contents.
+fn annotation(input: &[u8]) -> Result<Option<ParsedItem<'_, Annotation<'_>>>, error::Parse> {
+    let input = match ascii_char::<b'['>(input) {
+        Some(ParsedItem(input, ())) => input,
+        None => return Ok(None),
+    };
+    let (input, is_critical) = match ascii_char::<b'!'>(input) {
+        Some(ParsedItem(input, ())) => (input, true),
+        None => (input, false),
+    };
+    let end = input
+        .iter()
+        .position(|&c| c == b']')
+        .ok_or(InvalidLiteral)?;
+
+    Ok(Some(ParsedItem(
+        &input[end + 1..],
+        Annotation {
+            contents: &input[..end],
+            is_critical,
+        },
+    )))
+}
+
+/// Parse the contents of a time zone annotation.
+fn time_zone(contents: &[u8]) -> Result<TimeZone<'_>, error::Parse> {
+    if let Some(ParsedItem(input, sign)) = sign(contents) {
+        let offset = exactly_n_digits::<u8, 2>(input)
+            .and_then(|ParsedItem(input, hour)| {
+                let ParsedItem(input, ()) = ascii_char::<b':'>(input)?;
+                let ParsedItem(input, minute) = exactly_n_digits::<u8, 2>(input)?;
+                if !input.is_empty() || hour > 23 {
+                    return None;
+                }
+                let (hour, minute) = if sign == b'-' {
+                    (-(hour as i8), -(minute as i8))
+                } else {
+                    (hour as _, minute as _)
+                };
+                UtcOffset::from_hms(hour, minute, 0).ok()
+            })
+            .ok_or(InvalidComponent("time zone"))?;
+        Ok(TimeZone::Offset(offset))
+    } else if is_valid_time_zone_name(contents) {
+        Ok(TimeZone::Name(contents))
+    } else {
+        Err(InvalidComponent("time zone").into())
+    }
+}
+
+/// Parse the annotations that follow an RFC 3339 timestamp.
+///
+/// The offset of the timestamp is used to check a critical time zone annotation for consistency.
+/// It is `None` when the timestamp uses `Z`, indicating that the local offset is unknown.
+pub(crate) fn parse_annotations(
+    input: &[u8],
+    offset: Option<UtcOffset>,
+) -> Result<ParsedItem<'_, Annotations<'_>>, error::Parse> {
+    let mut annotations = Annotations::default();
+    let mut input = input;
+    let mut is_first = true;
+
+    while let Some(ParsedItem(
+        remaining,
+        Annotation {
+            contents,
+            is_critical,
+        },
+    )) = annotation(input)?
+    {
+        input = remaining;
+
+        let (key, value) = match contents.iter().position(|&c| c == b'=') {
+            Some(index) => (&contents[..index], &contents[index + 1..]),
+            None if is_first => {
+                let time_zone = time_zone(contents)?;
+                if let (TimeZone::Offset(annotated), Some(offset), true) =
+                    (time_zone, offset, is_critical)
+                {
+                    if annotated != offset {
+                        return Err(InvalidComponent("time zone").into());
+                    }
+                }
+                annotations.time_zone = Some((time_zone, is_critical));
+                is_first = false;
+                continue;
+            }
+            None => return Err(InvalidComponent("annotation").into()),
+        };
+        is_first = false;
+
+        if !is_valid_annotation_key(key) || !is_valid_annotation_value(value) {
+            return Err(InvalidComponent("annotation").into());
+        }
+
+        if key == b"u-ca" {
+            match annotations.calendar {
+                None => annotations.calendar = Some((value, is_critical)),
+                // A repeated calendar is only an error if one of them is critical.
+                Some((_, was_critical)) if was_critical || is_critical => {
+                    return Err(InvalidComponent("calendar").into());
+                }
+                Some(_) => {}
+            }
+        } else if is_critical {
+            return Err(InvalidComponent("annotation").into());
+        }
+    }
+
+    Ok(ParsedItem(input, annotations))
+}
@@ -1,19 +1,30 @@
 //! The [`PrimitiveDateTime`] struct and its associated `impl`s.
 
+#[cfg(feature = "parsing")]
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
 #[cfg(feature = "formatting")]
 use std::io;
 
-#[cfg(any(feature = "formatting", feature = "parsing"))]
 use crate::error;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{self, Parsable};
 use crate::{util, Date, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday};
 
+/// The Julian day of the epoch of the modified Julian date, November 17, 1858.
+const MODIFIED_JULIAN_DATE_EPOCH_JULIAN_DAY: i32 = 2_400_001;
+/// The Julian day of day zero in the 1900 date system of Excel, December 30, 1899. Due to the
+/// system incorrectly considering 1900 a leap year, this is only correct from March 1, 1900.
+const EXCEL_1900_EPOCH_JULIAN_DAY: i32 = 2_415_019;
+/// The serial date of March 1, 1900 in the 1900 date system of Excel.
+const EXCEL_1900_MARCH_1: i32 = 61;
+/// The Julian day of day zero in the 1904 date system of Excel, January 1, 1904.
+const EXCEL_1904_EPOCH_JULIAN_DAY: i32 = 2_416_481;
+
 /// Combined date and time.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct PrimitiveDateTime {
@@ -578,6 +589,217 @@ impl PrimitiveDateTime {
         }
     }
     // endregion: saturating arithmetic
+
+    // region: julian date
+    /// Create a `PrimitiveDateTime` from a fractional [Julian date](https://en.wikipedia.org/wiki/Julian_day).
+    /// The Julian date is the number of days elapsed since noon on November 24, 4714 BCE in the
+    /// proleptic Gregorian calendar. The result is rounded to the nearest nanosecond.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(datetime!(2000-01-01 12:00))
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_julian_date(2_459_580.25),
+    ///     Ok(datetime!(2021-12-31 18:00))
+    /// );
+    /// assert!(PrimitiveDateTime::from_julian_date(f64::NAN).is_err());
+    /// ```
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        Self::from_fractional_days(julian_date + 0.5, 0, "julian_date")
+    }
+
+    /// Create a `PrimitiveDateTime` from a fractional
+    /// [modified Julian date](https://en.wikipedia.org/wiki/Julian_day#Variants). The modified
+    /// Julian date is the number of days elapsed since midnight on November 17, 1858. The result
+    /// is rounded to the nearest nanosecond.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_modified_julian_date(0.),
+    ///     Ok(datetime!(1858-11-17 0:00))
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_modified_julian_date(59_579.75),
+    ///     Ok(datetime!(2021-12-31 18:00))
+    /// );
+    /// ```
+    pub fn from_modified_julian_date(
+        modified_julian_date: f64,
+    ) -> Result<Self, error::ComponentRange> {
+        Self::from_fractional_days(
+            modified_julian_date,
+            MODIFIED_JULIAN_DATE_EPOCH_JULIAN_DAY,
+            "modified_julian_date",
+        )
+    }
+
+    /// Create a `PrimitiveDateTime` from a fractional number of days, where day zero begins at
+    /// midnight of the provided Julian day.
+    fn from_fractional_days(
+        days: f64,
+        epoch_julian_day: i32,
+        name: &'static str,
+    ) -> Result<Self, error::ComponentRange> {
+        let minimum = Date::MIN.to_julian_day() as i64 - epoch_julian_day as i64;
+        let maximum = Date::MAX.to_julian_day() as i64 - epoch_julian_day as i64;
+        let err = error::ComponentRange {
+            name,
+            minimum,
+            maximum,
+            value: days as _,
+            conditional_range: false,
+        };
+
+        // This comparison also rejects NaN.
+        if !(days >= minimum as f64 && days < (maximum + 1) as f64) {
+            return Err(err);
+        }
+
+        // Floating point methods are not available in `core`, so the floor is computed manually.
+        let mut whole_days = days as i64;
+        if whole_days as f64 > days {
+            whole_days -= 1;
+        }
+        // `f64::mul_add` is not available in `core`.
+        #[allow(clippy::suboptimal_flops)]
+        let mut nanoseconds = ((days - whole_days as f64) * 86_400_000_000_000. + 0.5) as u64;
+        if nanoseconds >= 86_400_000_000_000 {
+            whole_days += 1;
+            nanoseconds -= 86_400_000_000_000;
+        }
+        if whole_days > maximum {
+            return Err(err);
+        }
+
+        let julian_day = whole_days + epoch_julian_day as i64;
+        Ok(Self::new(
+            Date::from_julian_day_unchecked(julian_day as _),
+            Time::__from_hms_nanos_unchecked(
+                (nanoseconds / 3_600_000_000_000) as _,
+                (nanoseconds / 60_000_000_000 % 60) as _,
+                (nanoseconds / 1_000_000_000 % 60) as _,
+                (nanoseconds % 1_000_000_000) as _,
+            ),
+        ))
+    }
+
+    /// Get the fractional [Julian date](https://en.wikipedia.org/wiki/Julian_day).
+    ///
+    /// Due to the limited precision of `f64`, the value is only accurate to roughly 50
+    /// microseconds for contemporary dates. The
+    /// [modified Julian date](Self::to_modified_julian_date) is roughly fifty times more precise.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2021-12-31 18:00).to_julian_date(), 2_459_580.25);
+    /// ```
+    pub fn to_julian_date(self) -> f64 {
+        self.to_julian_day() as f64 - 0.5 + self.fraction_of_day()
+    }
+
+    /// Get the fractional
+    /// [modified Julian date](https://en.wikipedia.org/wiki/Julian_day#Variants).
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(1858-11-17 0:00).to_modified_julian_date(), 0.);
+    /// assert_eq!(datetime!(2021-12-31 18:00).to_modified_julian_date(), 59_579.75);
+    /// ```
+    pub fn to_modified_julian_date(self) -> f64 {
+        (self.to_julian_day() - MODIFIED_JULIAN_DATE_EPOCH_JULIAN_DAY) as f64
+            + self.fraction_of_day()
+    }
+
+    /// Get the fraction of the day that has elapsed since midnight.
+    fn fraction_of_day(self) -> f64 {
+        let (hour, minute, second, nanosecond) = self.time.as_hms_nano();
+        let seconds = hour as u64 * 3_600 + minute as u64 * 60 + second as u64;
+        (seconds * 1_000_000_000 + nanosecond as u64) as f64 / 86_400_000_000_000.
+    }
+    // endregion julian date
+
+    // region: excel serial date
+    /// Create a `PrimitiveDateTime` from a serial date in the 1900 date system used by default in
+    /// Excel. The integral part is the number of days since December 31, 1899 and the fractional
+    /// part is the time of day. The result is rounded to the nearest nanosecond.
+    ///
+    /// For compatibility with Lotus 1-2-3, Excel considers 1900 a leap year. The nonexistent
+    /// February 29, 1900 (serial date 60) is treated as March 1, 1900.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_excel_1900_serial(1.),
+    ///     Ok(datetime!(1900-01-01 0:00))
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_excel_1900_serial(44_562.75),
+    ///     Ok(datetime!(2022-01-01 18:00))
+    /// );
+    /// ```
+    pub fn from_excel_1900_serial(serial: f64) -> Result<Self, error::ComponentRange> {
+        let days = if serial < EXCEL_1900_MARCH_1 as f64 {
+            serial + 1.
+        } else {
+            serial
+        };
+        Self::from_fractional_days(days, EXCEL_1900_EPOCH_JULIAN_DAY, "serial")
+    }
+
+    /// Create a `PrimitiveDateTime` from a serial date in the 1904 date system used by older
+    /// versions of Excel for Mac. The integral part is the number of days since January 1, 1904
+    /// and the fractional part is the time of day. The result is rounded to the nearest
+    /// nanosecond.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_excel_1904_serial(0.),
+    ///     Ok(datetime!(1904-01-01 0:00))
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_excel_1904_serial(43_100.75),
+    ///     Ok(datetime!(2022-01-01 18:00))
+    /// );
+    /// ```
+    pub fn from_excel_1904_serial(serial: f64) -> Result<Self, error::ComponentRange> {
+        Self::from_fractional_days(serial, EXCEL_1904_EPOCH_JULIAN_DAY, "serial")
+    }
+
+    /// Get the serial date in the 1900 date system used by default in Excel. Dates prior to
+    /// March 1, 1900 are offset by one day to match the serial dates displayed by Excel.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(1900-01-01 0:00).excel_1900_serial(), 1.);
+    /// assert_eq!(datetime!(1900-02-28 0:00).excel_1900_serial(), 59.);
+    /// assert_eq!(datetime!(1900-03-01 0:00).excel_1900_serial(), 61.);
+    /// assert_eq!(datetime!(2022-01-01 18:00).excel_1900_serial(), 44_562.75);
+    /// ```
+    pub fn excel_1900_serial(self) -> f64 {
+        let mut days = self.to_julian_day() - EXCEL_1900_EPOCH_JULIAN_DAY;
+        if days < EXCEL_1900_MARCH_1 {
+            days -= 1;
+        }
+        days as f64 + self.fraction_of_day()
+    }
+
+    /// Get the serial date in the 1904 date system used by older versions of Excel for Mac.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!(1904-01-01 0:00).excel_1904_serial(), 0.);
+    /// assert_eq!(datetime!(2022-01-01 18:00).excel_1904_serial(), 43_100.75);
+    /// ```
+    pub fn excel_1904_serial(self) -> f64 {
+        (self.to_julian_day() - EXCEL_1904_EPOCH_JULIAN_DAY) as f64 + self.fraction_of_day()
+    }
+    // endregion excel serial date
 }
 
 // region: replacement
@@ -619,7 +841,9 @@ impl PrimitiveDateTime {
     /// Assuming that the current [`PrimitiveDateTime`] is a value in the provided [`UtcOffset`],
     /// obtain the equivalent value in the UTC.
     pub(crate) const fn offset_to_utc(self, offset: UtcOffset) -> Self {
-        let mut second = self.second() as i8 - offset.seconds_past_minute();
+        // A leap second is cascaded as the final second of the minute so that it is retained.
+        let is_leap_second = self.time.is_leap_second();
+        let mut second = self.second() as i8 - is_leap_second as i8 - offset.seconds_past_minute();
         let mut minute = self.minute() as i8 - offset.minutes_past_hour();
         let mut hour = self.hour() as i8 - offset.whole_hours();
         let (mut year, mut ordinal) = self.date.to_ordinal_date();
@@ -634,7 +858,7 @@ impl PrimitiveDateTime {
             time: Time::__from_hms_nanos_unchecked(
                 hour as _,
                 minute as _,
-                second as _,
+                (second + is_leap_second as i8) as _,
                 self.nanosecond(),
             ),
         }
@@ -702,6 +926,29 @@ impl PrimitiveDateTime {
     ) -> Result<Self, error::Parse> {
         description.parse_date_time(input.as_bytes())
     }
+
+    /// Parse a `PrimitiveDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the remainder of the input.
+    ///
+    /// Unlike [`PrimitiveDateTime::parse`], it is not an error for the input to continue after the
+    /// `PrimitiveDateTime`.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, PrimitiveDateTime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_prefix("2020-01-02 03:04:05 rest of line", &format)?,
+    ///     (datetime!(2020-01-02 03:04:05), " rest of line")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((Self::try_from(parsed)?, parsing::remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for PrimitiveDateTime {
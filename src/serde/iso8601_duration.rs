@@ -0,0 +1,53 @@
+//! Use the [ISO 8601 duration format] when serializing and deserializing a [`Duration`].
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! [ISO 8601 duration format]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::marker::PhantomData;
+
+use serde::{Deserializer, Serialize, Serializer};
+
+use super::Visitor;
+use crate::Duration;
+
+/// A marker for deserializing a [`Duration`] from its ISO 8601 representation.
+pub(super) struct Iso8601Duration;
+
+/// Serialize a [`Duration`] using the ISO 8601 duration format.
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    duration.format_iso8601().serialize(serializer)
+}
+
+/// Deserialize a [`Duration`] from its ISO 8601 representation.
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Duration, D::Error> {
+    deserializer.deserialize_any(Visitor::<Iso8601Duration>(PhantomData))
+}
+
+/// Use the [ISO 8601 duration format] when serializing and deserializing an
+/// [`Option<Duration>`].
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [ISO 8601 duration format]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an [`Option<Duration>`] using the ISO 8601 duration format.
+    pub fn serialize<S: Serializer>(
+        option: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Duration::format_iso8601).serialize(serializer)
+    }
+
+    /// Deserialize an [`Option<Duration>`] from its ISO 8601 representation.
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        deserializer.deserialize_option(Visitor::<Option<Iso8601Duration>>(PhantomData))
+    }
+}
@@ -13,6 +13,8 @@ macro_rules! item {
     };
 }
 
+#[cfg(feature = "serde-well-known")]
+pub mod iso8601_duration;
 #[cfg(feature = "serde-well-known")]
 pub mod rfc2822;
 #[cfg(feature = "serde-well-known")]
@@ -1,4 +1,4 @@
-//! Treat an [`OffsetDateTime`] as a [Unix timestamp] for the purposes of serde.
+//! Treat an [`OffsetDateTime`] as a [Unix timestamp] in milliseconds for the purposes of serde.
 //!
 //! Use this module in combination with serde's [`#[with]`][with] attribute.
 //!
@@ -11,21 +11,21 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::OffsetDateTime;
 
-/// Serialize an `OffsetDateTime` as its Unix timestamp
+/// Serialize an `OffsetDateTime` as its Unix timestamp in milliseconds
 pub fn serialize<S: Serializer>(
     datetime: &OffsetDateTime,
     serializer: S,
 ) -> Result<S::Ok, S::Error> {
-    datetime.unix_timestamp().serialize(serializer)
+    datetime.unix_timestamp_millis().serialize(serializer)
 }
 
-/// Deserialize an `OffsetDateTime` from its Unix timestamp
+/// Deserialize an `OffsetDateTime` from its Unix timestamp in milliseconds
 pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
-    OffsetDateTime::from_unix_timestamp(<_>::deserialize(deserializer)?)
+    OffsetDateTime::from_unix_timestamp_millis(<_>::deserialize(deserializer)?)
         .map_err(|err| de::Error::invalid_value(de::Unexpected::Signed(err.value), &err))
 }
 
-/// Treat an `Option<OffsetDateTime>` as a [Unix timestamp] for the purposes of
+/// Treat an `Option<OffsetDateTime>` as a [Unix timestamp] in milliseconds for the purposes of
 /// serde.
 ///
 /// Use this module in combination with serde's [`#[with]`][with] attribute.
@@ -38,22 +38,22 @@ pub mod option {
     #[allow(clippy::wildcard_imports)]
     use super::*;
 
-    /// Serialize an `Option<OffsetDateTime>` as its Unix timestamp
+    /// Serialize an `Option<OffsetDateTime>` as its Unix timestamp in milliseconds
     pub fn serialize<S: Serializer>(
         option: &Option<OffsetDateTime>,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
         option
-            .map(OffsetDateTime::unix_timestamp)
+            .map(OffsetDateTime::unix_timestamp_millis)
             .serialize(serializer)
     }
 
-    /// Deserialize an `Option<OffsetDateTime>` from its Unix timestamp
+    /// Deserialize an `Option<OffsetDateTime>` from its Unix timestamp in milliseconds
     pub fn deserialize<'a, D: Deserializer<'a>>(
         deserializer: D,
     ) -> Result<Option<OffsetDateTime>, D::Error> {
         Option::deserialize(deserializer)?
-            .map(OffsetDateTime::from_unix_timestamp)
+            .map(OffsetDateTime::from_unix_timestamp_millis)
             .transpose()
             .map_err(|err| de::Error::invalid_value(de::Unexpected::Signed(err.value), &err))
     }
@@ -0,0 +1,67 @@
+//! Treat an [`OffsetDateTime`] as a [Unix timestamp] with fractional seconds for the purposes of
+//! serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! When deserializing, the offset is assumed to be UTC and the value is rounded to the nearest
+//! nanosecond. Due to the limited precision of `f64`, contemporary timestamps only round-trip to
+//! within a microsecond.
+//!
+//! [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::OffsetDateTime;
+
+/// Serialize an `OffsetDateTime` as its Unix timestamp with fractional seconds
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    datetime.fractional_seconds(0).serialize(serializer)
+}
+
+/// Deserialize an `OffsetDateTime` from its Unix timestamp with fractional seconds
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    let timestamp = <_>::deserialize(deserializer)?;
+    OffsetDateTime::from_fractional_seconds(timestamp, 0, "timestamp")
+        .map_err(|err| de::Error::invalid_value(de::Unexpected::Float(timestamp), &err))
+}
+
+/// Treat an `Option<OffsetDateTime>` as a [Unix timestamp] with fractional seconds for the
+/// purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// When deserializing, the offset is assumed to be UTC and the value is rounded to the nearest
+/// nanosecond.
+///
+/// [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an `Option<OffsetDateTime>` as its Unix timestamp with fractional seconds
+    pub fn serialize<S: Serializer>(
+        option: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option
+            .map(|datetime| datetime.fractional_seconds(0))
+            .serialize(serializer)
+    }
+
+    /// Deserialize an `Option<OffsetDateTime>` from its Unix timestamp with fractional seconds
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        Option::deserialize(deserializer)?
+            .map(|timestamp| {
+                OffsetDateTime::from_fractional_seconds(timestamp, 0, "timestamp")
+                    .map_err(|err| de::Error::invalid_value(de::Unexpected::Float(timestamp), &err))
+            })
+            .transpose()
+    }
+}
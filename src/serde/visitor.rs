@@ -1,5 +1,6 @@
 //! Serde visitor for various types.
 
+use core::convert::TryFrom;
 use core::fmt;
 use core::marker::PhantomData;
 
@@ -7,6 +8,9 @@ use serde::de;
 #[cfg(feature = "serde-well-known")]
 use serde::Deserializer;
 
+#[cfg(feature = "serde-well-known")]
+use super::iso8601_duration::Iso8601Duration;
+use super::timestamp::MaybeI128;
 #[cfg(feature = "parsing")]
 use super::{
     DATE_FORMAT, OFFSET_DATE_TIME_FORMAT, PRIMITIVE_DATE_TIME_FORMAT, TIME_FORMAT,
@@ -17,6 +21,20 @@ use crate::error::ComponentRange;
 use crate::format_description::well_known;
 use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
+/// Create a `Time` from its components, which may fall within a leap second.
+const fn time_from_hms_nano(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+) -> Result<Time, ComponentRange> {
+    if second == 60 {
+        Time::from_leap_second(hour, minute, nanosecond)
+    } else {
+        Time::from_hms_nano(hour, minute, second, nanosecond)
+    }
+}
+
 /// A serde visitor for various types.
 pub(super) struct Visitor<T: ?Sized>(pub(super) PhantomData<T>);
 
@@ -96,7 +114,9 @@ impl<'a> de::Visitor<'a> for Visitor<OffsetDateTime> {
         let offset_seconds = item!(seq, "offset seconds")?;
 
         Date::from_ordinal_date(year, ordinal)
-            .and_then(|date| date.with_hms_nano(hour, minute, second, nanosecond))
+            .and_then(|date| {
+                time_from_hms_nano(hour, minute, second, nanosecond).map(|time| date.with_time(time))
+            })
             .and_then(|datetime| {
                 UtcOffset::from_hms(offset_hours, offset_minutes, offset_seconds)
                     .map(|offset| datetime.assume_offset(offset))
@@ -126,7 +146,9 @@ impl<'a> de::Visitor<'a> for Visitor<PrimitiveDateTime> {
         let nanosecond = item!(seq, "nanosecond")?;
 
         Date::from_ordinal_date(year, ordinal)
-            .and_then(|date| date.with_hms_nano(hour, minute, second, nanosecond))
+            .and_then(|date| {
+                time_from_hms_nano(hour, minute, second, nanosecond).map(|time| date.with_time(time))
+            })
             .map_err(ComponentRange::into_de_error)
     }
 }
@@ -149,7 +171,7 @@ impl<'a> de::Visitor<'a> for Visitor<Time> {
         let second = item!(seq, "second")?;
         let nanosecond = item!(seq, "nanosecond")?;
 
-        Time::from_hms_nano(hour, minute, second, nanosecond).map_err(ComponentRange::into_de_error)
+        time_from_hms_nano(hour, minute, second, nanosecond).map_err(ComponentRange::into_de_error)
     }
 }
 
@@ -327,3 +349,64 @@ impl<'a> de::Visitor<'a> for Visitor<Option<well_known::Rfc3339>> {
         Ok(None)
     }
 }
+
+impl<'a> de::Visitor<'a> for Visitor<MaybeI128> {
+    type Value = MaybeI128;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a Unix timestamp")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<MaybeI128, E> {
+        Ok(MaybeI128(value.into()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<MaybeI128, E> {
+        Ok(MaybeI128(value.into()))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<MaybeI128, E> {
+        Ok(MaybeI128(value))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<MaybeI128, E> {
+        i128::try_from(value)
+            .map(MaybeI128)
+            .map_err(|_| E::invalid_value(de::Unexpected::Other("u128"), &self))
+    }
+}
+
+#[cfg(feature = "serde-well-known")]
+impl<'a> de::Visitor<'a> for Visitor<Iso8601Duration> {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601-formatted `Duration`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Duration, E> {
+        Duration::parse_iso8601(value).map_err(E::custom)
+    }
+}
+
+#[cfg(feature = "serde-well-known")]
+impl<'a> de::Visitor<'a> for Visitor<Option<Iso8601Duration>> {
+    type Value = Option<Duration>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601-formatted `Option<Duration>`")
+    }
+
+    fn visit_some<D: Deserializer<'a>>(
+        self,
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        deserializer
+            .deserialize_any(Visitor::<Iso8601Duration>(PhantomData))
+            .map(Some)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<Duration>, E> {
+        Ok(None)
+    }
+}
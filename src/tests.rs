@@ -12,7 +12,6 @@
 use std::num::NonZeroU8;
 
 use crate::format_description::modifier::Modifiers;
-use crate::format_description::FormatItem;
 use crate::formatting::DigitCount;
 use crate::parsing::shim::Integer;
 use crate::{duration, parsing};
@@ -71,7 +70,7 @@ fn debug() {
     let _ = format!(
         "{:?}",
         crate::format_description::parse::ParsedItem {
-            item: FormatItem::Literal(b""),
+            item: crate::format_description::parse::Item::Literal(b""),
             remaining: b""
         }
     );
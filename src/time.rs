@@ -1,5 +1,7 @@
 //! The [`Time`] struct and its associated `impl`s.
 
+#[cfg(feature = "parsing")]
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
@@ -9,7 +11,7 @@ use std::io;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{self, Parsable};
 use crate::util::DateAdjustment;
 use crate::{error, Duration};
 
@@ -24,8 +26,9 @@ pub(crate) enum Padding {
 
 /// The clock time within a given date. Nanosecond precision.
 ///
-/// All minutes are assumed to have exactly 60 seconds; no attempt is made to handle leap seconds
-/// (either positive or negative).
+/// Outside of [leap seconds](Self::from_leap_second), all minutes are assumed to have exactly 60
+/// seconds. A leap second is represented as the second `60` of the minute. Arithmetic treats it as
+/// the first second of the following minute, matching the behavior of Unix timestamps.
 ///
 /// When comparing two `Time`s, they are assumed to be in the same calendar date.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -202,6 +205,36 @@ impl Time {
             hour, minute, second, nanosecond,
         ))
     }
+
+    /// Attempt to create a `Time` that falls within a positive leap second. The second of the
+    /// returned value is `60`.
+    ///
+    /// No attempt is made to verify that a leap second actually occurred at the given time. See
+    /// [`LeapSecondTable`](crate::leap_seconds::LeapSecondTable) for that information.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert_eq!(Time::from_leap_second(23, 59, 0)?.second(), 60);
+    /// assert!(Time::from_leap_second(23, 59, 0)?.is_leap_second());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// assert!(Time::from_leap_second(24, 59, 0).is_err()); // 24 isn't a valid hour.
+    /// assert!(Time::from_leap_second(23, 60, 0).is_err()); // 60 isn't a valid minute.
+    /// assert!(Time::from_leap_second(23, 59, 1_000_000_000).is_err()); // 1_000_000_000 isn't a valid nanosecond.
+    /// ```
+    pub const fn from_leap_second(
+        hour: u8,
+        minute: u8,
+        nanosecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hour in 0 => 23);
+        ensure_value_in_range!(minute in 0 => 59);
+        ensure_value_in_range!(nanosecond in 0 => 999_999_999);
+        Ok(Self::__from_hms_nanos_unchecked(hour, minute, 60, nanosecond))
+    }
     // endregion constructors
 
     // region: getters
@@ -288,7 +321,8 @@ impl Time {
 
     /// Get the second within the minute.
     ///
-    /// The returned value will always be in the range `0..60`.
+    /// The returned value will always be in the range `0..60`, unless the value is a [leap
+    /// second](Self::is_leap_second), in which case it is `60`.
     ///
     /// ```rust
     /// # use time::macros::time;
@@ -299,6 +333,19 @@ impl Time {
         self.second
     }
 
+    /// Whether the `Time` falls within a positive leap second, which is represented as the second
+    /// `60` of the minute.
+    ///
+    /// ```rust
+    /// # use time::{macros::time, Time};
+    /// assert!(!time!(23:59:59).is_leap_second());
+    /// assert!(Time::from_leap_second(23, 59, 0)?.is_leap_second());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn is_leap_second(self) -> bool {
+        self.second == 60
+    }
+
     /// Get the milliseconds within the second.
     ///
     /// The returned value will always be in the range `0..1_000`.
@@ -495,6 +542,28 @@ impl Time {
     ) -> Result<Self, error::Parse> {
         description.parse_time(input.as_bytes())
     }
+
+    /// Parse a `Time` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the remainder of the input.
+    ///
+    /// Unlike [`Time::parse`], it is not an error for the input to continue after the `Time`.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::time, Time};
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     Time::parse_prefix("12:00:00 rest of line", &format)?,
+    ///     (time!(12:00), " rest of line")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((Self::try_from(parsed)?, parsing::remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for Time {
@@ -0,0 +1,316 @@
+//! Time scales other than UTC.
+//!
+//! Each type in this module wraps the reading of a clock keeping the given time scale. Unlike UTC,
+//! these scales do not have leap seconds, so every minute is exactly sixty seconds long. Conversions
+//! to and from UTC use a [`LeapSecondTable`].
+//!
+//! The Julian date in a given time scale is available from the underlying [`PrimitiveDateTime`].
+//!
+//! ```rust
+//! # use time::{macros::datetime, time_scale::Tt};
+//! let tt = Tt::from_utc(datetime!(2000-01-01 11:58:55.816 UTC))?;
+//! assert_eq!(tt.datetime().to_julian_date(), 2_451_545.0);
+//! # Ok::<_, time::Error>(())
+//! ```
+//!
+//! Converting between time scales fails if the result is outside the range supported by
+//! [`PrimitiveDateTime`].
+
+use core::convert::{TryFrom, TryInto};
+
+use crate::leap_seconds::LeapSecondTable;
+use crate::{error, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// The number of seconds by which TAI is ahead of GPS time.
+const TAI_MINUS_GPS: i64 = 19;
+
+/// The amount of time by which TT is ahead of TAI.
+const TT_MINUS_TAI: Duration = Duration::new(32, 184_000_000);
+
+/// The number of nanoseconds in one week.
+const NANOSECONDS_PER_WEEK: i128 = 604_800_000_000_000;
+
+/// International Atomic Time (TAI).
+///
+/// ```rust
+/// # use time::{macros::datetime, time_scale::Tai};
+/// let tai = Tai::from_utc(datetime!(2017-01-01 0:00 UTC))?;
+/// assert_eq!(tai.datetime(), datetime!(2017-01-01 0:00:37));
+/// assert_eq!(tai.to_utc()?, datetime!(2017-01-01 0:00 UTC));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tai(PrimitiveDateTime);
+
+impl Tai {
+    /// Create a value from the reading of a clock keeping TAI.
+    pub const fn new(datetime: PrimitiveDateTime) -> Self {
+        Self(datetime)
+    }
+
+    /// Get the reading of a clock keeping TAI.
+    pub const fn datetime(self) -> PrimitiveDateTime {
+        self.0
+    }
+
+    /// Convert a moment in UTC to TAI using the [built-in](LeapSecondTable::BUILTIN) table.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_scale::Tai, Time};
+    /// let leap_second = datetime!(2016-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?);
+    /// assert_eq!(Tai::from_utc(leap_second)?.datetime(), datetime!(2017-01-01 0:00:36));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn from_utc(datetime: OffsetDateTime) -> Result<Self, error::ConversionRange> {
+        Self::from_utc_with_table(datetime, LeapSecondTable::BUILTIN)
+    }
+
+    /// Convert a moment in UTC to TAI using the provided table.
+    pub fn from_utc_with_table(
+        datetime: OffsetDateTime,
+        table: LeapSecondTable<'_>,
+    ) -> Result<Self, error::ConversionRange> {
+        table.utc_to_tai(datetime).map(Self)
+    }
+
+    /// Convert to UTC using the [built-in](LeapSecondTable::BUILTIN) table. Moments that fall
+    /// within a leap second have a second of 60.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_scale::Tai};
+    /// let utc = Tai::new(datetime!(2017-01-01 0:00:36.5)).to_utc()?;
+    /// assert!(utc.time().is_leap_second());
+    /// assert_eq!(utc.date(), time::macros::date!(2016-12-31));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn to_utc(self) -> Result<OffsetDateTime, error::ConversionRange> {
+        self.to_utc_with_table(LeapSecondTable::BUILTIN)
+    }
+
+    /// Convert to UTC using the provided table.
+    pub fn to_utc_with_table(
+        self,
+        table: LeapSecondTable<'_>,
+    ) -> Result<OffsetDateTime, error::ConversionRange> {
+        table.tai_to_utc(self.0)
+    }
+}
+
+/// Terrestrial Time (TT), which is a constant 32.184 seconds ahead of TAI.
+///
+/// ```rust
+/// # use time::{macros::datetime, time_scale::Tt};
+/// let tt = Tt::from_utc(datetime!(2017-01-01 0:00 UTC))?;
+/// assert_eq!(tt.datetime(), datetime!(2017-01-01 0:01:09.184));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tt(PrimitiveDateTime);
+
+impl Tt {
+    /// Create a value from the reading of a clock keeping TT.
+    pub const fn new(datetime: PrimitiveDateTime) -> Self {
+        Self(datetime)
+    }
+
+    /// Get the reading of a clock keeping TT.
+    pub const fn datetime(self) -> PrimitiveDateTime {
+        self.0
+    }
+
+    /// Convert a moment in UTC to TT using the [built-in](LeapSecondTable::BUILTIN) table.
+    pub fn from_utc(datetime: OffsetDateTime) -> Result<Self, error::ConversionRange> {
+        Tai::from_utc(datetime)?.try_into()
+    }
+
+    /// Convert a moment in UTC to TT using the provided table.
+    pub fn from_utc_with_table(
+        datetime: OffsetDateTime,
+        table: LeapSecondTable<'_>,
+    ) -> Result<Self, error::ConversionRange> {
+        Tai::from_utc_with_table(datetime, table)?.try_into()
+    }
+
+    /// Convert to UTC using the [built-in](LeapSecondTable::BUILTIN) table.
+    pub fn to_utc(self) -> Result<OffsetDateTime, error::ConversionRange> {
+        Tai::try_from(self)?.to_utc()
+    }
+
+    /// Convert to UTC using the provided table.
+    pub fn to_utc_with_table(
+        self,
+        table: LeapSecondTable<'_>,
+    ) -> Result<OffsetDateTime, error::ConversionRange> {
+        Tai::try_from(self)?.to_utc_with_table(table)
+    }
+}
+
+/// GPS time, which is a constant nineteen seconds behind TAI.
+///
+/// ```rust
+/// # use time::{macros::datetime, time_scale::Gps};
+/// let gps = Gps::from_utc(datetime!(2017-01-01 0:00 UTC))?;
+/// assert_eq!(gps.datetime(), datetime!(2017-01-01 0:00:18));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gps(PrimitiveDateTime);
+
+impl Gps {
+    /// The start of GPS week zero, January 6, 1980.
+    pub const EPOCH: Self = Self(PrimitiveDateTime::new(
+        Date::__from_ordinal_date_unchecked(1980, 6),
+        Time::MIDNIGHT,
+    ));
+
+    /// Create a value from the reading of a clock keeping GPS time.
+    pub const fn new(datetime: PrimitiveDateTime) -> Self {
+        Self(datetime)
+    }
+
+    /// Get the reading of a clock keeping GPS time.
+    pub const fn datetime(self) -> PrimitiveDateTime {
+        self.0
+    }
+
+    /// Create a value from a GPS week number and the time elapsed since the start of that week.
+    /// The week number is counted from the [epoch](Self::EPOCH) and is not truncated to ten or
+    /// thirteen bits as it is in the navigation message.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, macros::datetime, time_scale::Gps};
+    /// assert_eq!(
+    ///     Gps::from_week_and_time_of_week(2_190, 518_400.5.seconds())?.datetime(),
+    ///     datetime!(2022-01-01 0:00:00.5)
+    /// );
+    /// assert!(Gps::from_week_and_time_of_week(2_190, 7.days()).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn from_week_and_time_of_week(
+        week: i32,
+        time_of_week: Duration,
+    ) -> Result<Self, error::ComponentRange> {
+        if time_of_week.is_negative() || time_of_week >= Duration::WEEK {
+            return Err(error::ComponentRange {
+                name: "time_of_week",
+                minimum: 0,
+                maximum: Duration::WEEK.whole_seconds() - 1,
+                value: time_of_week.whole_seconds(),
+                conditional_range: false,
+            });
+        }
+
+        Self::EPOCH
+            .0
+            .checked_add(Duration::weeks(week as _))
+            .and_then(|datetime| datetime.checked_add(time_of_week))
+            .map(Self)
+            .ok_or_else(|| error::ComponentRange {
+                name: "week",
+                minimum: Self::new(PrimitiveDateTime::MIN)
+                    .to_week_and_time_of_week()
+                    .0 as _,
+                maximum: Self::new(PrimitiveDateTime::MAX)
+                    .to_week_and_time_of_week()
+                    .0 as _,
+                value: week as _,
+                conditional_range: true,
+            })
+    }
+
+    /// Get the GPS week number and the time elapsed since the start of that week. The week number
+    /// is negative prior to the [epoch](Self::EPOCH).
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, macros::datetime, time_scale::Gps};
+    /// assert_eq!(
+    ///     Gps::new(datetime!(2022-01-01 0:00:00.5)).to_week_and_time_of_week(),
+    ///     (2_190, 518_400.5.seconds())
+    /// );
+    /// assert_eq!(
+    ///     Gps::new(datetime!(1980-01-05 0:00)).to_week_and_time_of_week(),
+    ///     (-1, 6.days())
+    /// );
+    /// ```
+    pub fn to_week_and_time_of_week(self) -> (i32, Duration) {
+        let nanoseconds = (self.0 - Self::EPOCH.0).whole_nanoseconds();
+        let week = div_floor!(nanoseconds, NANOSECONDS_PER_WEEK);
+        let time_of_week = nanoseconds - week * NANOSECONDS_PER_WEEK;
+        (
+            week as _,
+            Duration::new(
+                (time_of_week / 1_000_000_000) as _,
+                (time_of_week % 1_000_000_000) as _,
+            ),
+        )
+    }
+
+    /// Convert a moment in UTC to GPS time using the [built-in](LeapSecondTable::BUILTIN) table.
+    pub fn from_utc(datetime: OffsetDateTime) -> Result<Self, error::ConversionRange> {
+        Tai::from_utc(datetime)?.try_into()
+    }
+
+    /// Convert a moment in UTC to GPS time using the provided table.
+    pub fn from_utc_with_table(
+        datetime: OffsetDateTime,
+        table: LeapSecondTable<'_>,
+    ) -> Result<Self, error::ConversionRange> {
+        Tai::from_utc_with_table(datetime, table)?.try_into()
+    }
+
+    /// Convert to UTC using the [built-in](LeapSecondTable::BUILTIN) table.
+    pub fn to_utc(self) -> Result<OffsetDateTime, error::ConversionRange> {
+        Tai::try_from(self)?.to_utc()
+    }
+
+    /// Convert to UTC using the provided table.
+    pub fn to_utc_with_table(
+        self,
+        table: LeapSecondTable<'_>,
+    ) -> Result<OffsetDateTime, error::ConversionRange> {
+        Tai::try_from(self)?.to_utc_with_table(table)
+    }
+}
+
+impl TryFrom<Tai> for Gps {
+    type Error = error::ConversionRange;
+
+    fn try_from(tai: Tai) -> Result<Self, Self::Error> {
+        tai.0
+            .checked_sub(Duration::seconds(TAI_MINUS_GPS))
+            .map(Self)
+            .ok_or(error::ConversionRange)
+    }
+}
+
+impl TryFrom<Gps> for Tai {
+    type Error = error::ConversionRange;
+
+    fn try_from(gps: Gps) -> Result<Self, Self::Error> {
+        gps.0
+            .checked_add(Duration::seconds(TAI_MINUS_GPS))
+            .map(Self)
+            .ok_or(error::ConversionRange)
+    }
+}
+
+impl TryFrom<Tai> for Tt {
+    type Error = error::ConversionRange;
+
+    fn try_from(tai: Tai) -> Result<Self, Self::Error> {
+        tai.0
+            .checked_add(TT_MINUS_TAI)
+            .map(Self)
+            .ok_or(error::ConversionRange)
+    }
+}
+
+impl TryFrom<Tt> for Tai {
+    type Error = error::ConversionRange;
+
+    fn try_from(tt: Tt) -> Result<Self, Self::Error> {
+        tt.0.checked_sub(TT_MINUS_TAI)
+            .map(Self)
+            .ok_or(error::ConversionRange)
+    }
+}
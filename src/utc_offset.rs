@@ -1,5 +1,7 @@
 //! The [`UtcOffset`] struct and its associated `impl`s.
 
+#[cfg(feature = "parsing")]
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::Neg;
 #[cfg(feature = "formatting")]
@@ -9,7 +11,7 @@ use crate::error;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{self, Parsable};
 #[cfg(feature = "local-offset")]
 use crate::sys::local_offset_at;
 #[cfg(feature = "local-offset")]
@@ -296,6 +298,29 @@ impl UtcOffset {
     ) -> Result<Self, error::Parse> {
         description.parse_offset(input.as_bytes())
     }
+
+    /// Parse a `UtcOffset` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the remainder of the input.
+    ///
+    /// Unlike [`UtcOffset::parse`], it is not an error for the input to continue after the
+    /// `UtcOffset`.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset, UtcOffset};
+    /// let format = format_description::parse("[offset_hour]:[offset_minute]")?;
+    /// assert_eq!(
+    ///     UtcOffset::parse_prefix("-03:42 rest of line", &format)?,
+    ///     (offset!(-3:42), " rest of line")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a str), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input.as_bytes())?;
+        Ok((Self::try_from(parsed)?, parsing::remaining_str(input, remaining)?))
+    }
 }
 
 impl fmt::Display for UtcOffset {
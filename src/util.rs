@@ -12,6 +12,18 @@ pub(crate) enum DateAdjustment {
     None,
 }
 
+/// Get the English ordinal suffix of a day of the month, such as `st` for the first day.
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub(crate) const fn ordinal_suffix(day: u8) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
 /// Get the number of days in the month of a given year.
 ///
 /// ```rust
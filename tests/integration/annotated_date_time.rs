@@ -0,0 +1,153 @@
+use time::macros::{datetime, offset};
+use time::{error, AnnotatedDateTime, TimeZoneAnnotation};
+
+#[test]
+fn new() {
+    let value = AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1));
+    assert_eq!(value.date_time(), datetime!(2022-07-08 0:14:07 +1));
+    assert_eq!(value.time_zone(), None);
+    assert!(!value.time_zone_is_critical());
+    assert_eq!(value.calendar(), None);
+    assert!(!value.calendar_is_critical());
+    assert_eq!(
+        AnnotatedDateTime::from(datetime!(2022-07-08 0:14:07 +1)),
+        value
+    );
+}
+
+#[test]
+fn format() -> time::Result<()> {
+    let value = AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1));
+    assert_eq!(value.format()?, "2022-07-08T00:14:07+01:00");
+    assert_eq!(
+        value
+            .clone()
+            .with_time_zone(TimeZoneAnnotation::Name("Europe/Paris".to_owned()), true)
+            .with_calendar("gregory", false)
+            .format()?,
+        "2022-07-08T00:14:07+01:00[!Europe/Paris][u-ca=gregory]"
+    );
+    assert_eq!(
+        value
+            .clone()
+            .with_time_zone(TimeZoneAnnotation::Offset(offset!(+1)), true)
+            .format()?,
+        "2022-07-08T00:14:07+01:00[!+01:00]"
+    );
+    assert_eq!(
+        value
+            .with_time_zone(TimeZoneAnnotation::Offset(offset!(-3:30)), false)
+            .format()?,
+        "2022-07-08T00:14:07+01:00[-03:30]"
+    );
+    assert_eq!(
+        AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 UTC))
+            .with_time_zone(TimeZoneAnnotation::Offset(offset!(+1)), true)
+            .with_calendar("iso8601", true)
+            .format()?,
+        "2022-07-08T00:14:07Z[!+01:00][!u-ca=iso8601]"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_err() {
+    let value = AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1));
+    assert!(matches!(
+        value
+            .clone()
+            .with_time_zone(TimeZoneAnnotation::Offset(offset!(+2)), true)
+            .format(),
+        Err(error::Format::InvalidComponent("time zone"))
+    ));
+    assert!(matches!(
+        value
+            .clone()
+            .with_time_zone(TimeZoneAnnotation::Offset(offset!(+1:00:01)), false)
+            .format(),
+        Err(error::Format::InvalidComponent("time zone"))
+    ));
+    assert!(matches!(
+        value
+            .clone()
+            .with_time_zone(TimeZoneAnnotation::Name("Europe/Paris]".to_owned()), false)
+            .format(),
+        Err(error::Format::InvalidComponent("time zone"))
+    ));
+    assert!(matches!(
+        value.with_calendar("", false).format(),
+        Err(error::Format::InvalidComponent("calendar"))
+    ));
+}
+
+#[test]
+fn parse() -> time::Result<()> {
+    let value = AnnotatedDateTime::parse(
+        "2022-07-08T00:14:07+01:00[!Europe/Paris][x-foo=bar][u-ca=gregory]",
+    )?;
+    assert_eq!(value.date_time(), datetime!(2022-07-08 0:14:07 +1));
+    assert_eq!(
+        value.time_zone(),
+        Some(&TimeZoneAnnotation::Name("Europe/Paris".to_owned()))
+    );
+    assert!(value.time_zone_is_critical());
+    assert_eq!(value.calendar(), Some("gregory"));
+    assert!(!value.calendar_is_critical());
+
+    let value = AnnotatedDateTime::parse("2022-07-08T00:14:07z[!+01:00][!u-ca=iso8601]")?;
+    assert_eq!(value.date_time(), datetime!(2022-07-08 0:14:07 UTC));
+    assert_eq!(
+        value.time_zone(),
+        Some(&TimeZoneAnnotation::Offset(offset!(+1)))
+    );
+    assert!(value.time_zone_is_critical());
+    assert_eq!(value.calendar(), Some("iso8601"));
+    assert!(value.calendar_is_critical());
+
+    let value = AnnotatedDateTime::parse("2022-07-08T00:14:07+01:00[u-ca=gregory]")?;
+    assert_eq!(value.time_zone(), None);
+    assert_eq!(value.calendar(), Some("gregory"));
+
+    assert_eq!(
+        AnnotatedDateTime::parse("2022-07-08T00:14:07+01:00")?,
+        AnnotatedDateTime::new(datetime!(2022-07-08 0:14:07 +1))
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_err() {
+    assert!(matches!(
+        AnnotatedDateTime::parse("2022-07-08T00:14:07+00:00[!+01:00]"),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("time zone")
+        ))
+    ));
+    assert!(matches!(
+        AnnotatedDateTime::parse("2022-07-08T00:14:07+01:00[!x-foo=bar]"),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("annotation")
+        ))
+    ));
+    assert!(matches!(
+        AnnotatedDateTime::parse("2022-07-08T00:14:07+01:00[Europe/Paris]x"),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        AnnotatedDateTime::parse("2022-07-08T00:14:07+01:00x[Europe/Paris]"),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+}
+
+#[test]
+fn roundtrip() -> time::Result<()> {
+    for input in [
+        "2022-07-08T00:14:07+01:00",
+        "2022-07-08T00:14:07.123+01:00[Europe/Paris]",
+        "2022-07-08T00:14:07Z[!Etc/UTC][!u-ca=gregory]",
+        "2022-07-08T00:14:07-05:00[-05:00][u-ca=iso8601]",
+    ] {
+        assert_eq!(AnnotatedDateTime::parse(input)?.format()?, input);
+    }
+    Ok(())
+}
@@ -2,11 +2,13 @@ use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 
+use time::duration_format::{DurationFormat, DurationUnit, Rounding, UnitNames};
 use time::error::{self, ConversionRange, IndeterminateOffset, TryFromParsed};
 use time::ext::NumericalDuration;
 use time::format_description::{self, modifier, well_known, Component, FormatItem};
+use time::locale::{English, FormatStyle, Localized, Styled};
 use time::macros::{date, offset, time};
-use time::parsing::Parsed;
+use time::parsing::{Lenient, Parsed};
 use time::{Duration, Error, Instant, Month, Time, Weekday};
 use time_macros::datetime;
 
@@ -51,6 +53,14 @@ fn clone() {
     assert_cloned_eq!(well_known::Rfc3339);
     assert_cloned_eq!(component_range_error());
     assert_cloned_eq!(FormatItem::Literal(b""));
+    assert_cloned_eq!(Lenient::new(FormatItem::Literal(b"")));
+    assert_cloned_eq!(Localized::new(FormatItem::Literal(b""), English));
+    assert_cloned_eq!(Styled::date(FormatStyle::Short, English));
+    assert_cloned_eq!(DurationFormat::DEFAULT);
+    assert_cloned_eq!(DurationFormat::DEFAULT.display(Duration::ZERO));
+    assert_cloned_eq!(DurationUnit::Second);
+    assert_cloned_eq!(UnitNames::Short);
+    assert_cloned_eq!(Rounding::Down);
 
     assert_cloned_eq!(modifier::Day::default());
     assert_cloned_eq!(modifier::MonthRepr::default());
@@ -87,6 +97,9 @@ fn hash() {
     Instant::now().hash(&mut hasher);
     Duration::ZERO.hash(&mut hasher);
     component_range_error().hash(&mut hasher);
+    English.hash(&mut hasher);
+    FormatStyle::Short.hash(&mut hasher);
+    DurationFormat::DEFAULT.hash(&mut hasher);
 }
 
 #[test]
@@ -112,6 +125,11 @@ fn ord() {
     assert_eq!(offset!(UTC).cmp(&offset!(+1)), Ordering::Less);
     assert_eq!(offset!(+1).cmp(&offset!(UTC)), Ordering::Greater);
     assert_eq!(offset!(UTC).cmp(&offset!(UTC)), Ordering::Equal);
+    assert_eq!(FormatStyle::Short.cmp(&FormatStyle::Full), Ordering::Less);
+    assert_eq!(
+        DurationUnit::Second.cmp(&DurationUnit::Minute),
+        Ordering::Less
+    );
 }
 
 #[test]
@@ -128,6 +146,10 @@ fn debug() {
         ConversionRange;
         TryFromParsed::InsufficientInformation;
         Parsed::new();
+        Lenient::new(FormatItem::Literal(b""));
+        Localized::new(FormatItem::Literal(b""), English);
+        Styled::date(FormatStyle::Short, English);
+        DurationFormat::DEFAULT.display(Duration::ZERO);
         Instant::now();
         error::ParseFromDescription::InvalidComponent("foo");
         error::Format::InvalidComponent("foo");
@@ -1,8 +1,10 @@
 use core::convert::TryFrom;
+use core::num::NonZeroU8;
 use core::u64;
 use std::cmp::Ordering;
 use std::time::Duration as StdDuration;
 
+use time::duration_format::{DurationFormat, DurationUnit, Rounding, UnitNames};
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::{error, Duration};
 
@@ -768,3 +770,291 @@ fn sum_iter() {
     let sum = i.into_iter().sum::<Duration>();
     assert_eq!(sum, 3.2.seconds());
 }
+
+#[test]
+fn format_iso8601() {
+    assert_eq!(Duration::ZERO.format_iso8601(), "PT0S");
+    assert_eq!(1.nanoseconds().format_iso8601(), "PT0.000000001S");
+    assert_eq!(1.5.seconds().format_iso8601(), "PT1.5S");
+    assert_eq!((-1.5).seconds().format_iso8601(), "-PT1.5S");
+    assert_eq!(90.minutes().format_iso8601(), "PT1H30M");
+    assert_eq!((3.days() + 4.hours()).format_iso8601(), "P3DT4H");
+    assert_eq!((1.days() + 1.seconds()).format_iso8601(), "P1DT1S");
+    assert_eq!(6.days().format_iso8601(), "P6D");
+    assert_eq!(2.weeks().format_iso8601(), "P2W");
+    assert_eq!((-2).weeks().format_iso8601(), "-P2W");
+    assert_eq!(
+        Duration::MIN.format_iso8601(),
+        "-P106751991167300DT15H30M8.999999999S"
+    );
+    assert_eq!(
+        Duration::MAX.format_iso8601(),
+        "P106751991167300DT15H30M7.999999999S"
+    );
+}
+
+#[test]
+fn parse_iso8601() -> Result<(), error::Parse> {
+    assert_eq!(Duration::parse_iso8601("PT0S")?, Duration::ZERO);
+    assert_eq!(Duration::parse_iso8601("P0D")?, Duration::ZERO);
+    assert_eq!(Duration::parse_iso8601("PT1H30M")?, 90.minutes());
+    assert_eq!(Duration::parse_iso8601("PT90M")?, 90.minutes());
+    assert_eq!(Duration::parse_iso8601("P3DT4H")?, 3.days() + 4.hours());
+    assert_eq!(Duration::parse_iso8601("+P1DT1S")?, 1.days() + 1.seconds());
+    assert_eq!(Duration::parse_iso8601("-PT1.5S")?, (-1.5).seconds());
+    assert_eq!(Duration::parse_iso8601("PT0,25S")?, 0.25.seconds());
+    assert_eq!(Duration::parse_iso8601("PT1.5H")?, 90.minutes());
+    assert_eq!(Duration::parse_iso8601("P0.5D")?, 12.hours());
+    assert_eq!(Duration::parse_iso8601("P2W")?, 2.weeks());
+    assert_eq!(Duration::parse_iso8601("P1W2D")?, 9.days());
+    assert_eq!(
+        Duration::parse_iso8601("PT0.1234567891S")?,
+        123_456_789.nanoseconds()
+    );
+    assert_eq!(
+        Duration::parse_iso8601("P106751991167300DT15H30M7.999999999S")?,
+        Duration::MAX
+    );
+    assert_eq!(
+        Duration::parse_iso8601("-P106751991167300DT15H30M8.999999999S")?,
+        Duration::MIN
+    );
+
+    assert!(matches!(
+        Duration::parse_iso8601("P1Y"),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("years")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse_iso8601("P1M"),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("months")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse_iso8601("P106751991167301D"),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("days")
+        ))
+    ));
+    assert!(matches!(
+        Duration::parse_iso8601("PT99999999999999999999999999999999999999999S"),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("seconds")
+        ))
+    ));
+    for input in ["", "P", "PT", "P1DT", "1D", "PT1", "PT1S1M", "PT1.S", "P1D1D"] {
+        assert!(matches!(
+            Duration::parse_iso8601(input),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidLiteral { .. }
+            ))
+        ));
+    }
+    for input in ["PT1.5H30M", "PT1SX"] {
+        assert!(matches!(
+            Duration::parse_iso8601(input),
+            Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn display() {
+    assert_eq!(Duration::ZERO.to_string(), "0ns");
+    assert_eq!(1.nanoseconds().to_string(), "1ns");
+    assert_eq!(1.5.seconds().to_string(), "1s 500ms");
+    assert_eq!((-1.5).seconds().to_string(), "-1s 500ms");
+    assert_eq!(90.minutes().to_string(), "1h 30m");
+    assert_eq!((1.days() + 1.seconds()).to_string(), "1d 1s");
+    assert_eq!(2.weeks().to_string(), "14d");
+    assert_eq!(
+        Duration::MAX.to_string(),
+        "106751991167300d 15h 30m 7s 999ms 999us 999ns"
+    );
+    assert_eq!(
+        Duration::MIN.to_string(),
+        "-106751991167300d 15h 30m 8s 999ms 999us 999ns"
+    );
+}
+
+#[test]
+fn duration_format() {
+    let duration = 1.weeks() + 1.days() + 1.hours() + 29.minutes() + 31.5.seconds();
+    let format = |format: DurationFormat| format.display(duration).to_string();
+
+    assert_eq!(format(DurationFormat::DEFAULT), "8d 1h 29m 31s 500ms");
+    assert_eq!(
+        format(DurationFormat::DEFAULT.set_largest_unit(DurationUnit::Week)),
+        "1w 1d 1h 29m 31s 500ms"
+    );
+    assert_eq!(
+        format(DurationFormat::DEFAULT.set_largest_unit(DurationUnit::Hour)),
+        "193h 29m 31s 500ms"
+    );
+    assert_eq!(
+        format(DurationFormat::DEFAULT.set_smallest_unit(DurationUnit::Minute)),
+        "8d 1h 29m"
+    );
+    assert_eq!(
+        format(
+            DurationFormat::DEFAULT
+                .set_largest_unit(DurationUnit::Second)
+                .set_smallest_unit(DurationUnit::Minute)
+        ),
+        "696571s"
+    );
+    assert_eq!(
+        format(DurationFormat::DEFAULT.set_unit_names(UnitNames::Long)),
+        "8 days 1 hour 29 minutes 31 seconds 500 milliseconds"
+    );
+    assert_eq!(
+        DurationFormat::DEFAULT
+            .set_unit_names(UnitNames::Long)
+            .display(Duration::ZERO)
+            .to_string(),
+        "0 nanoseconds"
+    );
+
+    let max_units = DurationFormat::DEFAULT.set_max_units(NonZeroU8::new(3));
+    assert_eq!(format(max_units), "8d 1h 29m");
+    assert_eq!(
+        format(max_units.set_rounding(Rounding::Nearest)),
+        "8d 1h 30m"
+    );
+    assert_eq!(format(max_units.set_rounding(Rounding::Up)), "8d 1h 30m");
+    assert_eq!(max_units.display(1.days() + 1.seconds()).to_string(), "1d");
+
+    let max_units = DurationFormat::DEFAULT.set_max_units(NonZeroU8::new(2));
+    assert_eq!(
+        max_units
+            .set_rounding(Rounding::Nearest)
+            .display(23.hours() + 59.minutes() + 30.seconds())
+            .to_string(),
+        "1d"
+    );
+    assert_eq!(
+        max_units
+            .set_rounding(Rounding::Up)
+            .display((-1).hours() - 1.nanoseconds())
+            .to_string(),
+        "-1h 1m"
+    );
+    assert_eq!(
+        DurationFormat::DEFAULT
+            .set_max_units(NonZeroU8::new(1))
+            .set_rounding(Rounding::Nearest)
+            .display(999.microseconds() + 500.nanoseconds())
+            .to_string(),
+        "1ms"
+    );
+
+    let seconds = DurationFormat::DEFAULT.set_smallest_unit(DurationUnit::Second);
+    assert_eq!(seconds.display(999.milliseconds()).to_string(), "0s");
+    assert_eq!(
+        seconds
+            .set_rounding(Rounding::Nearest)
+            .display((-1.5).seconds())
+            .to_string(),
+        "-2s"
+    );
+    assert_eq!(
+        seconds
+            .set_rounding(Rounding::Up)
+            .display(1.nanoseconds())
+            .to_string(),
+        "1s"
+    );
+    let weeks = DurationFormat::DEFAULT
+        .set_largest_unit(DurationUnit::Week)
+        .set_smallest_unit(DurationUnit::Week)
+        .set_rounding(Rounding::Up);
+    assert_eq!(weeks.display(Duration::MAX).to_string(), "15250284452471w");
+    assert_eq!(weeks.display(Duration::MIN).to_string(), "-15250284452471w");
+    assert_eq!(DurationFormat::default(), DurationFormat::DEFAULT);
+}
+
+#[test]
+fn from_str() -> Result<(), error::Parse> {
+    assert_eq!("0s".parse::<Duration>()?, Duration::ZERO);
+    assert_eq!("1h30m".parse::<Duration>()?, 90.minutes());
+    assert_eq!("90s".parse::<Duration>()?, 90.seconds());
+    assert_eq!("1.5 days".parse::<Duration>()?, 36.hours());
+    assert_eq!("2 weeks 3 days".parse::<Duration>()?, 17.days());
+    assert_eq!("2w 3d".parse::<Duration>()?, 17.days());
+    assert_eq!("1 Hour, 30 MINS".parse::<Duration>()?, 90.minutes());
+    assert_eq!("+1 hr 30 min".parse::<Duration>()?, 90.minutes());
+    assert_eq!("-1h 30m".parse::<Duration>()?, (-90).minutes());
+    assert_eq!("1m 1m".parse::<Duration>()?, 2.minutes());
+    assert_eq!("1 second 1 ms".parse::<Duration>()?, 1_001.milliseconds());
+    assert_eq!("5us 5µs 5ns".parse::<Duration>()?, 10_005.nanoseconds());
+    assert_eq!(
+        "0.1234567891s".parse::<Duration>()?,
+        123_456_789.nanoseconds()
+    );
+    assert_eq!(
+        "106751991167300d 15h 30m 7.999999999s".parse::<Duration>()?,
+        Duration::MAX
+    );
+    assert_eq!(
+        "-106751991167300d 15h 30m 8.999999999s".parse::<Duration>()?,
+        Duration::MIN
+    );
+
+    for duration in [
+        Duration::ZERO,
+        1.5.seconds(),
+        (-90).minutes(),
+        Duration::MAX,
+        Duration::MIN,
+    ] {
+        assert_eq!(duration.to_string().parse::<Duration>()?, duration);
+        let long = DurationFormat::DEFAULT
+            .set_largest_unit(DurationUnit::Week)
+            .set_unit_names(UnitNames::Long)
+            .display(duration)
+            .to_string();
+        assert_eq!(long.parse::<Duration>()?, duration);
+        for rounding in [Rounding::Down, Rounding::Nearest, Rounding::Up] {
+            let weeks = DurationFormat::DEFAULT
+                .set_largest_unit(DurationUnit::Week)
+                .set_smallest_unit(DurationUnit::Week)
+                .set_rounding(rounding)
+                .display(duration)
+                .to_string();
+            assert!(weeks.parse::<Duration>().is_ok(), "{}", weeks);
+        }
+    }
+
+    assert!(matches!(
+        "106751991167301d".parse::<Duration>(),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        "99999999999999999999999999999999999999999s".parse::<Duration>(),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    for input in ["", "-", "h", "1", "1.h", "1 month", "1 y", " 1h"] {
+        assert!(matches!(
+            input.parse::<Duration>(),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidLiteral { .. }
+            ))
+        ));
+    }
+    for input in ["1h ", "1h,", "1h x", "1h -1m"] {
+        assert!(matches!(
+            input.parse::<Duration>(),
+            Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        ));
+    }
+
+    Ok(())
+}
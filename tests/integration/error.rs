@@ -3,11 +3,13 @@ use std::error::Error as _;
 use std::io;
 
 use time::error::{
-    ComponentRange, ConversionRange, DifferentVariant, Error, Format, IndeterminateOffset,
-    InvalidFormatDescription, Parse, ParseFromDescription, TryFromParsed,
+    ComponentRange, ConversionRange, DifferentVariant, Error, Expected, Format,
+    IndeterminateOffset, InvalidFormatDescription, InvalidInput, Parse, ParseFromDescription,
+    TryFromParsed,
 };
 use time::macros::format_description;
-use time::parsing::Parsed;
+use time::parsing::{Located, Parsed};
+use time::format_description::Component;
 use time::{format_description, Date, Time};
 
 macro_rules! assert_display_eq {
@@ -57,11 +59,22 @@ fn invalid_literal() -> ParseFromDescription {
     Parsed::parse_literal(b"a", b"b").unwrap_err()
 }
 
+fn invalid_input() -> InvalidInput {
+    match Time::parse(
+        "12:3x",
+        &Located::new(format_description!("[hour]:[minute]")),
+    ) {
+        Err(Parse::InvalidInput(err)) => err,
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn debug() {
     assert_dbg_reflexive!(Parse::from(ParseFromDescription::InvalidComponent("a")));
     assert_dbg_reflexive!(invalid_format_description());
     assert_dbg_reflexive!(DifferentVariant);
+    assert_dbg_reflexive!(invalid_input());
 }
 
 #[test]
@@ -109,6 +122,9 @@ fn display() {
     );
     assert_display_eq!(io_error(), Format::from(io_error()));
     assert_display_eq!(DifferentVariant, Error::from(DifferentVariant));
+    assert_display_eq!(invalid_input(), Parse::from(invalid_input()));
+    assert_display_eq!(invalid_input(), Error::from(invalid_input()));
+    assert_display_eq!(invalid_input(), Error::from(Parse::from(invalid_input())));
 }
 
 #[test]
@@ -145,6 +161,9 @@ fn source() {
     );
     assert_source!(Format::from(io_error()), io::Error);
     assert_source!(Error::from(DifferentVariant), DifferentVariant);
+    assert_source!(invalid_input(), ParseFromDescription);
+    assert_source!(Parse::from(invalid_input()), InvalidInput);
+    assert_source!(Error::from(invalid_input()), InvalidInput);
 }
 
 #[test]
@@ -169,6 +188,11 @@ fn conversion() {
     assert!(TryFromParsed::try_from(Error::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(TryFromParsed::try_from(Parse::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(std::io::Error::try_from(Format::from(io_error())).is_ok());
+    assert!(InvalidInput::try_from(Error::from(invalid_input())).is_ok());
+    assert!(InvalidInput::try_from(Parse::from(invalid_input())).is_ok());
+    assert!(Parse::try_from(Error::from(invalid_input())).is_ok());
+    assert!(ParseFromDescription::try_from(Parse::from(invalid_input())).is_ok());
+    assert!(ParseFromDescription::try_from(Error::from(invalid_input())).is_ok());
 
     assert!(ComponentRange::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ConversionRange::try_from(Error::from(IndeterminateOffset)).is_err());
@@ -183,4 +207,92 @@ fn conversion() {
     assert!(TryFromParsed::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(TryFromParsed::try_from(unexpected_trailing_characters()).is_err());
     assert!(std::io::Error::try_from(insufficient_type_information()).is_err());
+    assert!(InvalidInput::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidInput::try_from(unexpected_trailing_characters()).is_err());
+}
+
+#[test]
+fn invalid_input_details() {
+    let err = invalid_input();
+    assert_eq!(err.error(), ParseFromDescription::InvalidComponent("minute"));
+    assert_eq!(err.index(), 3);
+    assert_eq!(err.found().as_bytes(), b"3x");
+    assert!(!err.found().is_truncated());
+    assert!(matches!(err.expected(), Expected::Component(Component::Minute(_))));
+
+    let err = match Date::parse(
+        "2021-06-01 and some more text",
+        &Located::new(format_description!("[year]-[month]-[day]T")),
+    ) {
+        Err(Parse::InvalidInput(err)) => err,
+        _ => unreachable!(),
+    };
+    assert!(matches!(err.error(), ParseFromDescription::InvalidLiteral { .. }));
+    assert_eq!(err.index(), 10);
+    assert!(matches!(err.expected(), Expected::Literal(literal) if literal.as_bytes() == b"T"));
+    assert_eq!(err.found().as_bytes(), b" and some more t");
+    assert!(err.found().is_truncated());
+    assert_eq!(
+        err.to_string(),
+        "a character literal was not valid at byte index 10: expected literal \"T\", found \" and \
+         some more t\"..."
+    );
+
+    let err = match Time::parse("12:", &Located::new(format_description!("[hour]:[minute]"))) {
+        Err(Parse::InvalidInput(err)) => err,
+        _ => unreachable!(),
+    };
+    assert!(err.found().is_empty());
+    assert!(err.to_string().ends_with("found end of input"));
+
+    // A character split by the end of the snippet is escaped.
+    let err = match Date::parse(
+        "xxxxxxxxxxxxxxxé",
+        &Located::new(format_description!("[year]")),
+    ) {
+        Err(Parse::InvalidInput(err)) => err,
+        _ => unreachable!(),
+    };
+    assert_eq!(err.found().to_string(), r#""xxxxxxxxxxxxxxx\xc3"..."#);
+}
+
+#[test]
+fn invalid_input_render() -> time::Result<()> {
+    let input = "12:3x";
+    assert_eq!(
+        invalid_input().render(input).to_string(),
+        "12:3x\n   ^ expected component [minute], found \"3x\""
+    );
+
+    // Only the line containing the error is shown, with control characters replaced.
+    let description = Located::new(format_description!("[hour]:[minute]\n[hour]é\t[minute]"));
+    let input = "12:34\n05é\tx";
+    let err = match Time::parse(input, &description) {
+        Err(Parse::InvalidInput(err)) => err,
+        _ => unreachable!(),
+    };
+    assert_eq!(err.index(), 11);
+    assert_eq!(
+        err.render(input).to_string(),
+        "05é x\n    ^ expected component [minute], found \"x\""
+    );
+
+    // Long inputs are truncated on either side of the error.
+    let input = format!("{}2021-13-01{}", "x".repeat(100), "y".repeat(100));
+    let description = format!("{}[year]-[month]", "x".repeat(100));
+    let description = Located::new(format_description::parse_borrowed::<1>(&description)?);
+    let err = match Date::parse(&input, &description) {
+        Err(Parse::InvalidInput(err)) => err,
+        _ => unreachable!(),
+    };
+    assert_eq!(err.index(), 105);
+    let rendered = err.render(&input).to_string();
+    let (line, caret) = rendered.split_once('\n').unwrap_or_default();
+    assert_eq!(
+        line,
+        format!("...{}2021-13-01{}...", "x".repeat(35), "y".repeat(35))
+    );
+    assert_eq!(caret.find('^'), Some(43));
+
+    Ok(())
 }
@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-use time::format_description::{modifier, Component, FormatItem};
+use time::format_description::{self as fd, modifier, Component, FormatItem, OwnedFormatItem};
 
 #[test]
 fn format_item_component_conversions() {
@@ -32,3 +32,207 @@ fn format_item_equality() {
     assert_eq!(compound, compound_item);
     assert_eq!(compound_item, compound);
 }
+
+#[test]
+fn owned_format_item_from_format_item() {
+    let component = Component::Year(modifier::Year::default());
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::Literal(b"foo")),
+        OwnedFormatItem::Literal(Box::new(*b"foo"))
+    );
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::Component(component)),
+        OwnedFormatItem::Component(component)
+    );
+    assert_eq!(
+        OwnedFormatItem::from(&FormatItem::Compound(&[FormatItem::Component(component)])),
+        OwnedFormatItem::Compound(Box::new([OwnedFormatItem::Component(component)]))
+    );
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::Optional(&FormatItem::Component(component))),
+        OwnedFormatItem::Optional(Box::new(OwnedFormatItem::Component(component)))
+    );
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::First(&[
+            FormatItem::Literal(b""),
+            FormatItem::Component(component),
+        ])),
+        OwnedFormatItem::First(Box::new([
+            OwnedFormatItem::Literal(Box::new([])),
+            OwnedFormatItem::Component(component),
+        ]))
+    );
+    assert_eq!(
+        OwnedFormatItem::from(vec![FormatItem::Literal(b"a"), FormatItem::Literal(b"b")]),
+        OwnedFormatItem::Compound(Box::new([
+            OwnedFormatItem::Literal(Box::new(*b"a")),
+            OwnedFormatItem::Literal(Box::new(*b"b")),
+        ]))
+    );
+}
+
+#[test]
+fn owned_format_item_component_conversions() {
+    let component = Component::Year(modifier::Year::default());
+    let item = OwnedFormatItem::from(component);
+    assert!(matches!(item, OwnedFormatItem::Component(inner) if inner == component));
+    assert_eq!(Component::try_from(item), Ok(component));
+    assert!(Component::try_from(OwnedFormatItem::Literal(Box::new([]))).is_err());
+    assert!(<Vec<OwnedFormatItem>>::try_from(OwnedFormatItem::Literal(Box::new([]))).is_err());
+}
+
+#[test]
+fn owned_format_item_compound_conversions() {
+    let compound = vec![OwnedFormatItem::Literal(Box::new([]))];
+    let item = OwnedFormatItem::from(compound.clone());
+    assert!(matches!(&item, OwnedFormatItem::Compound(inner) if **inner == *compound));
+    assert_eq!(<Vec<OwnedFormatItem>>::try_from(item), Ok(compound));
+}
+
+#[test]
+fn owned_format_item_equality() {
+    let component = Component::Year(modifier::Year::default());
+    let compound = &[OwnedFormatItem::Literal(Box::new([]))][..];
+    let component_item = OwnedFormatItem::from(component);
+    let compound_item = OwnedFormatItem::from(compound.to_vec());
+
+    assert_eq!(component, component_item);
+    assert_eq!(component_item, component);
+    assert_eq!(compound, compound_item);
+    assert_eq!(compound_item, compound);
+}
+
+#[test]
+fn component_display() -> time::Result<()> {
+    // Every modifier that differs from its default is included.
+    for (description, expected) in [
+        ("[day]", "[day]"),
+        ("[day padding:zero]", "[day]"),
+        ("[day padding:space]", "[day padding:space]"),
+        ("[day suffix:none]", "[day]"),
+        (
+            "[day padding:none suffix:ordinal]",
+            "[day padding:none suffix:ordinal]",
+        ),
+        (
+            "[month repr:long case_sensitive:false]",
+            "[month repr:long case_sensitive:false]",
+        ),
+        (
+            "[month padding:none repr:numerical]",
+            "[month padding:none]",
+        ),
+        ("[month repr:short]", "[month repr:short]"),
+        ("[ordinal padding:none]", "[ordinal padding:none]"),
+        ("[weekday repr:long one_indexed:true]", "[weekday]"),
+        ("[weekday repr:short]", "[weekday repr:short]"),
+        (
+            "[weekday repr:sunday one_indexed:false]",
+            "[weekday repr:sunday one_indexed:false]",
+        ),
+        (
+            "[weekday repr:monday case_sensitive:false]",
+            "[weekday repr:monday case_sensitive:false]",
+        ),
+        ("[week_number repr:sunday]", "[week_number repr:sunday]"),
+        (
+            "[week_number padding:space repr:monday]",
+            "[week_number padding:space repr:monday]",
+        ),
+        ("[week_number repr:iso]", "[week_number]"),
+        ("[year repr:full base:calendar sign:automatic]", "[year]"),
+        (
+            "[year sign:mandatory base:iso_week repr:last_two]",
+            "[year repr:last_two base:iso_week sign:mandatory]",
+        ),
+        ("[hour repr:24]", "[hour]"),
+        ("[hour repr:12 padding:none]", "[hour padding:none repr:12]"),
+        ("[minute padding:space]", "[minute padding:space]"),
+        ("[period case:upper case_sensitive:true]", "[period]"),
+        (
+            "[period case:lower case_sensitive:false]",
+            "[period case:lower case_sensitive:false]",
+        ),
+        ("[second padding:none]", "[second padding:none]"),
+        ("[subsecond digits:1+]", "[subsecond]"),
+        ("[subsecond digits:1]", "[subsecond digits:1]"),
+        ("[subsecond digits:9]", "[subsecond digits:9]"),
+        ("[offset_hour sign:automatic]", "[offset_hour]"),
+        (
+            "[offset_hour sign:mandatory padding:space]",
+            "[offset_hour padding:space sign:mandatory]",
+        ),
+        (
+            "[offset_minute padding:none]",
+            "[offset_minute padding:none]",
+        ),
+        (
+            "[offset_second padding:space]",
+            "[offset_second padding:space]",
+        ),
+        (
+            "[unix_timestamp precision:second sign:automatic]",
+            "[unix_timestamp]",
+        ),
+        (
+            "[unix_timestamp sign:mandatory precision:nanosecond]",
+            "[unix_timestamp precision:nanosecond sign:mandatory]",
+        ),
+        (
+            "[unix_timestamp precision:millisecond]",
+            "[unix_timestamp precision:millisecond]",
+        ),
+        (
+            "[unix_timestamp precision:microsecond]",
+            "[unix_timestamp precision:microsecond]",
+        ),
+        ("[ignore  count:4]", "[ignore count:4]"),
+        ("[ignore until:,]", "[ignore until:,]"),
+        ("[ignore until:| count:4]", "[ignore count:4 until:|]"),
+        ("[end]", "[end]"),
+    ] {
+        assert_eq!(fd::parse(description)?[0].to_string(), expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn format_item_display() -> time::Result<()> {
+    assert_eq!(
+        FormatItem::Compound(&fd::parse("[year]-[month]-[day] [[literal]")?).to_string(),
+        r"[year]-[month]-[day] \[literal\]"
+    );
+    assert_eq!(FormatItem::Literal(br"\[]").to_string(), r"\\\[\]");
+    assert_eq!(FormatItem::Literal(b"\xff").to_string(), "\u{fffd}");
+    assert_eq!(
+        FormatItem::Optional(&FormatItem::Literal(b"x")).to_string(),
+        "[optional [x]]"
+    );
+    assert_eq!(FormatItem::First(&[]).to_string(), "[first]");
+    assert_eq!(
+        FormatItem::First(&[FormatItem::Literal(b"a"), FormatItem::Literal(b"b")]).to_string(),
+        "[first [a] [b]]"
+    );
+    Ok(())
+}
+
+#[test]
+fn owned_format_item_display() -> time::Result<()> {
+    for description in [
+        "",
+        "[year]-[month]-[day]",
+        r"\\ \[ \] [hour repr:12]",
+        "[optional []]",
+        "[optional [[optional [[second]]]]]",
+        "[first]",
+        "[first [[period]] [[period case:lower]] [x]]",
+    ] {
+        let parsed = fd::parse_owned::<2>(description)?;
+        assert_eq!(parsed.to_string(), description);
+    }
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::Literal(b"[")).to_string(),
+        r"\["
+    );
+    Ok(())
+}
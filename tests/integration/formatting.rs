@@ -1,9 +1,15 @@
 use std::io::{self, ErrorKind};
-
-use time::format_description::well_known::{Rfc2822, Rfc3339};
-use time::format_description::{self, FormatItem};
+use std::num::NonZeroU8;
+
+use time::format_description::well_known::iso8601::{
+    Config, DateKind, EncodedConfig, FormattedComponents, OffsetPrecision, TimePrecision,
+};
+use time::format_description::well_known::{
+    GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424, Rfc9557, UtcTime,
+};
+use time::format_description::{self, FormatItem, OwnedFormatItem};
 use time::macros::{date, datetime, format_description as fd, offset, time};
-use time::{OffsetDateTime, Time};
+use time::{Date, Month, OffsetDateTime, Time};
 
 #[test]
 fn rfc_2822() -> time::Result<()> {
@@ -32,6 +38,36 @@ fn rfc_2822() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        datetime!(1994-11-06 08:49:37 UTC).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(1994-11-06 03:49:37.5 -05:00).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(0000-01-01 01:00 +01:00).format(&HttpDate)?,
+        "Sat, 01 Jan 0000 00:00:00 GMT"
+    );
+
+    assert!(matches!(
+        datetime!(9999-12-31 23:00 -01:00).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(0000-01-01 0:00 +01:00).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(1994-11-06 08:49:37).format(&HttpDate),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    Ok(())
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -42,6 +78,12 @@ fn rfc_3339() -> time::Result<()> {
         datetime!(2021-01-02 03:04:05.1 UTC).format(&Rfc3339)?,
         "2021-01-02T03:04:05.1Z"
     );
+    assert_eq!(
+        datetime!(2016-12-31 0:00 UTC)
+            .replace_time(Time::from_leap_second(23, 59, 0)?)
+            .format(&Rfc3339)?,
+        "2016-12-31T23:59:60Z"
+    );
     assert_eq!(
         datetime!(2021-01-02 03:04:05.12 UTC).format(&Rfc3339)?,
         "2021-01-02T03:04:05.12Z"
@@ -95,6 +137,244 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_3164() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2003-10-11 22:14:15).format(&Rfc3164)?,
+        "Oct 11 22:14:15"
+    );
+    assert_eq!(
+        datetime!(2003-10-01 02:04:05.5 -7).format(&Rfc3164)?,
+        "Oct  1 02:04:05"
+    );
+    assert!(matches!(
+        time!(22:14:15).format(&Rfc3164),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn rfc_5424() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2003-10-11 22:14:15.003 -7).format(&Rfc5424)?,
+        "2003-10-11T22:14:15.003-07:00"
+    );
+    assert_eq!(
+        datetime!(2003-10-11 22:14:15.000_000_9 UTC).format(&Rfc5424)?,
+        "2003-10-11T22:14:15Z"
+    );
+    assert_eq!(
+        datetime!(2003-10-11 22:14:15.123_456_789 UTC).format(&Rfc5424)?,
+        "2003-10-11T22:14:15.123456Z"
+    );
+    Ok(())
+}
+
+#[test]
+fn rfc_9557() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2022-07-08 0:14:07 +1).format(&Rfc9557)?,
+        "2022-07-08T00:14:07+01:00"
+    );
+    assert_eq!(
+        datetime!(2022-07-08 0:14:07.5 UTC).format(&Rfc9557)?,
+        "2022-07-08T00:14:07.5Z"
+    );
+    assert!(matches!(
+        datetime!(-1-01-01 0:00 UTC).format(&Rfc9557),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    Ok(())
+}
+
+#[test]
+fn utc_time() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2049-12-31 23:59:59.999 UTC).format(&UtcTime::DER)?,
+        "491231235959Z"
+    );
+    assert_eq!(
+        datetime!(1949-12-31 23:00 -1).format(&UtcTime::BER)?,
+        "500101000000Z"
+    );
+    assert!(matches!(
+        datetime!(1949-12-31 23:59:59 UTC).format(&UtcTime::DER),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2049-12-31 23:00 -1).format(&UtcTime::DER),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2049-12-31 23:00).format(&UtcTime::DER),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn generalized_time() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2049-12-31 23:59:59 UTC).format(&GeneralizedTime::DER)?,
+        "20491231235959Z"
+    );
+    assert_eq!(
+        datetime!(2049-12-31 23:59:59.1 UTC).format(&GeneralizedTime::DER)?,
+        "20491231235959.1Z"
+    );
+    assert_eq!(
+        datetime!(2049-12-31 23:59:59.000_000_001 UTC).format(&GeneralizedTime::DER)?,
+        "20491231235959.000000001Z"
+    );
+    assert_eq!(
+        datetime!(2050-01-01 0:00 +1).format(&GeneralizedTime::BER)?,
+        "20491231230000Z"
+    );
+    assert!(matches!(
+        datetime!(9999-12-31 23:00 -1).format(&GeneralizedTime::DER),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(-1-01-01 0:00 UTC).format(&GeneralizedTime::DER),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    Ok(())
+}
+
+#[test]
+fn iso_8601() -> time::Result<()> {
+    const BASIC: EncodedConfig = Config::DEFAULT.set_use_separators(false).encode();
+    const WEEK: EncodedConfig = Config::DEFAULT.set_date_kind(DateKind::Week).encode();
+    const ORDINAL_BASIC: EncodedConfig = Config::DEFAULT
+        .set_use_separators(false)
+        .set_date_kind(DateKind::Ordinal)
+        .encode();
+    const SIX_DIGIT_YEAR: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::Date)
+        .set_year_is_six_digits(true)
+        .encode();
+    const HOUR: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::TimeOffset)
+        .set_time_precision(TimePrecision::Hour {
+            decimal_digits: NonZeroU8::new(2),
+        })
+        .set_offset_precision(OffsetPrecision::Hour)
+        .encode();
+    const MINUTE: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::Time)
+        .set_time_precision(TimePrecision::Minute {
+            decimal_digits: None,
+        })
+        .encode();
+    const MINUTE_FRACTION: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::Time)
+        .set_time_precision(TimePrecision::Minute {
+            decimal_digits: NonZeroU8::new(3),
+        })
+        .encode();
+    const SECOND: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::DateTime)
+        .set_time_precision(TimePrecision::Second {
+            decimal_digits: None,
+        })
+        .encode();
+    const OFFSET: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::Offset)
+        .encode();
+
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.123_456_789 UTC).format(&Iso8601::DEFAULT)?,
+        "2021-01-02T03:04:05.123456789Z"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 -01:02).format(&Iso8601::DEFAULT)?,
+        "2021-01-02T03:04:05.000000000-01:02"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.1 +01:02).format(&Iso8601::<BASIC>)?,
+        "20210102T030405.100000000+0102"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Iso8601::<WEEK>)?,
+        "2020-W53-6T03:04:05.000000000Z"
+    );
+    assert_eq!(
+        datetime!(2021-02-03 03:04:05 UTC).format(&Iso8601::<ORDINAL_BASIC>)?,
+        "2021034T030405.000000000Z"
+    );
+    assert_eq!(date!(2021-01-02).format(&Iso8601::DATE)?, "2021-01-02");
+    assert_eq!(
+        date!(2021-01-02).format(&Iso8601::<SIX_DIGIT_YEAR>)?,
+        "+002021-01-02"
+    );
+    assert_eq!(
+        date!(-2021-01-02).format(&Iso8601::<SIX_DIGIT_YEAR>)?,
+        "-002021-01-02"
+    );
+    assert_eq!(
+        time!(03:04:05.6).format(&Iso8601::TIME)?,
+        "03:04:05.600000000"
+    );
+    assert_eq!(time!(03:04:05.6).format(&Iso8601::<MINUTE>)?, "03:04");
+    assert_eq!(
+        time!(03:04:05.6).format(&Iso8601::<MINUTE_FRACTION>)?,
+        "03:04.093"
+    );
+    assert_eq!(
+        Time::from_leap_second(23, 59, 500_000_000)?.format(&Iso8601::<MINUTE_FRACTION>)?,
+        "23:59.999"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:45 +01:00).format(&Iso8601::<HOUR>)?,
+        "03.75+01"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.6).format(&Iso8601::<SECOND>)?,
+        "2021-01-02T03:04:05"
+    );
+    assert_eq!(
+        datetime!(2016-12-31 0:00)
+            .replace_time(Time::from_leap_second(23, 59, 0)?)
+            .format(&Iso8601::<SECOND>)?,
+        "2016-12-31T23:59:60"
+    );
+    assert_eq!(offset!(-00:30).format(&Iso8601::<OFFSET>)?, "-00:30");
+
+    assert!(matches!(
+        date!(+10_000-01-01).format(&Iso8601::DATE),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2021-01-02 03:04 +01:30).format(&Iso8601::<HOUR>),
+        Err(time::error::Format::InvalidComponent("offset_minute"))
+    ));
+    assert!(matches!(
+        datetime!(2021-01-02 03:04 +00:00:01).format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+    assert!(matches!(
+        date!(2021-01-02).format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        datetime!(2021-01-02 03:04).format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn iso_8601_too_many_decimal_digits() {
+    let _ = Config::DEFAULT
+        .set_time_precision(TimePrecision::Second {
+            decimal_digits: NonZeroU8::new(10),
+        })
+        .encode();
+}
+
 #[test]
 fn format_time() -> time::Result<()> {
     let format_output = [
@@ -357,6 +637,98 @@ fn format_odt() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn format_unix_timestamp() -> time::Result<()> {
+    let value = datetime!(2021-10-16 16:00:00.123_456_789 +1);
+    assert_eq!(value.format(fd!("[unix_timestamp]"))?, "1634396400");
+    assert_eq!(
+        value.format(fd!("[unix_timestamp precision:millisecond]"))?,
+        "1634396400123"
+    );
+    assert_eq!(
+        value.format(fd!("[unix_timestamp precision:microsecond]"))?,
+        "1634396400123456"
+    );
+    assert_eq!(
+        value.format(fd!("[unix_timestamp precision:nanosecond]"))?,
+        "1634396400123456789"
+    );
+    assert_eq!(
+        value.format(fd!("[[[unix_timestamp sign:mandatory].[subsecond digits:3]]"))?,
+        "[+1634396400.123]"
+    );
+    assert_eq!(
+        OffsetDateTime::UNIX_EPOCH.format(fd!("[unix_timestamp sign:mandatory]"))?,
+        "+0"
+    );
+
+    // Values are rounded towards negative infinity.
+    let value = datetime!(1969-12-31 23:59:59.75 UTC);
+    assert_eq!(value.format(fd!("[unix_timestamp]"))?, "-1");
+    assert_eq!(
+        value.format(fd!("[unix_timestamp sign:mandatory].[subsecond]"))?,
+        "-1.75"
+    );
+    assert_eq!(
+        value.format(fd!("[unix_timestamp precision:millisecond]"))?,
+        "-250"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_ignore_end() -> time::Result<()> {
+    assert!(matches!(
+        OffsetDateTime::UNIX_EPOCH.format(fd!("[year][ignore count:2]")),
+        Err(time::error::Format::InvalidComponent("ignore"))
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.format(&format_description::parse("[hour] [ignore count:1]")?),
+        Err(time::error::Format::InvalidComponent("ignore"))
+    ));
+    assert_eq!(Time::MIDNIGHT.format(fd!("[hour][end]"))?, "00");
+    assert_eq!(date!(2021 - 001).format(fd!("[end]"))?, "");
+
+    Ok(())
+}
+
+#[test]
+fn format_day_ordinal_suffix() -> time::Result<()> {
+    for (day, expected) in [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (22, "22nd"),
+        (23, "23rd"),
+        (30, "30th"),
+        (31, "31st"),
+    ] {
+        assert_eq!(
+            Date::from_calendar_date(2021, Month::January, day)?
+                .format(fd!("[day padding:none suffix:ordinal]"))?,
+            expected
+        );
+    }
+    assert_eq!(
+        date!(2021 - 06 - 01).format(fd!(
+            "[weekday], [month repr:long] [day padding:none suffix:ordinal], [year]"
+        ))?,
+        "Tuesday, June 1st, 2021"
+    );
+    assert_eq!(
+        date!(2021 - 06 - 02).format(&format_description::parse("[day suffix:ordinal]")?)?,
+        "02nd"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn display_odt() {
     assert_eq!(
@@ -383,6 +755,9 @@ fn insufficient_type_information() {
     assert_insufficient_type_information(
         Time::MIDNIGHT.format(&FormatItem::First(&[FormatItem::Compound(fd!("[year]"))])),
     );
+    assert_insufficient_type_information(
+        datetime!(2021 - 001 0:00).format(fd!("[unix_timestamp]")),
+    );
 }
 
 #[test]
@@ -448,6 +823,10 @@ fn failed_write() -> time::Result<()> {
     assert_err(offset!(+1).format_into(bytes!(0), fd!("[offset_hour sign:mandatory]")));
     assert_err(offset!(-1).format_into(bytes!(0), fd!("[offset_hour]")));
     assert_err(offset!(-1).format_into(bytes!(1), fd!("[offset_hour]")));
+    assert_err(
+        OffsetDateTime::UNIX_EPOCH.format_into(bytes!(0), fd!("[unix_timestamp sign:mandatory]")),
+    );
+    assert_err(datetime!(1969-12-31 0:00 UTC).format_into(bytes!(1), fd!("[unix_timestamp]")));
     assert_err(date!(-1 - 001).format_into(bytes!(0), fd!("[year]")));
     assert_err(date!(2021 - 001).format_into(bytes!(0), fd!("[year sign:mandatory]")));
     assert_err(date!(+999_999 - 001).format_into(bytes!(4), fd!("[year]")));
@@ -468,6 +847,7 @@ fn failed_write() -> time::Result<()> {
         "offset_hour",
         "offset_minute",
         "offset_second",
+        "unix_timestamp",
     ];
     for component in &component_names {
         assert_err(OffsetDateTime::UNIX_EPOCH.format_into(
@@ -489,3 +869,43 @@ fn first() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn owned() -> time::Result<()> {
+    let description =
+        format_description::parse_owned::<1>("[year]-[month]-[day] [hour]:[minute]")?;
+    assert_eq!(
+        datetime!(2021-06-01 13:05).format(&description)?,
+        "2021-06-01 13:05"
+    );
+    assert_eq!(
+        datetime!(2021-06-01 13:05).format(&OwnedFormatItem::First(Box::new([])))?,
+        ""
+    );
+    assert_eq!(
+        Time::MIDNIGHT.format(&OwnedFormatItem::First(Box::new([
+            format_description::parse_owned::<1>("[hour]")?,
+            format_description::parse_owned::<1>("[year]")?,
+        ])))?,
+        "00"
+    );
+    assert_eq!(
+        Time::MIDNIGHT.format(&OwnedFormatItem::Optional(Box::new(
+            format_description::parse_owned::<1>("[hour]:[minute]")?
+        )))?,
+        "00:00"
+    );
+    assert_eq!(
+        Time::MIDNIGHT.format(&vec![
+            OwnedFormatItem::from(fd!("[hour]")),
+            OwnedFormatItem::Literal(Box::new(*b"h")),
+        ])?,
+        "00h"
+    );
+    assert!(matches!(
+        Time::MIDNIGHT.format(&format_description::parse_owned::<1>("[year]")?),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use time::error::{Parse, ParseFromDescription};
+use time::ext::NumericalDuration;
+use time::interval::{Interval, RepeatingInterval};
+use time::macros::datetime;
+
+#[test]
+fn bounds() {
+    let start_end = Interval::StartEnd {
+        start: datetime!(2021-01-01 0:00 UTC),
+        end: datetime!(2021-01-02 12:00 UTC),
+    };
+    assert_eq!(start_end.start(), Some(datetime!(2021-01-01 0:00 UTC)));
+    assert_eq!(start_end.end(), Some(datetime!(2021-01-02 12:00 UTC)));
+    assert_eq!(start_end.duration(), 36.hours());
+
+    let start_duration = Interval::StartDuration {
+        start: datetime!(2021-01-01 0:00 UTC),
+        duration: 1.days(),
+    };
+    assert_eq!(start_duration.start(), Some(datetime!(2021-01-01 0:00 UTC)));
+    assert_eq!(start_duration.end(), Some(datetime!(2021-01-02 0:00 UTC)));
+    assert_eq!(start_duration.duration(), 1.days());
+
+    let duration_end = Interval::DurationEnd {
+        duration: 1.days(),
+        end: datetime!(2021-01-02 0:00 UTC),
+    };
+    assert_eq!(duration_end.start(), Some(datetime!(2021-01-01 0:00 UTC)));
+    assert_eq!(duration_end.end(), Some(datetime!(2021-01-02 0:00 UTC)));
+    assert_eq!(duration_end.duration(), 1.days());
+
+    let duration = Interval::Duration(1.days());
+    assert_eq!(duration.start(), None);
+    assert_eq!(duration.end(), None);
+    assert_eq!(duration.duration(), 1.days());
+
+    let negative = Interval::StartDuration {
+        start: datetime!(2021-01-02 0:00 UTC),
+        duration: (-1).days(),
+    };
+    assert_eq!(negative.end(), Some(datetime!(2021-01-01 0:00 UTC)));
+    assert_eq!(negative.duration(), (-1).days());
+}
+
+#[test]
+fn format() -> time::Result<()> {
+    assert_eq!(
+        Interval::StartEnd {
+            start: datetime!(2021-01-01 0:00 UTC),
+            end: datetime!(2021-01-02 12:00 +1),
+        }
+        .format()?,
+        "2021-01-01T00:00:00Z/2021-01-02T12:00:00+01:00"
+    );
+    assert_eq!(
+        Interval::StartDuration {
+            start: datetime!(2021-01-01 0:00 UTC),
+            duration: 90.minutes(),
+        }
+        .format()?,
+        "2021-01-01T00:00:00Z/PT1H30M"
+    );
+    assert_eq!(
+        Interval::DurationEnd {
+            duration: 2.weeks(),
+            end: datetime!(2021-01-01 0:00 UTC),
+        }
+        .format()?,
+        "P2W/2021-01-01T00:00:00Z"
+    );
+    assert_eq!(Interval::Duration(1.days()).format()?, "P1D");
+
+    assert_eq!(
+        RepeatingInterval::new(
+            Some(5),
+            Interval::StartDuration {
+                start: datetime!(2021-01-01 0:00 UTC),
+                duration: 1.hours(),
+            }
+        )
+        .format()?,
+        "R5/2021-01-01T00:00:00Z/PT1H"
+    );
+    assert_eq!(
+        RepeatingInterval::new(None, Interval::Duration(1.days())).format()?,
+        "R/P1D"
+    );
+
+    assert!(matches!(
+        Interval::StartDuration {
+            start: datetime!(-1-01-01 0:00 UTC),
+            duration: 1.days(),
+        }
+        .format(),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse() -> time::Result<()> {
+    assert_eq!(
+        Interval::parse("2021-01-01T00:00Z/2021-01-02T12:00+01:00")?,
+        Interval::StartEnd {
+            start: datetime!(2021-01-01 0:00 UTC),
+            end: datetime!(2021-01-02 12:00 +1),
+        }
+    );
+    assert_eq!(
+        Interval::parse("2021-01-01T00:00Z/PT1H30M")?,
+        Interval::StartDuration {
+            start: datetime!(2021-01-01 0:00 UTC),
+            duration: 90.minutes(),
+        }
+    );
+    assert_eq!(
+        Interval::parse("P2W/2021-01-01T00:00Z")?,
+        Interval::DurationEnd {
+            duration: 2.weeks(),
+            end: datetime!(2021-01-01 0:00 UTC),
+        }
+    );
+    assert_eq!(Interval::parse("P1D")?, Interval::Duration(1.days()));
+    assert_eq!(
+        Interval::parse("-010000-01-01T00:00Z/P1D")?,
+        Interval::StartDuration {
+            start: datetime!(-10_000-01-01 0:00 UTC),
+            duration: 1.days(),
+        }
+    );
+
+    assert_eq!(
+        RepeatingInterval::parse("R5/2021-01-01T00:00Z/PT1H")?,
+        RepeatingInterval::new(
+            Some(5),
+            Interval::StartDuration {
+                start: datetime!(2021-01-01 0:00 UTC),
+                duration: 1.hours(),
+            }
+        )
+    );
+    assert_eq!(
+        RepeatingInterval::parse("R/P1D")?,
+        RepeatingInterval::new(None, Interval::Duration(1.days()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_err() {
+    assert!(matches!(
+        Interval::parse("P1D/P1D"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidComponent("end")
+        ))
+    ));
+    assert!(matches!(
+        Interval::parse("2021-01-02T00:00Z/2021-01-01T00:00Z"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidComponent("end")
+        ))
+    ));
+    assert!(matches!(
+        Interval::parse("2021-01-01T00:00Z/-P1D"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(matches!(
+        Interval::parse("-PT1H"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+    assert!(Interval::parse("2021-01-01/P1D").is_err());
+    assert!(matches!(
+        RepeatingInterval::parse("Rx/P1D"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidComponent("repetitions")
+        ))
+    ));
+    assert!(matches!(
+        RepeatingInterval::parse("R99999999999/P1D"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidComponent("repetitions")
+        ))
+    ));
+    assert!(matches!(
+        RepeatingInterval::parse("5/P1D"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidLiteral { .. }
+        ))
+    ));
+    assert!(matches!(
+        RepeatingInterval::parse("R5"),
+        Err(Parse::ParseFromDescription(
+            ParseFromDescription::InvalidLiteral { .. }
+        ))
+    ));
+}
+
+#[test]
+fn repetitions() {
+    let forward = RepeatingInterval::new(
+        Some(3),
+        Interval::StartDuration {
+            start: datetime!(2021-01-01 0:00 UTC),
+            duration: 1.hours(),
+        },
+    );
+    assert_eq!(
+        forward.iter().collect::<Vec<_>>(),
+        [
+            datetime!(2021-01-01 0:00 UTC)..datetime!(2021-01-01 1:00 UTC),
+            datetime!(2021-01-01 1:00 UTC)..datetime!(2021-01-01 2:00 UTC),
+            datetime!(2021-01-01 2:00 UTC)..datetime!(2021-01-01 3:00 UTC),
+        ]
+    );
+
+    let backward = RepeatingInterval::new(
+        Some(2),
+        Interval::DurationEnd {
+            duration: 1.days(),
+            end: datetime!(2021-01-03 0:00 UTC),
+        },
+    );
+    assert_eq!(
+        backward.into_iter().collect::<Vec<_>>(),
+        [
+            datetime!(2021-01-02 0:00 UTC)..datetime!(2021-01-03 0:00 UTC),
+            datetime!(2021-01-01 0:00 UTC)..datetime!(2021-01-02 0:00 UTC),
+        ]
+    );
+
+    let unbounded = RepeatingInterval::new(
+        None,
+        Interval::StartEnd {
+            start: datetime!(2021-01-01 0:00 UTC),
+            end: datetime!(2021-01-08 0:00 UTC),
+        },
+    );
+    assert_eq!(
+        unbounded.iter().nth(52),
+        Some(datetime!(2021-12-31 0:00 UTC)..datetime!(2022-01-07 0:00 UTC))
+    );
+
+    let duration_only = RepeatingInterval::new(Some(5), Interval::Duration(1.days()));
+    assert_eq!(duration_only.iter().next(), None);
+
+    let overflowing = RepeatingInterval::new(
+        None,
+        Interval::StartDuration {
+            start: datetime!(+999_999-12-30 0:00 UTC),
+            duration: 1.days(),
+        },
+    );
+    let mut iter = overflowing.iter();
+    assert!(iter.next().is_some());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
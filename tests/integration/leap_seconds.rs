@@ -0,0 +1,78 @@
+use time::leap_seconds::{LeapSecond, LeapSecondList, LeapSecondTable};
+use time::macros::datetime;
+use time::{OffsetDateTime, Time};
+
+fn leap_second(datetime: OffsetDateTime) -> time::Result<OffsetDateTime> {
+    Ok(datetime.replace_time(Time::from_leap_second(23, 59, 0)?))
+}
+
+#[test]
+fn tai_minus_utc() -> time::Result<()> {
+    let table = LeapSecondTable::BUILTIN;
+    assert_eq!(table.tai_minus_utc(datetime!(1970-01-01 0:00 UTC)), 10);
+    assert_eq!(table.tai_minus_utc(datetime!(1972-06-30 23:59:59 UTC)), 10);
+    assert_eq!(table.tai_minus_utc(datetime!(1972-07-01 0:00 UTC)), 11);
+    assert_eq!(
+        table.tai_minus_utc(leap_second(datetime!(2016-12-31 0:00 UTC))?),
+        36
+    );
+    assert_eq!(table.tai_minus_utc(datetime!(2017-01-01 0:00 UTC)), 37);
+    assert_eq!(table.tai_minus_utc(datetime!(2017-01-01 0:59:59 +1)), 36);
+    assert_eq!(table.tai_minus_utc(datetime!(2030-01-01 0:00 UTC)), 37);
+    Ok(())
+}
+
+#[test]
+fn is_leap_second() -> time::Result<()> {
+    let table = LeapSecondTable::BUILTIN;
+    assert!(table.is_leap_second(leap_second(datetime!(1972-06-30 0:00 UTC))?));
+    assert!(table.is_leap_second(leap_second(datetime!(2016-12-31 0:00 UTC))?));
+    assert!(!table.is_leap_second(leap_second(datetime!(2017-06-30 0:00 UTC))?));
+    assert!(!table.is_leap_second(leap_second(datetime!(1971-12-31 0:00 UTC))?));
+    assert!(!table.is_leap_second(datetime!(2016-12-31 23:59:59 UTC)));
+    Ok(())
+}
+
+#[test]
+fn table_new() {
+    assert!(LeapSecondTable::new(&[]).is_some());
+    assert!(LeapSecondTable::new(&[LeapSecond::new(0, 10), LeapSecond::new(0, 11)]).is_none());
+    assert_eq!(LeapSecondTable::BUILTIN.entries().len(), 28);
+    assert_eq!(LeapSecondTable::BUILTIN.expires(), None);
+}
+
+#[test]
+fn list_parse() -> time::Result<()> {
+    let list = LeapSecondList::parse(
+        "# comment\n\
+         #$\t 3913697179\n\
+         #@\t3960057600\n\
+         \n\
+         2272060800\t10\t# 1 Jan 1972\n\
+         2287785600\t11\t# 1 Jul 1972\n\
+         #h\t16edd0f0 3666784f 37db6bdd e7a6a18d 6ad9ed9c\n",
+    )?;
+    let table = list.table();
+    assert_eq!(
+        table.entries(),
+        [
+            LeapSecond::new(63_072_000, 10),
+            LeapSecond::new(78_796_800, 11)
+        ]
+    );
+    assert_eq!(table.expires(), Some(datetime!(2025-06-28 0:00 UTC)));
+    assert_eq!(table.tai_minus_utc(datetime!(1990-01-01 0:00 UTC)), 11);
+
+    assert_eq!(LeapSecondList::parse("1 2 3").map_err(|e| e.line()), Err(1));
+    assert_eq!(
+        LeapSecondList::parse("\nx 10").map_err(|e| e.line()),
+        Err(2)
+    );
+    assert_eq!(LeapSecondList::parse("#@ x").map_err(|e| e.line()), Err(1));
+    assert_eq!(
+        LeapSecondList::parse("100 10\n50 11").map_err(|e| e.line()),
+        Err(2)
+    );
+    assert_eq!(LeapSecondList::parse("100").map_err(|e| e.line()), Err(1));
+    Ok(())
+}
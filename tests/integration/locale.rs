@@ -0,0 +1,562 @@
+use time::format_description::well_known::Rfc2822;
+use time::format_description::{self, FormatItem};
+use time::locale::{
+    English, FormatStyle, French, German, Locale, Localized, Portuguese, Spanish, Styled,
+};
+use time::macros::{date, datetime, format_description as fd, time};
+use time::parsing::{Lenient, Located};
+use time::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, Weekday};
+
+/// A locale whose names are prefixes of one another, to ensure the longest name is parsed.
+struct Prefixed;
+
+impl Locale for Prefixed {
+    fn month_name(&self, month: Month) -> &str {
+        match month {
+            Month::January => "m",
+            Month::February => "mm",
+            _ => "mmm",
+        }
+    }
+
+    fn month_abbreviation(&self, month: Month) -> &str {
+        self.month_name(month)
+    }
+
+    fn weekday_name(&self, weekday: Weekday) -> &str {
+        match weekday {
+            Weekday::Monday => "w",
+            _ => "ww",
+        }
+    }
+
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+        self.weekday_name(weekday)
+    }
+
+    fn period_name(&self, is_pm: bool, _: bool) -> &str {
+        if is_pm {
+            "p"
+        } else {
+            "pp"
+        }
+    }
+}
+
+#[test]
+fn format_names() -> time::Result<()> {
+    let description =
+        fd!("[weekday] [weekday repr:short] [month repr:long] [month repr:short] [period]");
+    let datetime = datetime!(2021-03-01 13:00);
+    assert_eq!(
+        datetime.format(&Localized::new(description, English))?,
+        "Monday Mon March Mar PM"
+    );
+    assert_eq!(
+        datetime.format(&Localized::new(description, French))?,
+        "lundi lun. mars mars PM"
+    );
+    assert_eq!(
+        datetime.format(&Localized::new(description, German))?,
+        "Montag Mo März Mär PM"
+    );
+    assert_eq!(
+        datetime.format(&Localized::new(description, Portuguese))?,
+        "segunda-feira seg. março mar. PM"
+    );
+    assert_eq!(
+        datetime.format(&Localized::new(description, Spanish))?,
+        "lunes lun marzo mar p. m."
+    );
+    assert_eq!(
+        datetime.format(&Localized::new(fd!("[period case:lower]"), English))?,
+        "pm"
+    );
+    assert_eq!(
+        datetime.format(&Localized::new(fd!("[period case:lower]"), Spanish))?,
+        "p. m."
+    );
+    Ok(())
+}
+
+#[test]
+fn format_ordinal_suffix() -> time::Result<()> {
+    let description = fd!("[day padding:none suffix:ordinal] [month repr:long]");
+    assert_eq!(
+        date!(2021 - 01 - 01).format(&Localized::new(description, French))?,
+        "1er janvier"
+    );
+    assert_eq!(
+        date!(2021 - 01 - 06).format(&Localized::new(description, French))?,
+        "6 janvier"
+    );
+    assert_eq!(
+        date!(2021 - 01 - 06).format(&Localized::new(description, German))?,
+        "6. Januar"
+    );
+    assert_eq!(
+        date!(2021 - 01 - 01).format(&Localized::new(description, Portuguese))?,
+        "1º janeiro"
+    );
+    assert_eq!(
+        date!(2021 - 01 - 06).format(&Localized::new(description, Spanish))?,
+        "6 enero"
+    );
+    // Locales that do not provide their own suffixes use the English ones.
+    assert_eq!(
+        date!(2021 - 01 - 22).format(&Localized::new(description, Prefixed))?,
+        "22nd m"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_english_by_default() -> time::Result<()> {
+    let description = fd!("[weekday] [month repr:long] [period]");
+    let datetime = datetime!(2021-08-14 9:00);
+    assert_eq!(
+        datetime.format(&Localized::new(description, English))?,
+        datetime.format(description)?
+    );
+    Ok(())
+}
+
+#[test]
+fn format_unaffected() -> time::Result<()> {
+    let description = Localized::new(fd!("[day].[month].[year] [hour]:[minute]"), German);
+    assert_eq!(
+        datetime!(2021-08-14 9:05).format(&description)?,
+        "14.08.2021 09:05"
+    );
+    assert_eq!(
+        datetime!(2021-08-14 9:05 UTC).format(&Localized::new(Rfc2822, German))?,
+        "Sat, 14 Aug 2021 09:05:00 +0000"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_owned() -> time::Result<()> {
+    let description =
+        format_description::parse_owned::<1>("[weekday repr:short] [month repr:long]")?;
+    assert_eq!(
+        date!(2021 - 12 - 25).format(&Localized::new(&description, French))?,
+        "sam. décembre"
+    );
+    assert_eq!(
+        date!(2021 - 12 - 25).format(&Localized::new(description, French))?,
+        "sam. décembre"
+    );
+    Ok(())
+}
+
+#[test]
+fn format_dyn_locale() -> time::Result<()> {
+    let locales: [&dyn Locale; 2] = [&English, &German];
+    let names = locales
+        .iter()
+        .map(|&locale| {
+            date!(2021 - 05 - 01).format(&Localized::new(fd!("[month repr:long]"), locale))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(names, ["May", "Mai"]);
+    Ok(())
+}
+
+#[test]
+fn parse_names() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "samedi 14 août 2021",
+            &Localized::new(fd!("[weekday] [day] [month repr:long] [year]"), French)
+        )?,
+        date!(2021 - 08 - 14)
+    );
+    assert_eq!(
+        Date::parse(
+            "sáb., 14 de ago. de 2021",
+            &Localized::new(
+                fd!("[weekday repr:short], [day] de [month repr:short] de [year]"),
+                Portuguese
+            )
+        )?,
+        date!(2021 - 08 - 14)
+    );
+    assert_eq!(
+        Time::parse(
+            "1:30 p. m.",
+            &Localized::new(
+                fd!("[hour repr:12 padding:none]:[minute] [period]"),
+                Spanish
+            )
+        )?,
+        time!(13:30)
+    );
+    assert_eq!(
+        Date::parse(
+            "01. MÄRZ 2021",
+            &Localized::new(
+                fd!("[day]. [month repr:long case_sensitive:false] [year]"),
+                German
+            )
+        )
+        .ok(),
+        None,
+        "non-ASCII letters are compared exactly"
+    );
+    assert_eq!(
+        Date::parse(
+            "01. MäRZ 2021",
+            &Localized::new(
+                fd!("[day]. [month repr:long case_sensitive:false] [year]"),
+                German
+            )
+        )?,
+        date!(2021 - 03 - 01)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_ordinal_suffix() -> time::Result<()> {
+    let description = fd!("[day padding:none suffix:ordinal] [month repr:long] [year]");
+    assert_eq!(
+        Date::parse("1er janvier 2021", &Localized::new(description, French))?,
+        date!(2021 - 01 - 01)
+    );
+    assert_eq!(
+        Date::parse("6 janvier 2021", &Localized::new(description, French))?,
+        date!(2021 - 01 - 06)
+    );
+    assert_eq!(
+        Date::parse("6. Januar 2021", &Localized::new(description, German))?,
+        date!(2021 - 01 - 06)
+    );
+    assert!(Date::parse("6th janvier 2021", &Localized::new(description, French)).is_err());
+    Ok(())
+}
+
+#[test]
+fn parse_longest_name() -> time::Result<()> {
+    let description = Localized::new(fd!("[year] [month repr:long] [day] [weekday]"), Prefixed);
+    assert_eq!(
+        Date::parse("2021 m 04 w", &description)?,
+        date!(2021 - 01 - 04)
+    );
+    assert_eq!(
+        Date::parse("2021 mm 02 ww", &description)?,
+        date!(2021 - 02 - 02)
+    );
+    assert_eq!(
+        Date::parse("2021 mmm 01 w", &description)?,
+        date!(2021 - 03 - 01)
+    );
+    assert_eq!(
+        Time::parse(
+            "3pp",
+            &Localized::new(fd!("[hour repr:12 padding:none][period]"), Prefixed)
+        )?,
+        time!(3:00)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_err() -> time::Result<()> {
+    assert!(matches!(
+        Date::parse(
+            "2021-March-01",
+            &Located::new(Localized::new(fd!("[year]-[month repr:long]-[day]"), German))
+        ),
+        Err(error::Parse::InvalidInput(err))
+            if err.index() == 5
+                && err.error() == error::ParseFromDescription::InvalidComponent("month")
+    ));
+    Ok(())
+}
+
+#[test]
+fn parse_lenient() -> time::Result<()> {
+    let description = fd!("[day]. [month repr:long] [year], [hour]:[minute] Uhr");
+    let expected = datetime!(2021-03-01 14:30);
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "01.  März 2021, 14:30 UHR",
+            &Lenient::new(Localized::new(description, German))
+        )?,
+        expected
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "01.  März 2021, 14:30 UHR",
+            &Localized::new(Lenient::new(description), German)
+        )?,
+        expected
+    );
+    Ok(())
+}
+
+#[test]
+fn nested() -> time::Result<()> {
+    // The innermost locale takes precedence.
+    let description = Localized::new(
+        Localized::new(fd!("[year] [month repr:long] [day]"), French),
+        German,
+    );
+    assert_eq!(date!(2021 - 03 - 01).format(&description)?, "2021 mars 01");
+    assert_eq!(
+        Date::parse("2021 mars 01", &description)?,
+        date!(2021 - 03 - 01)
+    );
+    assert_eq!(
+        Date::parse(
+            "2021 mars 01",
+            &Localized::new(
+                FormatItem::Compound(&[
+                    FormatItem::Compound(fd!("[year] ")),
+                    FormatItem::First(&[
+                        FormatItem::Compound(fd!("[month repr:long]")),
+                        FormatItem::Compound(fd!("[month repr:short]")),
+                    ]),
+                    FormatItem::Optional(&FormatItem::Compound(fd!(" [day]"))),
+                ]),
+                French
+            )
+        )?,
+        date!(2021 - 03 - 01)
+    );
+    Ok(())
+}
+
+#[test]
+fn accessors() {
+    let description = Localized::new(fd!("[month repr:long]"), French);
+    assert_eq!(description.locale(), &French);
+    assert_eq!(description.into_inner(), fd!("[month repr:long]"));
+}
+
+#[test]
+fn format_date_styles() -> time::Result<()> {
+    let date = date!(2021 - 01 - 06);
+    let cases: [(&dyn Locale, [&str; 4]); 5] = [
+        (
+            &English,
+            [
+                "1/6/21",
+                "Jan 6, 2021",
+                "January 6, 2021",
+                "Wednesday, January 6, 2021",
+            ],
+        ),
+        (
+            &French,
+            [
+                "06/01/2021",
+                "6 janv. 2021",
+                "6 janvier 2021",
+                "mercredi 6 janvier 2021",
+            ],
+        ),
+        (
+            &German,
+            [
+                "06.01.21",
+                "06.01.2021",
+                "6. Januar 2021",
+                "Mittwoch, 6. Januar 2021",
+            ],
+        ),
+        (
+            &Portuguese,
+            [
+                "06/01/2021",
+                "6 de jan. de 2021",
+                "6 de janeiro de 2021",
+                "quarta-feira, 6 de janeiro de 2021",
+            ],
+        ),
+        (
+            &Spanish,
+            [
+                "6/1/21",
+                "6 ene 2021",
+                "6 de enero de 2021",
+                "miércoles, 6 de enero de 2021",
+            ],
+        ),
+    ];
+    let styles = [
+        FormatStyle::Short,
+        FormatStyle::Medium,
+        FormatStyle::Long,
+        FormatStyle::Full,
+    ];
+    for (locale, expected) in cases.iter() {
+        for (&style, &expected) in styles.iter().zip(expected) {
+            let description = Styled::date(style, locale);
+            assert_eq!(date.format(&description)?, expected);
+            // A two-digit year is not sufficient to parse a date.
+            if style != FormatStyle::Short {
+                assert_eq!(Date::parse(expected, &description)?, date);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn format_time_styles() -> time::Result<()> {
+    let datetime = datetime!(2021-01-06 13:05:09 +1);
+    assert_eq!(
+        datetime.format(&Styled::time(FormatStyle::Short, English))?,
+        "1:05 PM"
+    );
+    assert_eq!(
+        datetime.format(&Styled::time(FormatStyle::Medium, English))?,
+        "1:05:09 PM"
+    );
+    assert_eq!(
+        datetime.format(&Styled::time(FormatStyle::Long, English))?,
+        "1:05:09 PM +01:00"
+    );
+    assert_eq!(
+        datetime.format(&Styled::time(FormatStyle::Short, German))?,
+        "13:05"
+    );
+    assert_eq!(
+        datetime.format(&Styled::time(FormatStyle::Full, French))?,
+        "13:05:09 +01:00"
+    );
+    assert_eq!(
+        datetime!(2021-01-06 9:05).format(&Styled::time(FormatStyle::Medium, Spanish))?,
+        "9:05:00"
+    );
+    assert_eq!(
+        Time::parse("1:05 PM", &Styled::time(FormatStyle::Short, English))?,
+        time!(13:05)
+    );
+    assert!(matches!(
+        datetime
+            .time()
+            .format(&Styled::time(FormatStyle::Long, English)),
+        Err(error::Format::InsufficientTypeInformation { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn format_date_time_styles() -> time::Result<()> {
+    let datetime = datetime!(2021-01-06 13:05:09 +1);
+    assert_eq!(
+        datetime.format(&Styled::date_time(
+            FormatStyle::Short,
+            FormatStyle::Short,
+            English
+        ))?,
+        "1/6/21, 1:05 PM"
+    );
+    assert_eq!(
+        datetime.format(&Styled::date_time(
+            FormatStyle::Long,
+            FormatStyle::Long,
+            English
+        ))?,
+        "January 6, 2021 at 1:05:09 PM +01:00"
+    );
+    assert_eq!(
+        datetime.format(&Styled::date_time(
+            FormatStyle::Full,
+            FormatStyle::Short,
+            German
+        ))?,
+        "Mittwoch, 6. Januar 2021 um 13:05"
+    );
+    assert_eq!(
+        datetime.format(&Styled::date_time(
+            FormatStyle::Short,
+            FormatStyle::Medium,
+            French
+        ))?,
+        "06/01/2021 13:05:09"
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "6 de enero de 2021, 13:05:09 +01:00",
+            &Styled::date_time(FormatStyle::Long, FormatStyle::Long, Spanish)
+        )?,
+        datetime
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "6 janv. 2021, 13:05",
+            &Styled::date_time(FormatStyle::Medium, FormatStyle::Short, French)
+        )?,
+        datetime!(2021-01-06 13:05)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_short_date_style() -> time::Result<()> {
+    // Only the last two digits of the year are present, so the century is not known.
+    assert!(matches!(
+        Date::parse("1/6/21", &Styled::date(FormatStyle::Short, English)),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("06.01.21", &Styled::date(FormatStyle::Short, German)),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+    assert_eq!(
+        Date::parse("06/01/2021", &Styled::date(FormatStyle::Short, French))?,
+        date!(2021 - 01 - 06)
+    );
+    assert_eq!(
+        Time::parse("1:05 PM", &Styled::time(FormatStyle::Short, English))?,
+        time!(13:05)
+    );
+    Ok(())
+}
+
+#[test]
+fn default_styles() -> time::Result<()> {
+    // Locales that only provide names use the English order of fields.
+    assert_eq!(
+        datetime!(2021-02-01 0:00).format(&Styled::date_time(
+            FormatStyle::Full,
+            FormatStyle::Short,
+            Prefixed
+        ))?,
+        "w, mm 1, 2021 at 12:00 pp"
+    );
+    assert_eq!(
+        Prefixed.date_format(FormatStyle::Medium),
+        English.date_format(FormatStyle::Medium)
+    );
+    Ok(())
+}
+
+#[test]
+fn styles_lenient() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "JAN 6,  2021",
+            &Lenient::new(Styled::date(FormatStyle::Medium, English))
+        )
+        .ok(),
+        None,
+        "names are matched case-sensitively"
+    );
+    assert_eq!(
+        Date::parse(
+            "Jan 6,  2021",
+            &Lenient::new(Styled::date(FormatStyle::Medium, English))
+        )?,
+        date!(2021 - 01 - 06)
+    );
+    Ok(())
+}
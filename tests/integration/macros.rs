@@ -1,8 +1,11 @@
+use std::num::NonZeroU16;
+
 use time::format_description::modifier::{
-    MonthRepr, Padding, WeekNumberRepr, WeekdayRepr, YearRepr,
+    End, Ignore, MonthRepr, Padding, UnixTimestampPrecision, WeekNumberRepr, WeekdayRepr,
+    YearRepr,
 };
 use time::format_description::{Component, FormatItem};
-use time::macros::{date, format_description, time};
+use time::macros::{date, format_description, strftime, time};
 use time::{Date, Time};
 
 #[test]
@@ -42,6 +45,18 @@ fn format_description_coverage() {
             })))
         ]
     );
+    assert_eq!(
+        format_description!("[day suffix:none][day padding:none suffix:ordinal]"),
+        &[
+            FormatItem::Component(Component::Day(modifier!(Day {
+                ordinal_suffix: false,
+            }))),
+            FormatItem::Component(Component::Day(modifier!(Day {
+                padding: Padding::None,
+                ordinal_suffix: true,
+            })))
+        ]
+    );
     assert_eq!(
         format_description!(
             "[offset_minute padding:space][offset_minute padding:zero][offset_minute padding:none]"
@@ -122,12 +137,146 @@ fn format_description_coverage() {
             sign_is_mandatory: false,
         })))]
     );
+    assert_eq!(
+        format_description!(
+            "[unix_timestamp][unix_timestamp precision:millisecond sign:mandatory][unix_timestamp \
+             precision:microsecond sign:automatic][unix_timestamp precision:nanosecond]"
+        ),
+        &[
+            FormatItem::Component(Component::UnixTimestamp(modifier!(UnixTimestamp {
+                precision: UnixTimestampPrecision::Second,
+                sign_is_mandatory: false,
+            }))),
+            FormatItem::Component(Component::UnixTimestamp(modifier!(UnixTimestamp {
+                precision: UnixTimestampPrecision::Millisecond,
+                sign_is_mandatory: true,
+            }))),
+            FormatItem::Component(Component::UnixTimestamp(modifier!(UnixTimestamp {
+                precision: UnixTimestampPrecision::Microsecond,
+                sign_is_mandatory: false,
+            }))),
+            FormatItem::Component(Component::UnixTimestamp(modifier!(UnixTimestamp {
+                precision: UnixTimestampPrecision::Nanosecond,
+                sign_is_mandatory: false,
+            }))),
+        ]
+    );
+    assert_eq!(
+        format_description!("[ignore count:2][ignore count:65535][end]"),
+        &[
+            FormatItem::Component(Component::Ignore(Ignore::count(
+                NonZeroU16::new(2).expect("count is nonzero")
+            ))),
+            FormatItem::Component(Component::Ignore(Ignore::count(
+                NonZeroU16::new(u16::MAX).expect("count is nonzero")
+            ))),
+            FormatItem::Component(Component::End(End::default())),
+        ]
+    );
+    let mut ignore = Ignore::count(NonZeroU16::new(8).expect("count is nonzero"));
+    ignore.until = Some(b'[');
+    assert_eq!(
+        format_description!("[ignore until:,][ignore count:8 until:[]"),
+        &[
+            FormatItem::Component(Component::Ignore(Ignore::until(b','))),
+            FormatItem::Component(Component::Ignore(ignore)),
+        ]
+    );
     assert_eq!(
         format_description!("[[ "),
         &[FormatItem::Literal(b"["), FormatItem::Literal(b" ")]
     );
 }
 
+#[test]
+fn format_description_version() {
+    assert_eq!(
+        format_description!(version = 1, "[[[hour]"),
+        format_description!("[[[hour]")
+    );
+    assert_eq!(
+        format_description!(version = 2, r"\[[hour]\]\\"),
+        &[
+            FormatItem::Literal(b"["),
+            format_description!("[hour]")[0].clone(),
+            FormatItem::Literal(b"]"),
+            FormatItem::Literal(b"\\"),
+        ]
+    );
+    assert_eq!(
+        format_description!(version = 2, "[hour][optional [:[minute]]]"),
+        &[
+            format_description!("[hour]")[0].clone(),
+            FormatItem::Optional(&FormatItem::Compound(format_description!(":[minute]"))),
+        ]
+    );
+    assert_eq!(
+        format_description!(version = 2, "[first [[hour]] [x] ]"),
+        &[FormatItem::First(&[
+            FormatItem::Compound(format_description!("[hour]")),
+            FormatItem::Compound(&[FormatItem::Literal(b"x")]),
+        ])]
+    );
+    assert_eq!(
+        format_description!(version = 2, "[first]"),
+        &[FormatItem::First(&[])]
+    );
+}
+
+#[test]
+fn strftime_coverage() {
+    assert_eq!(
+        strftime!("%Y-%m-%dT%H:%M:%S%z"),
+        format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour \
+             sign:mandatory][offset_minute]"
+        )
+    );
+    assert_eq!(
+        strftime!("%a %A %b %h %B %e %j %U %W %V %G %g %u %w"),
+        format_description!(
+            "[weekday repr:short] [weekday] [month repr:short] [month repr:short] [month \
+             repr:long] [day padding:space] [ordinal] [week_number repr:sunday] [week_number \
+             repr:monday] [week_number] [year base:iso_week] [year repr:last_two \
+             base:iso_week] [weekday repr:monday] [weekday repr:sunday one_indexed:false]"
+        )
+    );
+    assert_eq!(
+        strftime!("%I %l %k %p %P %f %:z %y"),
+        format_description!(
+            "[hour repr:12] [hour repr:12 padding:space] [hour padding:space] [period] [period \
+             case:lower] [subsecond digits:6] [offset_hour sign:mandatory]:[offset_minute] \
+             [year repr:last_two]"
+        )
+    );
+    assert_eq!(strftime!("%D"), format_description!("[month]/[day]/[year repr:last_two]"));
+    assert_eq!(strftime!("%F"), format_description!("[year]-[month]-[day]"));
+    assert_eq!(
+        strftime!("%r"),
+        format_description!("[hour repr:12]:[minute]:[second] [period]")
+    );
+    assert_eq!(strftime!("%R"), format_description!("[hour]:[minute]"));
+    assert_eq!(strftime!("%s"), format_description!("[unix_timestamp]"));
+    assert_eq!(strftime!("%T"), format_description!("[hour]:[minute]:[second]"));
+    assert_eq!(
+        strftime!("%-d%_m%0e%-H%-M%-S%-Y%-j%-V"),
+        format_description!(
+            "[day padding:none][month padding:space][day][hour padding:none][minute \
+             padding:none][second padding:none][year padding:none][ordinal \
+             padding:none][week_number padding:none]"
+        )
+    );
+    assert_eq!(
+        strftime!("%n%t%%"),
+        &[
+            FormatItem::Literal(b"\n"),
+            FormatItem::Literal(b"\t"),
+            FormatItem::Literal(b"%"),
+        ]
+    );
+    assert_eq!(strftime!(b"%F"), format_description!("[year]-[month]-[day]"));
+}
+
 #[test]
 fn date_coverage() {
     assert_eq!(Ok(date!(2000 - 001)), Date::from_ordinal_date(2000, 1));
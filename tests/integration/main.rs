@@ -44,6 +44,7 @@ macro_rules! modifier {
     (@value $field:ident $value:expr) => ($value);
 }
 
+mod annotated_date_time;
 mod date;
 mod derives;
 mod duration;
@@ -52,6 +53,9 @@ mod ext;
 mod format_description;
 mod formatting;
 mod instant;
+mod interval;
+mod leap_seconds;
+mod locale;
 mod macros;
 mod month;
 mod offset_date_time;
@@ -64,6 +68,7 @@ mod rand;
 mod serde;
 mod serde_helpers;
 mod time;
+mod time_scale;
 mod utc_offset;
 mod util;
 mod weekday;
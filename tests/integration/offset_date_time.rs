@@ -51,6 +51,41 @@ fn from_unix_timestamp() {
     );
 }
 
+#[test]
+fn from_unix_timestamp_millis() {
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(0),
+        Ok(OffsetDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(1_546_300_800_001),
+        Ok(datetime!(2019-01-01 0:00:00.001 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(-1),
+        Ok(datetime!(1969-12-31 23:59:59.999 UTC)),
+    );
+    assert!(OffsetDateTime::from_unix_timestamp_millis(i64::MAX).is_err());
+    assert!(OffsetDateTime::from_unix_timestamp_millis(i64::MIN).is_err());
+}
+
+#[test]
+fn from_unix_timestamp_micros() {
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(0),
+        Ok(OffsetDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(1_546_300_800_000_001),
+        Ok(datetime!(2019-01-01 0:00:00.000_001 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(-1),
+        Ok(datetime!(1969-12-31 23:59:59.999_999 UTC)),
+    );
+    assert!(OffsetDateTime::from_unix_timestamp_micros(i128::MAX).is_err());
+}
+
 #[test]
 fn from_unix_timestamp_nanos() {
     assert_eq!(
@@ -88,6 +123,36 @@ fn unix_timestamp() {
     assert_eq!(datetime!(1970-01-01 0:00 -1).unix_timestamp(), 3_600);
 }
 
+#[test]
+fn unix_timestamp_millis() {
+    assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_millis(), 0);
+    assert_eq!(
+        datetime!(1970-01-01 1:00:00.001_999 UTC)
+            .to_offset(offset!(-1))
+            .unix_timestamp_millis(),
+        3_600_001,
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_999 UTC).unix_timestamp_millis(),
+        -1
+    );
+}
+
+#[test]
+fn unix_timestamp_micros() {
+    assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_micros(), 0);
+    assert_eq!(
+        datetime!(1970-01-01 1:00:00.000_001_999 UTC)
+            .to_offset(offset!(-1))
+            .unix_timestamp_micros(),
+        3_600_000_001,
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_999_999 UTC).unix_timestamp_micros(),
+        -1
+    );
+}
+
 #[test]
 fn unix_timestamp_nanos() {
     assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_nanos(), 0);
@@ -242,6 +307,223 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00 UTC).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn julian_date() {
+    assert_eq!(datetime!(2000-01-01 12:00 UTC).to_julian_date(), 2_451_545.);
+    assert_eq!(
+        datetime!(2000-01-01 12:00 +6).to_julian_date(),
+        2_451_544.75
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_451_544.75),
+        Ok(datetime!(2000-01-01 6:00 UTC))
+    );
+    assert!(OffsetDateTime::from_julian_date(f64::NAN).is_err());
+}
+
+#[test]
+fn modified_julian_date() {
+    assert_eq!(
+        datetime!(2000-01-01 12:00 UTC).to_modified_julian_date(),
+        51_544.5
+    );
+    assert_eq!(
+        datetime!(2000-01-01 12:00 -6).to_modified_julian_date(),
+        51_544.75
+    );
+    assert_eq!(
+        OffsetDateTime::from_modified_julian_date(51_544.75),
+        Ok(datetime!(2000-01-01 18:00 UTC))
+    );
+    assert!(OffsetDateTime::from_modified_julian_date(f64::NAN).is_err());
+}
+
+#[test]
+fn windows_file_time() {
+    assert_eq!(
+        OffsetDateTime::from_windows_file_time(0),
+        Ok(datetime!(1601-01-01 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_windows_file_time(-1),
+        Ok(datetime!(1600-12-31 23:59:59.999_999_9 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_windows_file_time(132_854_688_001_234_567),
+        Ok(datetime!(2022-01-01 0:00:00.123_456_7 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_windows_file_time(i128::MAX).map_err(|err| err.name()),
+        Err("file_time")
+    );
+    assert_eq!(
+        OffsetDateTime::from_windows_file_time(i128::MIN).map_err(|err| err.name()),
+        Err("file_time")
+    );
+
+    assert_eq!(datetime!(1601-01-01 0:00 UTC).windows_file_time(), 0);
+    assert_eq!(
+        datetime!(2022-01-01 0:00:00.123_456_789 UTC).windows_file_time(),
+        132_854_688_001_234_567
+    );
+    assert_eq!(
+        datetime!(1600-12-31 23:59:59.999_999_99 UTC).windows_file_time(),
+        -1
+    );
+}
+
+#[test]
+fn dotnet_ticks() {
+    assert_eq!(
+        OffsetDateTime::from_dotnet_ticks(0),
+        Ok(datetime!(0001-01-01 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_dotnet_ticks(3_155_378_975_999_999_999),
+        Ok(datetime!(9999-12-31 23:59:59.999_999_9 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_dotnet_ticks(637_765_920_000_000_000),
+        Ok(datetime!(2022-01-01 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_dotnet_ticks(i128::MAX).map_err(|err| err.name()),
+        Err("ticks")
+    );
+
+    assert_eq!(datetime!(0001-01-01 0:00 UTC).dotnet_ticks(), 0);
+    assert_eq!(
+        datetime!(2022-01-01 1:00 +1).dotnet_ticks(),
+        637_765_920_000_000_000
+    );
+}
+
+#[test]
+fn ntp_timestamp() {
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp(0, 0),
+        Ok(datetime!(1900-01-01 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp(0, u64::MAX),
+        Ok(datetime!(2036-02-07 6:28:16 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp(1, 0),
+        Ok(datetime!(2036-02-07 6:28:16 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp(-1, 0xFFFF_FFFF << 32),
+        Ok(datetime!(1899-12-31 23:59:59 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp(i32::MAX, 0).map_err(|err| err.name()),
+        Err("era")
+    );
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp(i32::MIN, 0).map_err(|err| err.name()),
+        Err("era")
+    );
+
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp_near(0, datetime!(2030-01-01 0:00 UTC)),
+        Ok(datetime!(2036-02-07 6:28:16 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp_near(0xFFFF_FFFF << 32, datetime!(2040-01-01 0:00 UTC)),
+        Ok(datetime!(2036-02-07 6:28:15 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_ntp_timestamp_near(1 << 32, datetime!(1900-01-01 0:00 UTC)),
+        Ok(datetime!(1900-01-01 0:00:01 UTC))
+    );
+
+    assert_eq!(datetime!(1900-01-01 0:00 UTC).ntp_timestamp(), (0, 0));
+    assert_eq!(
+        datetime!(2036-02-07 6:28:15.999_999_999 UTC).ntp_timestamp(),
+        (0, 0xFFFF_FFFF_FFFF_FFFC)
+    );
+    assert_eq!(datetime!(2036-02-07 6:28:16 UTC).ntp_timestamp(), (1, 0));
+
+    for &datetime in &[
+        datetime!(1900-01-01 0:00:00.000_000_001 UTC),
+        datetime!(2022-05-06 7:08:09.123_456_789 UTC),
+        datetime!(2036-02-07 6:28:15.999_999_999 UTC),
+        datetime!(-1000-01-01 0:00:00.5 UTC),
+    ] {
+        let (era, timestamp) = datetime.ntp_timestamp();
+        assert_eq!(
+            OffsetDateTime::from_ntp_timestamp(era, timestamp),
+            Ok(datetime)
+        );
+        assert_eq!(
+            OffsetDateTime::from_ntp_timestamp_near(timestamp, datetime),
+            Ok(datetime)
+        );
+    }
+}
+
+#[test]
+fn cocoa_absolute_time() {
+    assert_eq!(
+        OffsetDateTime::from_cocoa_absolute_time(0.),
+        Ok(datetime!(2001-01-01 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_cocoa_absolute_time(-0.25),
+        Ok(datetime!(2000-12-31 23:59:59.75 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_cocoa_absolute_time(662_688_000.5),
+        Ok(datetime!(2022-01-01 0:00:00.5 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_cocoa_absolute_time(f64::NAN).map_err(|err| err.name()),
+        Err("absolute_time")
+    );
+    assert_eq!(
+        OffsetDateTime::from_cocoa_absolute_time(f64::INFINITY).map_err(|err| err.name()),
+        Err("absolute_time")
+    );
+    assert_eq!(
+        OffsetDateTime::from_cocoa_absolute_time(-1e300).map_err(|err| err.name()),
+        Err("absolute_time")
+    );
+
+    assert_eq!(datetime!(2001-01-01 0:00 UTC).cocoa_absolute_time(), 0.);
+    assert_eq!(
+        datetime!(2000-12-31 23:59:59.75 UTC).cocoa_absolute_time(),
+        -0.25
+    );
+    assert_eq!(
+        datetime!(2022-01-01 1:00:00.5 +1).cocoa_absolute_time(),
+        662_688_000.5
+    );
+}
+
+#[test]
+fn excel_serial() {
+    assert_eq!(
+        OffsetDateTime::from_excel_1900_serial(44_562.5),
+        Ok(datetime!(2022-01-01 12:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_excel_1904_serial(43_100.5),
+        Ok(datetime!(2022-01-01 12:00 UTC))
+    );
+    assert!(OffsetDateTime::from_excel_1900_serial(f64::NAN).is_err());
+    assert!(OffsetDateTime::from_excel_1904_serial(f64::NAN).is_err());
+
+    assert_eq!(
+        datetime!(2022-01-01 12:00 -1).excel_1900_serial(),
+        44_562.541_666_666_664
+    );
+    assert_eq!(
+        datetime!(2022-01-01 12:00 UTC).excel_1904_serial(),
+        43_100.5
+    );
+}
+
 #[test]
 fn to_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03 UTC).to_hms(), (1, 2, 3));
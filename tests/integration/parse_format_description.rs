@@ -1,6 +1,7 @@
 mod iterator {
     use time::format_description::modifier::{
-        MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+        MonthRepr, Padding, SubsecondDigits, UnixTimestampPrecision, WeekNumberRepr, WeekdayRepr,
+        YearRepr,
     };
 
     pub(super) fn padding() -> impl Iterator<Item = (Padding, &'static str)> {
@@ -50,6 +51,18 @@ mod iterator {
         .copied()
     }
 
+    pub(super) fn unix_timestamp_precision(
+    ) -> impl Iterator<Item = (UnixTimestampPrecision, &'static str)> {
+        [
+            (UnixTimestampPrecision::Second, "precision:second"),
+            (UnixTimestampPrecision::Millisecond, "precision:millisecond"),
+            (UnixTimestampPrecision::Microsecond, "precision:microsecond"),
+            (UnixTimestampPrecision::Nanosecond, "precision:nanosecond"),
+        ]
+        .iter()
+        .copied()
+    }
+
     pub(super) fn weekday_repr() -> impl Iterator<Item = (WeekdayRepr, &'static str)> {
         [
             (WeekdayRepr::Short, "repr:short"),
@@ -92,6 +105,12 @@ mod iterator {
             .copied()
     }
 
+    pub(super) fn day_ordinal_suffix() -> impl Iterator<Item = (bool, &'static str)> {
+        [(false, "suffix:none"), (true, "suffix:ordinal")]
+            .iter()
+            .copied()
+    }
+
     pub(super) fn weekday_is_one_indexed() -> impl Iterator<Item = (bool, &'static str)> {
         [(true, "one_indexed:true"), (false, "one_indexed:false")]
             .iter()
@@ -108,11 +127,14 @@ mod iterator {
     }
 }
 
+use std::num::NonZeroU16;
+
 use time::error::InvalidFormatDescription;
 use time::format_description::modifier::{
-    MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+    End, Ignore, MonthRepr, Padding, SubsecondDigits, UnixTimestampPrecision, WeekNumberRepr,
+    WeekdayRepr, YearRepr,
 };
-use time::format_description::{self, Component, FormatItem};
+use time::format_description::{self, Component, FormatItem, OwnedFormatItem};
 
 #[test]
 fn empty() {
@@ -161,6 +183,10 @@ fn simple_component() {
             }
         )))])
     );
+    assert_eq!(
+        format_description::parse("[end]"),
+        Ok(vec![FormatItem::Component(Component::End(End::default()))])
+    );
     assert_eq!(
         format_description::parse("[hour]"),
         Ok(vec![FormatItem::Component(Component::Hour(modifier!(
@@ -242,6 +268,15 @@ fn simple_component() {
             })
         ))])
     );
+    assert_eq!(
+        format_description::parse("[unix_timestamp]"),
+        Ok(vec![FormatItem::Component(Component::UnixTimestamp(
+            modifier!(UnixTimestamp {
+                precision: UnixTimestampPrecision::Second,
+                sign_is_mandatory: false,
+            })
+        ))])
+    );
     assert_eq!(
         format_description::parse("[weekday]"),
         Ok(vec![FormatItem::Component(Component::Weekday(modifier!(
@@ -292,6 +327,34 @@ fn errors() {
         format_description::parse("[day sign:mandatory]"),
         Err(InvalidModifier { value, index: 5,.. }) if value == "sign:mandatory"
     ));
+    assert!(matches!(
+        format_description::parse("[ignore]"),
+        Err(MissingRequiredModifier { name: "count", index: 1, .. })
+    ));
+    assert!(matches!(
+        format_description::parse("[ignore count:0]"),
+        Err(InvalidModifier { value, index: 8, .. }) if value == "count:0"
+    ));
+    assert!(matches!(
+        format_description::parse("[ignore count:x]"),
+        Err(InvalidModifier { value, index: 8, .. }) if value == "count:x"
+    ));
+    assert!(matches!(
+        format_description::parse("[ignore count:65536]"),
+        Err(InvalidModifier { value, index: 8, .. }) if value == "count:65536"
+    ));
+    assert!(matches!(
+        format_description::parse("[ignore until:]"),
+        Err(InvalidModifier { value, index: 8, .. }) if value == "until:"
+    ));
+    assert!(matches!(
+        format_description::parse("[ignore until:ab]"),
+        Err(InvalidModifier { value, index: 8, .. }) if value == "until:ab"
+    ));
+    assert!(matches!(
+        format_description::parse("[end count:1]"),
+        Err(InvalidModifier { value, index: 5, .. }) if value == "count:1"
+    ));
 }
 
 #[test]
@@ -303,6 +366,20 @@ fn component_with_modifiers() {
                 Day { padding }
             )))])
         );
+        for (ordinal_suffix, ordinal_suffix_str) in iterator::day_ordinal_suffix() {
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[day {} {}]",
+                    padding_str, ordinal_suffix_str
+                )),
+                Ok(vec![FormatItem::Component(Component::Day(modifier!(
+                    Day {
+                        padding,
+                        ordinal_suffix
+                    }
+                )))])
+            );
+        }
         assert_eq!(
             format_description::parse(&format!("[minute {}]", padding_str)),
             Ok(vec![FormatItem::Component(Component::Minute(modifier!(
@@ -447,6 +524,45 @@ fn component_with_modifiers() {
             ))])
         );
     }
+
+    for (precision, precision_str) in iterator::unix_timestamp_precision() {
+        for (sign_is_mandatory, sign_is_mandatory_str) in iterator::sign_is_mandatory() {
+            assert_eq!(
+                format_description::parse(&format!(
+                    "[unix_timestamp {} {}]",
+                    precision_str, sign_is_mandatory_str
+                )),
+                Ok(vec![FormatItem::Component(Component::UnixTimestamp(
+                    modifier!(UnixTimestamp {
+                        precision,
+                        sign_is_mandatory
+                    })
+                ))])
+            );
+        }
+    }
+    for count in [1, 16, u16::MAX] {
+        assert_eq!(
+            format_description::parse(&format!("[ignore count:{}]", count)),
+            Ok(vec![FormatItem::Component(Component::Ignore(
+                Ignore::count(NonZeroU16::new(count).expect("count is nonzero"))
+            ))])
+        );
+    }
+    assert_eq!(
+        format_description::parse("[ignore until:|]"),
+        Ok(vec![FormatItem::Component(Component::Ignore(Ignore::until(
+            b'|'
+        )))])
+    );
+    let mut ignore = Ignore::count(NonZeroU16::new(4).expect("count is nonzero"));
+    ignore.until = Some(b'\\');
+    assert_eq!(
+        format_description::parse_owned::<2>(r"[ignore count:4 until:\]"),
+        Ok(OwnedFormatItem::from(
+            &[FormatItem::Component(Component::Ignore(ignore))][..]
+        ))
+    );
 }
 
 #[test]
@@ -469,6 +585,297 @@ fn error_display() {
         format_description::parse("[]").unwrap_err().to_string(),
         "missing component name at byte index 1"
     );
+    assert_eq!(
+        format_description::parse("[ignore]")
+            .expect_err("count is required")
+            .to_string(),
+        "missing required modifier `count` for component at byte index 1"
+    );
+    assert_eq!(
+        format_description::parse_borrowed::<2>(r"\x")
+            .expect_err("escape sequence is invalid")
+            .to_string(),
+        "expected valid escape sequence at byte index 0"
+    );
+    assert_eq!(
+        format_description::parse_borrowed::<2>("x[optional [[year]]]")
+            .expect_err("optional items are not borrowed")
+            .to_string(),
+        "optional item is not supported in borrowed format descriptions at byte index 1"
+    );
+}
+
+#[test]
+fn version_2() -> time::Result<()> {
+    assert_eq!(
+        format_description::parse_borrowed::<2>(r"\[\\[year]\]")?,
+        vec![
+            FormatItem::Literal(b"["),
+            FormatItem::Literal(b"\\"),
+            format_description::parse("[year]")?[0].clone(),
+            FormatItem::Literal(b"]"),
+        ]
+    );
+    assert_eq!(
+        format_description::parse_borrowed::<2>("[year]]")?,
+        format_description::parse("[year]]")?
+    );
+    assert_eq!(
+        format_description::parse_borrowed::<1>("[[[year]")?,
+        format_description::parse("[[[year]")?
+    );
+    assert!(matches!(
+        format_description::parse_borrowed::<2>("[[year]"),
+        Err(InvalidFormatDescription::InvalidComponentName { name, index: 1, .. })
+            if name == "[year"
+    ));
+    assert!(matches!(
+        format_description::parse_borrowed::<2>("[first [[year]]]"),
+        Err(InvalidFormatDescription::NotSupported { what: "'first' item", index: 0, .. })
+    ));
+    assert!(matches!(
+        format_description::parse("[optional [[year]]]"),
+        Err(InvalidFormatDescription::InvalidComponentName { name, index: 1, .. })
+            if name == "optional"
+    ));
+    assert_eq!(
+        format_description::parse_owned::<2>(r"\[[year]")?,
+        format_description::parse_owned::<1>("[[[year]")?
+    );
+    Ok(())
+}
+
+#[test]
+fn nested() -> time::Result<()> {
+    let owned = format_description::parse_owned::<1>;
+    let component = |s| -> time::Result<OwnedFormatItem> {
+        Ok(OwnedFormatItem::from(&format_description::parse(s)?[0]))
+    };
+
+    assert_eq!(
+        format_description::parse_owned::<2>("[year][optional [-[month]]]")?,
+        OwnedFormatItem::Compound(Box::new([
+            component("[year]")?,
+            OwnedFormatItem::Optional(Box::new(owned("-[month]")?)),
+        ]))
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[ optional\t[[hour]\\]]  ]")?,
+        OwnedFormatItem::Compound(Box::new([OwnedFormatItem::Optional(Box::new(
+            OwnedFormatItem::Compound(Box::new([
+                component("[hour]")?,
+                OwnedFormatItem::Literal(Box::new(*b"]")),
+            ]))
+        ))]))
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[first [[day]] [x[optional [[year]]]] ]")?,
+        OwnedFormatItem::Compound(Box::new([OwnedFormatItem::First(Box::new([
+            owned("[day]")?,
+            OwnedFormatItem::Compound(Box::new([
+                component("x")?,
+                OwnedFormatItem::Optional(Box::new(owned("[year]")?)),
+            ])),
+        ]))]))
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[first]")?,
+        OwnedFormatItem::Compound(Box::new([OwnedFormatItem::First(Box::new([]))]))
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[optional []]")?,
+        OwnedFormatItem::Compound(Box::new([OwnedFormatItem::Optional(Box::new(owned("")?))]))
+    );
+    Ok(())
+}
+
+#[test]
+fn nested_errors() {
+    use InvalidFormatDescription::*;
+
+    assert!(matches!(
+        format_description::parse_owned::<2>("[optional [[year]"),
+        Err(UnclosedOpeningBracket { index: 10, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[optional [[year]] "),
+        Err(UnclosedOpeningBracket { index: 0, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[first [a] x]"),
+        Err(Expected { what: "opening bracket", index: 11, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[optional]"),
+        Err(Expected { what: "opening bracket", index: 9, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[optional [a] [b]]"),
+        Err(Expected { what: "closing bracket", index: 14, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[optional [[foo]]]"),
+        Err(InvalidComponentName { name, index: 12, .. }) if name == "foo"
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>(r"[first [\q]]"),
+        Err(Expected { what: "valid escape sequence", index: 8, .. })
+    ));
+}
+
+#[test]
+fn parse_owned() -> time::Result<()> {
+    assert_eq!(
+        format_description::parse_owned::<1>("[year]-[month]-[day]")?,
+        OwnedFormatItem::from(format_description::parse("[year]-[month]-[day]")?)
+    );
+    assert_eq!(
+        format_description::parse_owned::<1>("")?,
+        OwnedFormatItem::Compound(Box::new([]))
+    );
+    assert!(matches!(
+        format_description::parse_owned::<1>("[foo]"),
+        Err(InvalidFormatDescription::InvalidComponentName { name, index: 1, .. }) if name == "foo"
+    ));
+    assert_eq!(
+        format_description::parse_strftime_owned("%F")?,
+        format_description::parse_owned::<1>("[year]-[month]-[day]")?
+    );
+    assert!(matches!(
+        format_description::parse_strftime_owned("%Q"),
+        Err(InvalidFormatDescription::UnsupportedSpecifier { index: 0, .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn strftime() -> time::Result<()> {
+    for &(strftime, description) in &[
+        ("%a", "[weekday repr:short]"),
+        ("%A", "[weekday]"),
+        ("%b", "[month repr:short]"),
+        ("%h", "[month repr:short]"),
+        ("%B", "[month repr:long]"),
+        ("%d", "[day]"),
+        ("%D", "[month]/[day]/[year repr:last_two]"),
+        ("%e", "[day padding:space]"),
+        ("%f", "[subsecond digits:6]"),
+        ("%F", "[year]-[month]-[day]"),
+        ("%g", "[year repr:last_two base:iso_week]"),
+        ("%G", "[year base:iso_week]"),
+        ("%H", "[hour]"),
+        ("%I", "[hour repr:12]"),
+        ("%j", "[ordinal]"),
+        ("%k", "[hour padding:space]"),
+        ("%l", "[hour repr:12 padding:space]"),
+        ("%m", "[month]"),
+        ("%M", "[minute]"),
+        ("%n", "\n"),
+        ("%p", "[period]"),
+        ("%P", "[period case:lower]"),
+        ("%r", "[hour repr:12]:[minute]:[second] [period]"),
+        ("%R", "[hour]:[minute]"),
+        ("%s", "[unix_timestamp]"),
+        ("%S", "[second]"),
+        ("%t", "\t"),
+        ("%T", "[hour]:[minute]:[second]"),
+        ("%u", "[weekday repr:monday]"),
+        ("%U", "[week_number repr:sunday]"),
+        ("%V", "[week_number]"),
+        ("%w", "[weekday repr:sunday one_indexed:false]"),
+        ("%W", "[week_number repr:monday]"),
+        ("%y", "[year repr:last_two]"),
+        ("%Y", "[year]"),
+        ("%z", "[offset_hour sign:mandatory][offset_minute]"),
+        ("%:z", "[offset_hour sign:mandatory]:[offset_minute]"),
+        ("%%", "%"),
+        ("%-d", "[day padding:none]"),
+        ("%_m", "[month padding:space]"),
+        ("%0e", "[day]"),
+        ("%-I", "[hour repr:12 padding:none]"),
+        ("%-G", "[year base:iso_week padding:none]"),
+        (
+            "%Y-%m-%dT%H:%M:%S%z",
+            "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour \
+             sign:mandatory][offset_minute]",
+        ),
+        ("day %j of %Y", "day [ordinal] of [year]"),
+    ] {
+        assert_eq!(
+            format_description::parse_strftime(strftime)?,
+            format_description::parse(description)?,
+            "{}",
+            strftime
+        );
+    }
+    assert_eq!(
+        format_description::parse_strftime("[%H]")?,
+        vec![
+            FormatItem::Literal(b"["),
+            FormatItem::Component(Component::Hour(modifier!(Hour {
+                padding: Padding::Zero,
+                is_12_hour_clock: false,
+            }))),
+            FormatItem::Literal(b"]"),
+        ]
+    );
+    assert!(format_description::parse_strftime("")?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn strftime_errors() {
+    use InvalidFormatDescription::*;
+    assert!(matches!(
+        format_description::parse_strftime("%Y-%Q"),
+        Err(UnsupportedSpecifier { specifier, index: 3, .. }) if specifier == "%Q"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%-s"),
+        Err(UnsupportedSpecifier { specifier, index: 0, .. }) if specifier == "%-s"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%H:%M %Z"),
+        Err(UnsupportedSpecifier { specifier, index: 6, .. }) if specifier == "%Z"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%c"),
+        Err(UnsupportedSpecifier { specifier, index: 0, .. }) if specifier == "%c"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("ä%é"),
+        Err(UnsupportedSpecifier { specifier, index: 2, .. }) if specifier == "%é"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%:H"),
+        Err(UnsupportedSpecifier { specifier, index: 0, .. }) if specifier == "%:H"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%-a"),
+        Err(UnsupportedSpecifier { specifier, index: 0, .. }) if specifier == "%-a"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%_T"),
+        Err(UnsupportedSpecifier { specifier, index: 0, .. }) if specifier == "%_T"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%H %"),
+        Err(MissingComponentName { index: 4, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%-"),
+        Err(MissingComponentName { index: 2, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime("%:"),
+        Err(MissingComponentName { index: 2, .. })
+    ));
+    assert_eq!(
+        format_description::parse_strftime("%Y %q")
+            .expect_err("specifier is unsupported")
+            .to_string(),
+        "unsupported specifier `%q` at byte index 3"
+    );
 }
 
 #[test]
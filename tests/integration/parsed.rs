@@ -36,6 +36,7 @@ fn getters_setters() {
         set_offset_hour offset_hour 5;
         set_offset_minute offset_minute 5;
         set_offset_second offset_second 5;
+        set_unix_timestamp_nanos unix_timestamp_nanos 5;
     }
 }
 
@@ -62,6 +63,7 @@ fn builder_methods() {
         .and_then(|parsed| parsed.with_offset_hour(5))
         .and_then(|parsed| parsed.with_offset_minute(5))
         .and_then(|parsed| parsed.with_offset_second(5))
+        .and_then(|parsed| parsed.with_unix_timestamp_nanos(5))
         .expect("all values are valid");
 
     assert_eq!(parsed.year(), Some(5));
@@ -93,6 +95,7 @@ fn builder_methods() {
     assert_eq!(parsed.offset_hour(), Some(5));
     assert_eq!(parsed.offset_minute(), Some(5));
     assert_eq!(parsed.offset_second(), Some(5));
+    assert_eq!(parsed.unix_timestamp_nanos(), Some(5));
 }
 
 #[test]
@@ -136,6 +139,7 @@ fn component_err() {
     assert_invalid_component!("offset hour", Component::OffsetHour(<_>::default()));
     assert_invalid_component!("offset minute", Component::OffsetMinute(<_>::default()));
     assert_invalid_component!("offset second", Component::OffsetSecond(<_>::default()));
+    assert_invalid_component!("unix timestamp", Component::UnixTimestamp(<_>::default()));
 
     assert_invalid_component!(
         "week number",
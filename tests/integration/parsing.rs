@@ -1,10 +1,13 @@
 use core::convert::{TryFrom, TryInto};
 use std::num::NonZeroU8;
 
-use time::format_description::well_known::{Rfc2822, Rfc3339};
-use time::format_description::{modifier, Component, FormatItem};
-use time::macros::{date, datetime, time};
-use time::parsing::Parsed;
+use time::format_description::well_known::iso8601::{Config, EncodedConfig, FormattedComponents};
+use time::format_description::well_known::{
+    GeneralizedTime, HttpDate, Iso8601, Rfc2822, Rfc3164, Rfc3339, Rfc5424, Rfc9557, UtcTime,
+};
+use time::format_description::{modifier, Component, FormatItem, OwnedFormatItem};
+use time::macros::{date, datetime, offset, time};
+use time::parsing::{Lenient, Located, Parsed};
 use time::{
     error, format_description as fd, Date, Month, OffsetDateTime, PrimitiveDateTime, Time,
     UtcOffset, Weekday,
@@ -25,6 +28,17 @@ macro_rules! invalid_component {
     };
 }
 
+/// Obtain the location and underlying error of a failed parse using a format description wrapped
+/// in [`Located`].
+fn invalid_input<T>(
+    result: Result<T, error::Parse>,
+) -> Option<(usize, error::ParseFromDescription)> {
+    match result {
+        Err(error::Parse::InvalidInput(err)) => Some((err.index(), err.error())),
+        _ => None,
+    }
+}
+
 #[test]
 fn rfc_2822() -> time::Result<()> {
     assert_eq!(
@@ -49,27 +63,27 @@ fn rfc_2822() -> time::Result<()> {
     );
     assert_eq!(
         OffsetDateTime::parse("Fri, 31 Dec 2021 23:59:60 Z", &Rfc2822)?,
-        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+        datetime!(2021-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?),
     );
     assert_eq!(
         OffsetDateTime::parse("Fri, 31 Dec 2021 23:59:60 z", &Rfc2822)?,
-        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+        datetime!(2021-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?),
     );
     assert_eq!(
         OffsetDateTime::parse("Fri, 31 Dec 2021 23:59:60 a", &Rfc2822)?,
-        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+        datetime!(2021-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?),
     );
     assert_eq!(
         OffsetDateTime::parse("Fri, 31 Dec 2021 23:59:60 A", &Rfc2822)?,
-        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+        datetime!(2021-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?),
     );
     assert_eq!(
         OffsetDateTime::parse("Fri, 31 Dec 2021 17:52:60 -0607", &Rfc2822)?,
-        datetime!(2021-12-31 17:52:59.999_999_999 -06:07),
+        datetime!(2021-12-31 0:00 -06:07).replace_time(Time::from_leap_second(17, 52, 0)?),
     );
     assert_eq!(
         OffsetDateTime::parse("Sat, 01 Jan 2022 06:06:60 +0607", &Rfc2822)?,
-        datetime!(2022-01-01 06:06:59.999_999_999 +06:07),
+        datetime!(2022-01-01 0:00 +06:07).replace_time(Time::from_leap_second(6, 6, 0)?),
     );
 
     assert_eq!(
@@ -215,6 +229,103 @@ fn rfc_2822_err() {
     ));
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Tuesday, 01-Jan-30 00:00:00 GMT", &HttpDate)?,
+        datetime!(2030-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Wed Nov 16 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-16 08:49:37 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 31 Dec 2016 23:59:60 GMT", &HttpDate)?,
+        datetime!(2016-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?)
+    );
+    assert_eq!(
+        Date::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        date!(1994-11-06)
+    );
+    Ok(())
+}
+
+#[test]
+fn http_date_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("sun, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun,06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 6 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 94 08:49:37 GMT", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 8:49:37 GMT", &HttpDate),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 UT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 +0000", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 (comment) GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun,  06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sunday, 06-Nov-1994 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov 6 08:49:37 1994", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 GMT", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT ", &HttpDate),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -223,7 +334,13 @@ fn rfc_3339() -> time::Result<()> {
     );
     assert_eq!(
         OffsetDateTime::parse("2021-01-02T03:04:60Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:59.999_999_999 UTC),
+        datetime!(2021-01-02 0:00 UTC).replace_time(Time::from_leap_second(3, 4, 0)?),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2016-12-31T23:59:60.5+01:00", &Rfc3339)?,
+        datetime!(2016-12-31 0:00 UTC)
+            .replace_time(Time::from_leap_second(22, 59, 500_000_000)?)
+            .to_offset(offset!(+1)),
     );
     assert_eq!(
         OffsetDateTime::parse("2021-01-02T03:04:05.1Z", &Rfc3339)?,
@@ -280,16 +397,133 @@ fn rfc_3339() -> time::Result<()> {
     );
     assert_eq!(
         Date::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
-        date!(2021 - 01 - 02),
+        date!(2021-01-02),
     );
     assert_eq!(
         Time::parse("2021-01-02T03:04:60Z", &Rfc3339)?,
-        time!(03:04:59.999_999_999)
+        Time::from_leap_second(3, 4, 0)?
     );
 
     Ok(())
 }
 
+#[test]
+fn iso_8601() -> time::Result<()> {
+    const TIME_OFFSET: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::TimeOffset)
+        .encode();
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Iso8601::DEFAULT)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20210102T030405.123-0102", &Iso8601::DEFAULT)?,
+        datetime!(2021-01-02 03:04:05.123 -01:02),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-W05-3T03:04:05,5+01", &Iso8601::DEFAULT)?,
+        datetime!(2021-02-03 03:04:05.5 +01:00),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021W053T0304z", &Iso8601::DEFAULT)?,
+        datetime!(2021-02-03 03:04 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-034T03.5-05:30", &Iso8601::DEFAULT)?,
+        datetime!(2021-02-03 03:30 -05:30),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021034T03Z", &Iso8601::DEFAULT)?,
+        datetime!(2021-02-03 03:00 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("+002021-01-02T03:04:05Z", &Iso8601::DEFAULT)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2016-12-31T23:59:60.5Z", &Iso8601::DEFAULT)?,
+        datetime!(2016-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 500_000_000)?),
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:05.123456789123", &Iso8601::DATE_TIME)?,
+        datetime!(2021-01-02 03:04:05.123_456_789),
+    );
+    assert_eq!(
+        Date::parse("2021-01-02", &Iso8601::DATE)?,
+        date!(2021-01-02)
+    );
+    assert_eq!(
+        Date::parse("-002021-002", &Iso8601::DATE)?,
+        date!(-2021-01-02)
+    );
+    assert_eq!(
+        Date::parse("2021-01-02T03:04:05Z", &Iso8601::DEFAULT)?,
+        date!(2021-01-02)
+    );
+    assert_eq!(Time::parse("T0304", &Iso8601::TIME)?, time!(03:04));
+    assert_eq!(Time::parse("03:04.5", &Iso8601::TIME)?, time!(03:04:30));
+    assert_eq!(Time::parse("03,25", &Iso8601::TIME)?, time!(03:15));
+    assert_eq!(
+        UtcOffset::parse("03:04-05", &Iso8601::<TIME_OFFSET>)?,
+        offset!(-5)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("x", &Iso8601::DEFAULT),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01T03:04Z", &Iso8601::DEFAULT),
+        invalid_component!("ordinal")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T0304Z", &Iso8601::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("20210102T03:04Z", &Iso8601::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-13-02T03:04Z", &Iso8601::DEFAULT),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-W00-1T03:04Z", &Iso8601::DEFAULT),
+        invalid_component!("week number")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-W01-8T03:04Z", &Iso8601::DEFAULT),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02 03:04Z", &Iso8601::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04.Z", &Iso8601::DEFAULT),
+        invalid_component!("subsecond")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04", &Iso8601::DEFAULT),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        Time::parse("24:00", &Iso8601::TIME),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+}
+
 #[test]
 fn rfc_3339_err() {
     assert!(matches!(
@@ -386,68 +620,419 @@ fn rfc_3339_err() {
         invalid_component!("month")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01x", &Rfc3339),
-        invalid_literal!()
+        OffsetDateTime::parse("2021-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-0", &Rfc3339),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T0", &Rfc3339),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:0", &Rfc3339),
+        invalid_component!("minute")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
+        invalid_component!("subsecond")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
+        invalid_component!("offset minute")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+24:00", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset hour"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+00:60", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset minute"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+    ));
+}
+
+#[test]
+fn rfc_3164() -> time::Result<()> {
+    assert_eq!(Time::parse("Oct 11 22:14:15", &Rfc3164)?, time!(22:14:15));
+    assert_eq!(
+        Rfc3164.parse_with_year("Oct 11 22:14:15", 2003)?,
+        datetime!(2003-10-11 22:14:15)
+    );
+    assert_eq!(
+        Rfc3164.parse_with_year("Oct  1 22:14:15", 2003)?,
+        datetime!(2003-10-01 22:14:15)
+    );
+    assert_eq!(
+        Rfc3164.parse_with_year("Oct 01 22:14:15", 2003)?,
+        datetime!(2003-10-01 22:14:15)
+    );
+    assert_eq!(
+        Rfc3164.parse_relative_to("Oct 11 22:14:15", datetime!(2003-10-12 0:00 +2))?,
+        datetime!(2003-10-11 22:14:15 +2)
+    );
+    assert_eq!(
+        Rfc3164.parse_relative_to("Dec 31 23:59:59", datetime!(2022-01-01 0:00 UTC))?,
+        datetime!(2021-12-31 23:59:59 UTC)
+    );
+    assert_eq!(
+        Rfc3164.parse_relative_to("Jan  1 00:00:00", datetime!(2021-12-31 23:59 UTC))?,
+        datetime!(2022-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        Rfc3164.parse_relative_to("Feb 29 12:00:00", datetime!(2025-01-01 0:00 UTC))?,
+        datetime!(2024-02-29 12:00 UTC)
+    );
+    Ok(())
+}
+
+#[test]
+fn rfc_3164_err() {
+    assert!(matches!(
+        Rfc3164.parse_with_year("oct 11 22:14:15", 2003),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        Rfc3164.parse_with_year("Oct 1 22:14:15", 2003),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        Rfc3164.parse_with_year("Oct 11 22:14", 2003),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        Rfc3164.parse_with_year("Oct 11 22:14:15 2003", 2003),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        Rfc3164.parse_with_year("Feb 29 00:00:00", 2021),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "day"
+    ));
+    assert!(matches!(
+        Rfc3164.parse_relative_to("Feb 30 00:00:00", datetime!(2021-01-01 0:00 UTC)),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "day"
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("Oct 11 22:14:15", &Rfc3164),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+}
+
+#[test]
+fn rfc_5424() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("1985-04-12T23:20:50.52Z", &Rfc5424)?,
+        datetime!(1985-04-12 23:20:50.52 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2003-10-11T22:14:15.003-07:00", &Rfc5424)?,
+        datetime!(2003-10-11 22:14:15.003 -7)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2003-08-24T05:14:15.000003-07:00", &Rfc5424)?,
+        datetime!(2003-08-24 05:14:15.000_003 -7)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2003-10-11T22:14:15Z", &Rfc5424)?,
+        datetime!(2003-10-11 22:14:15 UTC)
+    );
+    Ok(())
+}
+
+#[test]
+fn rfc_5424_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("2003-08-24T05:14:15.000000003-07:00", &Rfc5424),
+        invalid_component!("subsecond")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2003-10-11t22:14:15Z", &Rfc5424),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2003-10-11T22:14:15z", &Rfc5424),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2016-12-31T23:59:60Z", &Rfc5424),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("-", &Rfc5424),
+        invalid_component!("year")
+    ));
+}
+
+#[test]
+fn rfc_9557() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00", &Rfc9557)?,
+        datetime!(2022-07-08 0:14:07 +1)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2022-07-08T00:14:07+01:00[Europe/Paris][u-ca=gregory]",
+            &Rfc9557
+        )?,
+        datetime!(2022-07-08 0:14:07 +1)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2022-07-08T00:14:07+01:00[!+01:00][!u-ca=iso8601]",
+            &Rfc9557
+        )?,
+        datetime!(2022-07-08 0:14:07 +1)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2022-07-08T00:14:07Z[!+01:00]", &Rfc9557)?,
+        datetime!(2022-07-08 0:14:07 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[+02:00]", &Rfc9557)?,
+        datetime!(2022-07-08 0:14:07 +1)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2022-07-08T00:14:07-03:00[!America/Argentina/Buenos_Aires]",
+            &Rfc9557
+        )?,
+        datetime!(2022-07-08 0:14:07 -3)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2022-07-08T00:14:07+01:00[foo=bar-baz][_x=1]",
+            &Rfc9557
+        )?,
+        datetime!(2022-07-08 0:14:07 +1)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2022-07-08T00:14:07+01:00[u-ca=gregory][u-ca=iso8601]",
+            &Rfc9557
+        )?,
+        datetime!(2022-07-08 0:14:07 +1)
+    );
+    Ok(())
+}
+
+#[test]
+fn rfc_9557_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[!+02:00]", &Rfc9557),
+        invalid_component!("time zone")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[+25:00]", &Rfc9557),
+        invalid_component!("time zone")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[+01]", &Rfc9557),
+        invalid_component!("time zone")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[Europe/../Paris]", &Rfc9557),
+        invalid_component!("time zone")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[1Europe]", &Rfc9557),
+        invalid_component!("time zone")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[Europe/Paris", &Rfc9557),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[!foo=bar]", &Rfc9557),
+        invalid_component!("annotation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[Foo=bar]", &Rfc9557),
+        invalid_component!("annotation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[foo=]", &Rfc9557),
+        invalid_component!("annotation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse(
+            "2022-07-08T00:14:07+01:00[u-ca=gregory][Europe/Paris]",
+            &Rfc9557
+        ),
+        invalid_component!("annotation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse(
+            "2022-07-08T00:14:07+01:00[u-ca=gregory][!u-ca=iso8601]",
+            &Rfc9557
+        ),
+        invalid_component!("calendar")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2022-07-08T00:14:07+01:00[Europe/Paris]x", &Rfc9557),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+}
+
+#[test]
+fn utc_time() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("491231235959Z", &UtcTime::DER)?,
+        datetime!(2049-12-31 23:59:59 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("500101000000Z", &UtcTime::DER)?,
+        datetime!(1950-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("000229120000Z", &UtcTime::BER)?,
+        datetime!(2000-02-29 12:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("9912312359Z", &UtcTime::BER)?,
+        datetime!(1999-12-31 23:59 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("991231235930-0500", &UtcTime::BER)?,
+        datetime!(1999-12-31 23:59:30 -5)
+    );
+    Ok(())
+}
+
+#[test]
+fn utc_time_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("9912312359Z", &UtcTime::DER),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("991231235959+0000", &UtcTime::DER),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("991231235959", &UtcTime::BER),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("991231235959+00", &UtcTime::BER),
+        invalid_component!("offset minute")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("991331235959Z", &UtcTime::DER),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+    ));
+}
+
+#[test]
+fn generalized_time() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("20491231235959Z", &GeneralizedTime::DER)?,
+        datetime!(2049-12-31 23:59:59 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20491231235959.123456789Z", &GeneralizedTime::DER)?,
+        datetime!(2049-12-31 23:59:59.123_456_789 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("19500101000000.5Z", &GeneralizedTime::BER)?,
+        datetime!(1950-01-01 0:00:00.5 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("19500101000000,50Z", &GeneralizedTime::BER)?,
+        datetime!(1950-01-01 0:00:00.5 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("195001011230.5+01", &GeneralizedTime::BER)?,
+        datetime!(1950-01-01 12:30:30 +1)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1950010112.25-0130", &GeneralizedTime::BER)?,
+        datetime!(1950-01-01 12:15 -1:30)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("1950010112", &GeneralizedTime::BER)?,
+        datetime!(1950-01-01 12:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("19500101123456.7", &GeneralizedTime::BER)?,
+        datetime!(1950-01-01 12:34:56.7)
+    );
+    Ok(())
+}
+
+#[test]
+fn generalized_time_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("20491231235959.50Z", &GeneralizedTime::DER),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-0", &Rfc3339),
-        invalid_component!("day")
+        OffsetDateTime::parse("20491231235959.Z", &GeneralizedTime::DER),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01x", &Rfc3339),
+        OffsetDateTime::parse("20491231235959,5Z", &GeneralizedTime::DER),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T0", &Rfc3339),
-        invalid_component!("hour")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00x", &Rfc3339),
-        invalid_literal!()
+        OffsetDateTime::parse("204912312359Z", &GeneralizedTime::DER),
+        invalid_component!("second")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:0", &Rfc3339),
+        OffsetDateTime::parse("2049123123Z", &GeneralizedTime::DER),
         invalid_component!("minute")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        OffsetDateTime::parse("20491231235959", &GeneralizedTime::DER),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
-        invalid_component!("second")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
-        invalid_component!("subsecond")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
-        invalid_component!("offset hour")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
-        invalid_component!("offset hour")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
+        OffsetDateTime::parse("20491231235959+0100", &GeneralizedTime::DER),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
-        invalid_component!("offset minute")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+24:00", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset hour"
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+00:60", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "offset minute"
+        OffsetDateTime::parse("20491231235959", &GeneralizedTime::BER),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+        OffsetDateTime::parse("20491231235959+1", &GeneralizedTime::BER),
+        invalid_component!("offset hour")
     ));
 }
 
@@ -792,6 +1377,365 @@ fn parse_offset_date_time_err() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn parse_unix_timestamp() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("1634396400", &fd::parse("[unix_timestamp]")?)?,
+        datetime!(2021-10-16 15:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1634396400123",
+            &fd::parse("[unix_timestamp precision:millisecond]")?
+        )?,
+        datetime!(2021-10-16 15:00:00.123 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1634396400123456",
+            &fd::parse("[unix_timestamp precision:microsecond]")?
+        )?,
+        datetime!(2021-10-16 15:00:00.123_456 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "-1634396400123456789",
+            &fd::parse("[unix_timestamp precision:nanosecond]")?
+        )?,
+        datetime!(1918-03-18 08:59:59.876_543_211 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "[1634400000.123] message",
+            &fd::parse("[[[unix_timestamp].[subsecond]] message")?
+        )?,
+        datetime!(2021-10-16 16:00:00.123 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-1.75", &fd::parse("[unix_timestamp].[subsecond]")?)?,
+        datetime!(1969-12-31 23:59:59.75 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "+1634396400 +01:00",
+            &fd::parse("[unix_timestamp sign:mandatory] [offset_hour]:[offset_minute]")?
+        )?,
+        datetime!(2021-10-16 16:00 +1)
+    );
+
+    // The timestamp takes precedence over any other components.
+    assert_eq!(
+        OffsetDateTime::parse(
+            "0 2021-01-01",
+            &fd::parse("[unix_timestamp] [year]-[month]-[day]")?
+        )?,
+        OffsetDateTime::UNIX_EPOCH
+    );
+
+    assert_eq!(
+        Date::parse("1634400000", &fd::parse("[unix_timestamp]")?)?,
+        date!(2021 - 10 - 16)
+    );
+    assert_eq!(
+        Time::parse("1634400000", &fd::parse("[unix_timestamp]")?)?,
+        time!(16:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("1634400000", &fd::parse("[unix_timestamp]")?)?,
+        datetime!(2021-10-16 16:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "1634400000 -20:00",
+            &fd::parse("[unix_timestamp] [offset_hour]:[offset_minute]")?
+        )?,
+        datetime!(2021-10-15 20:00)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_unix_timestamp_err() -> time::Result<()> {
+    assert!(matches!(
+        OffsetDateTime::parse(
+            "1634396400",
+            &fd::parse("[unix_timestamp sign:mandatory]")?
+        ),
+        invalid_component!("unix timestamp")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("x", &fd::parse("[unix_timestamp]")?),
+        invalid_component!("unix timestamp")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("99999999999999", &fd::parse("[unix_timestamp]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "timestamp"
+    ));
+    assert!(matches!(
+        Date::parse("99999999999999", &fd::parse("[unix_timestamp]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "timestamp"
+    ));
+    Ok(())
+}
+
+#[test]
+fn parse_ignore_end() -> time::Result<()> {
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "2021-01-02 [worker-3] 13:05",
+            &fd::parse("[year]-[month]-[day] [ignore count:11][hour]:[minute][end]")?
+        )?,
+        datetime!(2021-01-02 13:05)
+    );
+    assert_eq!(
+        Time::parse("13:05 xx", &fd::parse("[hour]:[minute][ignore count:3][end]")?)?,
+        time!(13:05)
+    );
+    assert_eq!(
+        Date::parse_prefix("2021-01-02", &fd::parse("[year]-[month]-[day][end]")?)?,
+        (date!(2021 - 01 - 02), "")
+    );
+    assert_eq!(
+        Time::parse(
+            "worker-12|13:05",
+            &fd::parse("[ignore until:|]|[hour]:[minute][end]")?
+        )?,
+        time!(13:05)
+    );
+    assert_eq!(
+        Time::parse("|13:05", &fd::parse("[ignore until:|]|[hour]:[minute]")?)?,
+        time!(13:05)
+    );
+    assert_eq!(
+        Time::parse("abc|13:05", &fd::parse("[ignore count:3 until:|]|[hour]:[minute]")?)?,
+        time!(13:05)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_ignore_end_err() -> time::Result<()> {
+    assert_eq!(
+        invalid_input(Time::parse("13:0", &Located::new(fd::parse("[hour][ignore count:3]")?))),
+        Some((2, error::ParseFromDescription::InvalidComponent("ignore")))
+    );
+    assert_eq!(
+        invalid_input(Date::parse_prefix(
+            "2021-01-02T",
+            &Located::new(fd::parse("[year]-[month]-[day][end]")?)
+        )),
+        Some((10, error::ParseFromDescription::InvalidComponent("end")))
+    );
+    assert!(matches!(
+        Time::parse_prefix("13:05é", &fd::parse("[hour]:[minute][ignore count:1]")?),
+        invalid_literal!()
+    ));
+    assert_eq!(
+        invalid_input(Time::parse(
+            "13:05 xx",
+            &Located::new(fd::parse("[hour]:[minute][ignore until:|]")?)
+        )),
+        Some((5, error::ParseFromDescription::InvalidComponent("ignore")))
+    );
+    assert_eq!(
+        invalid_input(Time::parse(
+            "abcd|13:05",
+            &Located::new(fd::parse("[ignore count:3 until:|]|[hour]:[minute]")?)
+        )),
+        Some((0, error::ParseFromDescription::InvalidComponent("ignore")))
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_lenient() -> time::Result<()> {
+    let description = fd::parse("[year]-[month]-[day]T[hour]:[minute]:[second]Z")?;
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-01t10:00:00z", &Lenient::new(&description))?,
+        datetime!(2021-01-01 10:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "Jan  2 2021 at\t 13:05",
+            &Lenient::new(&fd::parse(
+                "[month repr:short] [day padding:none] [year] AT [hour]:[minute]"
+            )?)
+        )?,
+        datetime!(2021-01-02 13:05)
+    );
+    assert_eq!(
+        Time::parse(
+            "13 h 05",
+            &Lenient::new(&fd::parse("[hour] H [minute]")?).set_collapse_whitespace(false)
+        )?,
+        time!(13:05)
+    );
+    assert_eq!(
+        Time::parse(
+            "13   :05",
+            &Lenient::new(&fd::parse("[hour] :[minute]")?).set_ignore_case(false)
+        )?,
+        time!(13:05)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_day_ordinal_suffix() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "June 1st, 2021",
+            &fd::parse("[month repr:long] [day padding:none suffix:ordinal], [year]")?
+        )?,
+        date!(2021 - 06 - 01)
+    );
+    assert_eq!(
+        Date::parse("2021-06-22nd", &fd::parse("[year]-[month]-[day suffix:ordinal]")?)?,
+        date!(2021 - 06 - 22)
+    );
+    assert!(matches!(
+        invalid_input(Date::parse(
+            "June 1th, 2021",
+            &Located::new(fd::parse(
+                "[month repr:long] [day padding:none suffix:ordinal], [year]"
+            )?)
+        )),
+        Some((5, error::ParseFromDescription::InvalidComponent("day")))
+    ));
+    assert!(matches!(
+        invalid_input(Date::parse(
+            "2021-06-11",
+            &Located::new(fd::parse("[year]-[month]-[day suffix:ordinal]")?)
+        )),
+        Some((8, error::ParseFromDescription::InvalidComponent("day")))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_lenient_err() -> time::Result<()> {
+    // Whitespace in a literal must still match at least one whitespace character.
+    assert!(matches!(
+        invalid_input(Time::parse(
+            "13:05",
+            &Located::new(Lenient::new(fd::parse("[hour] :[minute]")?))
+        )),
+        Some((2, error::ParseFromDescription::InvalidLiteral { .. }))
+    ));
+    assert!(matches!(
+        invalid_input(Time::parse(
+            "13h05",
+            &Located::new(Lenient::new(fd::parse("[hour]H[minute]")?).set_ignore_case(false))
+        )),
+        Some((2, error::ParseFromDescription::InvalidLiteral { .. }))
+    ));
+    assert!(matches!(
+        invalid_input(Time::parse(
+            "13  :05",
+            &Located::new(
+                Lenient::new(fd::parse("[hour] :[minute]")?).set_collapse_whitespace(false)
+            )
+        )),
+        Some((2, error::ParseFromDescription::InvalidLiteral { .. }))
+    ));
+    // Non-ASCII characters are always compared exactly.
+    assert!(matches!(
+        invalid_input(Time::parse(
+            "13É05",
+            &Located::new(Lenient::new(fd::parse("[hour]é[minute]")?))
+        )),
+        Some((2, error::ParseFromDescription::InvalidLiteral { .. }))
+    ));
+    Ok(())
+}
+
+#[test]
+fn parse_prefix() -> time::Result<()> {
+    assert_eq!(
+        Time::parse_prefix("13:02:03 rest", &fd::parse("[hour]:[minute]:[second]")?)?,
+        (time!(13:02:03), " rest")
+    );
+    assert_eq!(
+        Date::parse_prefix("2021-01-02T", &fd::parse("[year]-[month]-[day]")?)?,
+        (date!(2021 - 01 - 02), "T")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_prefix(
+            "2021-01-02 03:04:05",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?
+        )?,
+        (datetime!(2021-01-02 03:04:05), "")
+    );
+    assert_eq!(
+        UtcOffset::parse_prefix("+01:02:03, next", &fd::parse("[offset_hour]:[offset_minute]")?)?,
+        (offset!(+1:02), ":03, next")
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix(
+            "2021-01-02 03:04:05 +06:07 ...",
+            &fd::parse(
+                "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour]:[offset_minute]"
+            )?
+        )?,
+        (datetime!(2021-01-02 03:04:05 +06:07), " ...")
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix("2021-01-02T03:04:05Z trailing", &Rfc3339)?,
+        (datetime!(2021-01-02 03:04:05 UTC), " trailing")
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix("2021-12-31T23:59:60Z trailing", &Rfc3339)?,
+        (
+            datetime!(2021-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 0)?),
+            " trailing"
+        )
+    );
+    assert_eq!(
+        Date::parse_prefix("2021-01-02T03:04:05Z trailing", &Rfc3339)?,
+        (date!(2021 - 01 - 02), " trailing")
+    );
+    assert_eq!(
+        Date::parse_prefix(
+            "2021-W01-6 é",
+            &fd::parse("[year base:iso_week]-W[week_number repr:iso]-[weekday repr:monday]")?
+        )?,
+        (date!(2021 - 01 - 09), " é")
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_prefix_err() -> time::Result<()> {
+    assert!(matches!(
+        Time::parse_prefix("13:", &Located::new(fd::parse("[hour]:[minute]")?)),
+        Err(error::Parse::InvalidInput(err)) if err.index() == 3
+    ));
+    assert!(matches!(
+        Date::parse_prefix("2021-02-30 rest", &fd::parse("[year]-[month]-[day]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "day"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_prefix("2021-01-02 03:04:05Z", &Rfc3339),
+        invalid_literal!()
+    ));
+    // The remainder must start on a character boundary.
+    assert!(matches!(
+        Date::parse_prefix(
+            "2021-01-02é",
+            &[
+                FormatItem::Compound(&fd::parse("[year]-[month]-[day]")?),
+                FormatItem::Literal(b"\xc3"),
+            ][..]
+        ),
+        invalid_literal!()
+    ));
+    Ok(())
+}
+
 #[test]
 fn parse_components() -> time::Result<()> {
     macro_rules! parse_component {
@@ -1117,7 +2061,8 @@ fn parse_first() -> time::Result<()> {
 
     // Ensure an empty slice is a no-op success.
     let mut parsed = Parsed::new();
-    let remaining_input = parsed.parse_item(b"2021-01-02", &FormatItem::First(&[]))?;
+    let item: &FormatItem<'_> = &FormatItem::First(&[]);
+    let remaining_input = parsed.parse_item(b"2021-01-02", item)?;
     assert_eq!(remaining_input, b"2021-01-02");
     assert!(parsed.year().is_none());
     assert!(parsed.month().is_none());
@@ -1153,3 +2098,77 @@ fn parse_first() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parse_owned() -> time::Result<()> {
+    let description = fd::parse_owned::<1>("[year]-[month]-[day] [hour]:[minute]")?;
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-06-01 13:05", &description)?,
+        datetime!(2021-06-01 13:05)
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-06-01",
+            &vec![
+                fd::parse_owned::<1>("[year]")?,
+                OwnedFormatItem::Literal(Box::new(*b"-")),
+                fd::parse_owned::<1>("[month]-[day]")?,
+            ]
+        )?,
+        date!(2021 - 06 - 01)
+    );
+    assert!(matches!(
+        Date::parse("2021-06-01", &fd::parse_owned::<1>("[year]-[month]")?),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    // Ensure a successful partial parse of an optional item *does not* mutate `parsed`.
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_owned_item(
+        b"2021-01",
+        &OwnedFormatItem::Optional(Box::new(fd::parse_owned::<1>(
+            "[year]-[month]-[day]",
+        )?)),
+    )?;
+    assert_eq!(remaining_input, b"2021-01");
+    assert!(parsed.year().is_none());
+
+    // Ensure the first successful item is used, and the first error is returned otherwise.
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_owned_item(
+        b"2021-01-02",
+        &OwnedFormatItem::First(Box::new([
+            fd::parse_owned::<1>("[period]")?,
+            fd::parse_owned::<1>("[year]-[month]-[day]")?,
+        ])),
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(2));
+    let err = Parsed::new()
+        .parse_owned_item(
+            b"2021-01-02",
+            &OwnedFormatItem::First(Box::new([
+                fd::parse_owned::<1>("[period]")?,
+                fd::parse_owned::<1>("x")?,
+            ])),
+        )
+        .expect_err("no item matches");
+    assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
+    assert_eq!(
+        Parsed::new().parse_owned_item(b"2021", &OwnedFormatItem::First(Box::new([])))?,
+        b"2021"
+    );
+
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_owned_items(
+        b"2021-01-02",
+        &[
+            fd::parse_owned::<1>("[year]-")?,
+            fd::parse_owned::<1>("[month]-[day]")?,
+        ],
+    )?;
+    assert!(remaining_input.is_empty());
+    assert_eq!(parsed.month(), Some(Month::January));
+
+    Ok(())
+}
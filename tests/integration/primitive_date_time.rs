@@ -136,6 +136,138 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn to_julian_date() {
+    assert_eq!(datetime!(-4713-11-24 12:00).to_julian_date(), 0.);
+    assert_eq!(datetime!(-4713-11-24 0:00).to_julian_date(), -0.5);
+    assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.);
+    assert_eq!(datetime!(2000-01-01 18:00).to_julian_date(), 2_451_545.25);
+    assert_eq!(datetime!(2000-01-01 0:00).to_julian_date(), 2_451_544.5);
+}
+
+#[test]
+fn from_julian_date() -> time::Result<()> {
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(0.),
+        Ok(datetime!(-4713-11-24 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(-0.25),
+        Ok(datetime!(-4713-11-24 6:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(2_451_544.5),
+        Ok(datetime!(2000-01-01 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(2_451_545.5),
+        Ok(datetime!(2000-01-02 0:00))
+    );
+    assert!(PrimitiveDateTime::from_julian_date(f64::NAN).is_err());
+    assert!(PrimitiveDateTime::from_julian_date(f64::INFINITY).is_err());
+    assert!(PrimitiveDateTime::from_julian_date(1e20).is_err());
+    assert!(PrimitiveDateTime::from_julian_date(-1e20).is_err());
+
+    // The result is rounded to the nearest nanosecond, with the limited precision of `f64`.
+    let datetime = datetime!(2022-05-06 7:08:09.123_456);
+    let round_trip = PrimitiveDateTime::from_julian_date(datetime.to_julian_date())?;
+    assert!((round_trip - datetime).abs() < 50.microseconds());
+
+    Ok(())
+}
+
+#[test]
+fn to_modified_julian_date() {
+    assert_eq!(datetime!(1858-11-17 0:00).to_modified_julian_date(), 0.);
+    assert_eq!(datetime!(1858-11-16 12:00).to_modified_julian_date(), -0.5);
+    assert_eq!(
+        datetime!(2000-01-01 12:00).to_modified_julian_date(),
+        51_544.5
+    );
+}
+
+#[test]
+fn from_modified_julian_date() -> time::Result<()> {
+    assert_eq!(
+        PrimitiveDateTime::from_modified_julian_date(0.),
+        Ok(datetime!(1858-11-17 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_modified_julian_date(-0.5),
+        Ok(datetime!(1858-11-16 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_modified_julian_date(51_544.5),
+        Ok(datetime!(2000-01-01 12:00))
+    );
+    assert!(PrimitiveDateTime::from_modified_julian_date(f64::NEG_INFINITY).is_err());
+
+    let datetime = datetime!(2022-05-06 7:08:09.123_456);
+    let round_trip =
+        PrimitiveDateTime::from_modified_julian_date(datetime.to_modified_julian_date())?;
+    assert!((round_trip - datetime).abs() < 2.microseconds());
+
+    Ok(())
+}
+
+#[test]
+fn from_excel_1900_serial() {
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1900_serial(0.),
+        Ok(datetime!(1899-12-31 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1900_serial(59.5),
+        Ok(datetime!(1900-02-28 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1900_serial(60.),
+        Ok(datetime!(1900-03-01 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1900_serial(61.),
+        Ok(datetime!(1900-03-01 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1900_serial(2_958_465.5),
+        Ok(datetime!(9999-12-31 12:00))
+    );
+    assert!(PrimitiveDateTime::from_excel_1900_serial(f64::NAN).is_err());
+    assert!(PrimitiveDateTime::from_excel_1900_serial(f64::INFINITY).is_err());
+}
+
+#[test]
+fn excel_1900_serial() {
+    assert_eq!(datetime!(1899-12-31 0:00).excel_1900_serial(), 0.);
+    assert_eq!(datetime!(1900-02-28 12:00).excel_1900_serial(), 59.5);
+    assert_eq!(datetime!(1900-03-01 0:00).excel_1900_serial(), 61.);
+    assert_eq!(datetime!(2022-01-01 6:00).excel_1900_serial(), 44_562.25);
+}
+
+#[test]
+fn from_excel_1904_serial() {
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1904_serial(0.),
+        Ok(datetime!(1904-01-01 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1904_serial(-0.5),
+        Ok(datetime!(1903-12-31 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_excel_1904_serial(43_100.25),
+        Ok(datetime!(2022-01-01 6:00))
+    );
+    assert!(PrimitiveDateTime::from_excel_1904_serial(f64::NAN).is_err());
+}
+
+#[test]
+fn excel_1904_serial() {
+    assert_eq!(datetime!(1904-01-01 0:00).excel_1904_serial(), 0.);
+    assert_eq!(datetime!(1903-12-31 12:00).excel_1904_serial(), -0.5);
+    assert_eq!(datetime!(2022-01-01 6:00).excel_1904_serial(), 43_100.25);
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03).as_hms(), (1, 2, 3));
@@ -1,4 +1,5 @@
-use quickcheck_dep::{quickcheck, Arbitrary, TestResult};
+use quickcheck_dep::{quickcheck, Arbitrary, Gen, TestResult};
+use time::format_description::parse_owned;
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 macro_rules! test_shrink {
@@ -28,7 +29,87 @@ macro_rules! test_shrink {
     (@min_or_zero $min:literal) => { $min };
 }
 
+/// A version 2 format description, assembled from fragments that are frequently valid.
+#[derive(Debug, Clone)]
+struct Description(String);
+
+impl Description {
+    /// Fragments that may appear anywhere in a description.
+    const FRAGMENTS: &'static [&'static str] = &[
+        "-",
+        ":",
+        " ",
+        "T",
+        "é",
+        r"\\",
+        r"\[",
+        r"\]",
+        "[year]",
+        "[year repr:last_two base:iso_week sign:mandatory]",
+        "[month repr:short case_sensitive:false]",
+        "[month padding:space]",
+        "[day padding:none]",
+        "[day suffix:ordinal]",
+        "[ordinal]",
+        "[weekday repr:sunday one_indexed:false]",
+        "[week_number repr:monday]",
+        "[hour repr:12]",
+        "[minute]",
+        "[period case:lower]",
+        "[second padding:zero]",
+        "[subsecond digits:3]",
+        "[offset_hour sign:mandatory]",
+        "[offset_minute]",
+        "[offset_second padding:space]",
+        "[unix_timestamp precision:millisecond sign:mandatory]",
+        "[ignore count:3]",
+        "[end]",
+        "[unknown]",
+    ];
+
+    /// Append a sequence of fragments and nested items, up to the provided depth.
+    fn append(g: &mut Gen, depth: u8, description: &mut String) {
+        let len = usize::arbitrary(g) % 6;
+        for _ in 0..len {
+            match u8::arbitrary(g) % 8 {
+                0 if depth > 0 => {
+                    description.push_str("[optional [");
+                    Self::append(g, depth - 1, description);
+                    description.push_str("]]");
+                }
+                1 if depth > 0 => {
+                    description.push_str("[first");
+                    for _ in 0..usize::arbitrary(g) % 3 {
+                        description.push_str(" [");
+                        Self::append(g, depth - 1, description);
+                        description.push(']');
+                    }
+                    description.push(']');
+                }
+                _ => description.push_str(g.choose(Self::FRAGMENTS).unwrap_or(&"")),
+            }
+        }
+    }
+}
+
+impl Arbitrary for Description {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut description = String::new();
+        Self::append(g, 2, &mut description);
+        Self(description)
+    }
+}
+
 quickcheck! {
+    fn format_description_display_roundtrip(description: Description) -> TestResult {
+        parse_owned::<2>(&description.0).map_or_else(
+            |_| TestResult::discard(),
+            |parsed| TestResult::from_bool(
+                parse_owned::<2>(&parsed.to_string()).as_ref() == Ok(&parsed)
+            ),
+        )
+    }
+
     fn date_yo_roundtrip(d: Date) -> bool {
         Date::from_ordinal_date(d.year(), d.ordinal()) == Ok(d)
     }
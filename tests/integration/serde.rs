@@ -338,6 +338,34 @@ fn offset_date_time() {
     );
 }
 
+#[test]
+fn offset_date_time_leap_second() -> time::Result<()> {
+    let leap_second = date!(2016 - 12 - 31)
+        .with_time(Time::from_leap_second(23, 59, 500_000_000)?)
+        .assume_offset(offset!(+1));
+    assert_tokens(
+        &leap_second.compact(),
+        &[
+            Token::Tuple { len: 9 },
+            Token::I32(2016),
+            Token::U16(366),
+            Token::U8(23),
+            Token::U8(59),
+            Token::U8(60),
+            Token::U32(500_000_000),
+            Token::I8(1),
+            Token::I8(0),
+            Token::I8(0),
+            Token::TupleEnd,
+        ],
+    );
+    assert_tokens(
+        &leap_second.readable(),
+        &[Token::BorrowedStr("2016-12-31 23:59:60.5 +01:00:00")],
+    );
+    Ok(())
+}
+
 #[test]
 fn offset_date_time_error() {
     assert_de_tokens_error::<Readable<OffsetDateTime>>(
@@ -1,7 +1,8 @@
+use serde::de::value::{Error, I128Deserializer, U64Deserializer};
 use serde::{Deserialize, Serialize};
 use serde_test::{assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Token};
 use time::macros::datetime;
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime};
 
 #[test]
 fn success() {
@@ -223,3 +224,300 @@ fn timestamp_error() {
          -31619087596800..=31494784780799",
     );
 }
+
+#[test]
+fn timestamp_milliseconds() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Millis(
+        #[serde(with = "time::serde::timestamp::milliseconds")] OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::milliseconds::option")] Option<OffsetDateTime>,
+        #[serde(with = "time::serde::timestamp::milliseconds::option")] Option<OffsetDateTime>,
+    );
+
+    assert_tokens(
+        &Millis(
+            datetime!(2021-01-02 03:04:05.678 UTC),
+            Some(datetime!(1969-12-31 23:59:59.999 UTC)),
+            None,
+        ),
+        &[
+            Token::TupleStruct {
+                name: "Millis",
+                len: 3,
+            },
+            Token::I64(1_609_556_645_678),
+            Token::Some,
+            Token::I64(-1),
+            Token::None,
+            Token::TupleStructEnd,
+        ],
+    );
+}
+
+#[test]
+fn timestamp_milliseconds_error() {
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Millis(#[serde(with = "time::serde::timestamp::milliseconds")] OffsetDateTime);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct OptMillis(
+        #[serde(with = "time::serde::timestamp::milliseconds::option")] Option<OffsetDateTime>,
+    );
+
+    assert_de_tokens_error::<Millis>(
+        &[Token::Bool(false)],
+        "invalid type: boolean `false`, expected i64",
+    );
+    assert_de_tokens_error::<OptMillis>(
+        &[Token::Some, Token::Bool(false)],
+        "invalid type: boolean `false`, expected i64",
+    );
+    assert_de_tokens_error::<Millis>(
+        &[Token::I64(i64::MAX)],
+        "invalid value: integer `9223372036854775807`, expected a value in the range \
+         -31619087596800000..=31494784780799999",
+    );
+    assert_de_tokens_error::<OptMillis>(
+        &[Token::Some, Token::I64(i64::MIN)],
+        "invalid value: integer `-9223372036854775808`, expected a value in the range \
+         -31619087596800000..=31494784780799999",
+    );
+}
+
+#[test]
+fn timestamp_microseconds() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Micros(
+        #[serde(with = "time::serde::timestamp::microseconds")] OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::microseconds::option")] Option<OffsetDateTime>,
+        #[serde(with = "time::serde::timestamp::microseconds::option")] Option<OffsetDateTime>,
+    );
+
+    assert_tokens(
+        &Micros(
+            datetime!(2021-01-02 03:04:05.678_901 UTC),
+            Some(datetime!(1969-12-31 23:59:59.999_999 UTC)),
+            None,
+        ),
+        &[
+            Token::TupleStruct {
+                name: "Micros",
+                len: 3,
+            },
+            Token::I64(1_609_556_645_678_901),
+            Token::Some,
+            Token::I64(-1),
+            Token::None,
+            Token::TupleStructEnd,
+        ],
+    );
+    assert_eq!(
+        time::serde::timestamp::microseconds::deserialize(I128Deserializer::<Error>::new(-1)),
+        Ok(datetime!(1969-12-31 23:59:59.999_999 UTC))
+    );
+    assert_eq!(
+        time::serde::timestamp::microseconds::deserialize(U64Deserializer::<Error>::new(1)),
+        Ok(datetime!(1970-01-01 0:00:00.000_001 UTC))
+    );
+}
+
+#[test]
+fn timestamp_microseconds_error() {
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Micros(#[serde(with = "time::serde::timestamp::microseconds")] OffsetDateTime);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct OptMicros(
+        #[serde(with = "time::serde::timestamp::microseconds::option")] Option<OffsetDateTime>,
+    );
+
+    assert_de_tokens_error::<Micros>(
+        &[Token::Bool(false)],
+        "invalid type: boolean `false`, expected a Unix timestamp",
+    );
+    assert_de_tokens_error::<OptMicros>(
+        &[Token::Some, Token::Bool(false)],
+        "invalid type: boolean `false`, expected a Unix timestamp",
+    );
+}
+
+#[test]
+fn timestamp_nanoseconds() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Nanos(
+        #[serde(with = "time::serde::timestamp::nanoseconds")] OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::nanoseconds::option")] Option<OffsetDateTime>,
+        #[serde(with = "time::serde::timestamp::nanoseconds::option")] Option<OffsetDateTime>,
+    );
+
+    assert_tokens(
+        &Nanos(
+            datetime!(2021-01-02 03:04:05.678_901_234 UTC),
+            Some(datetime!(1969-12-31 23:59:59.999_999_999 UTC)),
+            None,
+        ),
+        &[
+            Token::TupleStruct {
+                name: "Nanos",
+                len: 3,
+            },
+            Token::I64(1_609_556_645_678_901_234),
+            Token::Some,
+            Token::I64(-1),
+            Token::None,
+            Token::TupleStructEnd,
+        ],
+    );
+    assert_eq!(
+        time::serde::timestamp::nanoseconds::deserialize(I128Deserializer::<Error>::new(
+            10_413_792_000_000_000_000
+        )),
+        Ok(datetime!(2300-01-01 0:00 UTC))
+    );
+    assert_eq!(
+        time::serde::timestamp::nanoseconds::deserialize(U64Deserializer::<Error>::new(1)),
+        Ok(datetime!(1970-01-01 0:00:00.000_000_001 UTC))
+    );
+}
+
+#[test]
+fn timestamp_nanoseconds_error() {
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Nanos(#[serde(with = "time::serde::timestamp::nanoseconds")] OffsetDateTime);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct OptNanos(
+        #[serde(with = "time::serde::timestamp::nanoseconds::option")] Option<OffsetDateTime>,
+    );
+
+    assert_de_tokens_error::<Nanos>(
+        &[Token::Bool(false)],
+        "invalid type: boolean `false`, expected a Unix timestamp",
+    );
+    assert_de_tokens_error::<OptNanos>(
+        &[Token::Some, Token::Bool(false)],
+        "invalid type: boolean `false`, expected a Unix timestamp",
+    );
+}
+
+#[test]
+fn timestamp_seconds_f64() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Float(
+        #[serde(with = "time::serde::timestamp::seconds_f64")] OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::seconds_f64::option")] Option<OffsetDateTime>,
+        #[serde(with = "time::serde::timestamp::seconds_f64::option")] Option<OffsetDateTime>,
+    );
+
+    assert_tokens(
+        &Float(
+            datetime!(2021-01-02 03:04:05.5 UTC),
+            Some(datetime!(1969-12-31 23:59:59.25 UTC)),
+            None,
+        ),
+        &[
+            Token::TupleStruct {
+                name: "Float",
+                len: 3,
+            },
+            Token::F64(1_609_556_645.5),
+            Token::Some,
+            Token::F64(-0.75),
+            Token::None,
+            Token::TupleStructEnd,
+        ],
+    );
+}
+
+#[test]
+fn timestamp_seconds_f64_error() {
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Float(#[serde(with = "time::serde::timestamp::seconds_f64")] OffsetDateTime);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct OptFloat(
+        #[serde(with = "time::serde::timestamp::seconds_f64::option")] Option<OffsetDateTime>,
+    );
+
+    assert_de_tokens_error::<Float>(
+        &[Token::Bool(false)],
+        "invalid type: boolean `false`, expected f64",
+    );
+    assert_de_tokens_error::<OptFloat>(
+        &[Token::Some, Token::Bool(false)],
+        "invalid type: boolean `false`, expected f64",
+    );
+    assert_de_tokens_error::<Float>(
+        &[Token::F64(1e20)],
+        "invalid value: floating point `100000000000000000000.0`, expected a value in the range \
+         -31619087596800..=31494784780799",
+    );
+    assert_de_tokens_error::<OptFloat>(
+        &[Token::Some, Token::F64(f64::NAN)],
+        "invalid value: floating point `NaN`, expected a value in the range \
+         -31619087596800..=31494784780799",
+    );
+}
+
+#[test]
+fn iso8601_duration() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Iso8601Duration(
+        #[serde(with = "time::serde::iso8601_duration")] Duration,
+        #[serde(with = "time::serde::iso8601_duration::option")] Option<Duration>,
+        #[serde(with = "time::serde::iso8601_duration::option")] Option<Duration>,
+    );
+
+    assert_tokens(
+        &Iso8601Duration(
+            Duration::new(3 * 86_400 + 4 * 3_600, 500_000_000),
+            Some(-Duration::WEEK),
+            None,
+        ),
+        &[
+            Token::TupleStruct {
+                name: "Iso8601Duration",
+                len: 3,
+            },
+            Token::Str("P3DT4H0.5S"),
+            Token::Some,
+            Token::Str("-P1W"),
+            Token::None,
+            Token::TupleStructEnd,
+        ],
+    );
+}
+
+#[test]
+fn iso8601_duration_error() {
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Iso8601Duration(#[serde(with = "time::serde::iso8601_duration")] Duration);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct OptIso8601Duration(
+        #[serde(with = "time::serde::iso8601_duration::option")] Option<Duration>,
+    );
+
+    assert_de_tokens_error::<Iso8601Duration>(
+        &[Token::Bool(false)],
+        "invalid type: boolean `false`, expected an ISO 8601-formatted `Duration`",
+    );
+    assert_de_tokens_error::<Iso8601Duration>(
+        &[Token::Str("P1Y")],
+        "the 'years' component could not be parsed",
+    );
+    assert_de_tokens_error::<OptIso8601Duration>(
+        &[Token::Some, Token::Str("PT")],
+        "a character literal was not valid",
+    );
+}
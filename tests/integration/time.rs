@@ -63,6 +63,19 @@ fn from_hms_nano() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_leap_second() -> Result<()> {
+    let time = Time::from_leap_second(23, 59, 5)?;
+    assert_eq!(time.as_hms_nano(), (23, 59, 60, 5));
+    assert!(time.is_leap_second());
+    assert!(!time!(23:59:59).is_leap_second());
+
+    assert!(Time::from_leap_second(24, 59, 0).is_err());
+    assert!(Time::from_leap_second(23, 60, 0).is_err());
+    assert!(Time::from_leap_second(23, 59, 1_000_000_000).is_err());
+    Ok(())
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(time!(1:02:03).as_hms(), (1, 2, 3));
@@ -0,0 +1,191 @@
+use core::convert::TryFrom;
+
+use time::ext::NumericalDuration;
+use time::leap_seconds::LeapSecondTable;
+use time::macros::datetime;
+use time::time_scale::{Gps, Tai, Tt};
+use time::{error, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+fn leap_second(datetime: OffsetDateTime) -> time::Result<OffsetDateTime> {
+    Ok(datetime.replace_time(Time::from_leap_second(23, 59, 0)?))
+}
+
+#[test]
+fn tai_round_trip() -> time::Result<()> {
+    for &utc in &[
+        datetime!(1960-01-01 0:00 UTC),
+        datetime!(1972-06-30 23:59:59.5 UTC),
+        leap_second(datetime!(1972-06-30 0:00 UTC))?,
+        datetime!(1972-07-01 0:00 UTC),
+        datetime!(2016-12-31 23:59:59 UTC),
+        datetime!(2016-12-31 0:00 UTC).replace_time(Time::from_leap_second(23, 59, 999_999_999)?),
+        datetime!(2017-01-01 0:00 UTC),
+        datetime!(2022-05-06 7:08:09.123_456_789 UTC),
+    ] {
+        assert_eq!(Tai::from_utc(utc)?.to_utc()?, utc);
+        assert_eq!(Gps::from_utc(utc)?.to_utc()?, utc);
+        assert_eq!(Tt::from_utc(utc)?.to_utc()?, utc);
+    }
+    Ok(())
+}
+
+#[test]
+fn tai() -> time::Result<()> {
+    assert_eq!(
+        Tai::from_utc(datetime!(2016-12-31 23:59:59 UTC))?.datetime(),
+        datetime!(2017-01-01 0:00:35)
+    );
+    assert_eq!(
+        Tai::from_utc(leap_second(datetime!(2016-12-31 0:00 UTC))?)?.datetime(),
+        datetime!(2017-01-01 0:00:36)
+    );
+    assert_eq!(
+        Tai::from_utc(datetime!(2017-01-01 0:00 UTC))?.datetime(),
+        datetime!(2017-01-01 0:00:37)
+    );
+    assert_eq!(
+        Tai::from_utc(datetime!(2017-01-01 1:00 +1))?.datetime(),
+        datetime!(2017-01-01 0:00:37)
+    );
+    assert_eq!(
+        Tai::new(datetime!(2017-01-01 0:00:36)).to_utc()?,
+        leap_second(datetime!(2016-12-31 0:00 UTC))?
+    );
+
+    let empty = LeapSecondTable::new(&[]).expect("empty table is sorted");
+    assert_eq!(
+        Tai::from_utc_with_table(datetime!(2017-01-01 0:00 UTC), empty)?.datetime(),
+        datetime!(2017-01-01 0:00)
+    );
+    assert_eq!(
+        Tai::new(datetime!(2017-01-01 0:00)).to_utc_with_table(empty)?,
+        datetime!(2017-01-01 0:00 UTC)
+    );
+    Ok(())
+}
+
+#[test]
+fn gps() -> time::Result<()> {
+    assert_eq!(
+        Gps::from_utc(datetime!(1980-01-06 0:00 UTC))?.datetime(),
+        datetime!(1980-01-06 0:00)
+    );
+    assert_eq!(
+        Tai::try_from(Gps::new(datetime!(2020-01-01 0:00)))?.datetime(),
+        datetime!(2020-01-01 0:00:19)
+    );
+    assert_eq!(
+        Gps::try_from(Tai::new(datetime!(2020-01-01 0:00:19)))?.datetime(),
+        datetime!(2020-01-01 0:00)
+    );
+    assert_eq!(
+        Gps::new(datetime!(2020-01-01 0:00:18)).to_utc_with_table(LeapSecondTable::BUILTIN)?,
+        datetime!(2020-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        Gps::from_utc_with_table(datetime!(2020-01-01 0:00 UTC), LeapSecondTable::BUILTIN)?,
+        Gps::new(datetime!(2020-01-01 0:00:18))
+    );
+    Ok(())
+}
+
+#[test]
+fn tt() -> time::Result<()> {
+    assert_eq!(
+        Tt::from_utc(datetime!(2000-01-01 11:58:55.816 UTC))?.datetime(),
+        datetime!(2000-01-01 12:00)
+    );
+    assert_eq!(
+        Tt::try_from(Tai::new(datetime!(2000-01-01 0:00)))?.datetime(),
+        datetime!(2000-01-01 0:00:32.184)
+    );
+    assert_eq!(
+        Tai::try_from(Tt::new(datetime!(2000-01-01 0:00:32.184)))?.datetime(),
+        datetime!(2000-01-01 0:00)
+    );
+    assert_eq!(
+        Tt::new(datetime!(2000-01-01 12:00)).to_utc_with_table(LeapSecondTable::BUILTIN)?,
+        datetime!(2000-01-01 11:58:55.816 UTC)
+    );
+    assert_eq!(
+        Tt::from_utc_with_table(datetime!(2000-01-01 0:00 UTC), LeapSecondTable::BUILTIN)?,
+        Tt::new(datetime!(2000-01-01 0:01:04.184))
+    );
+    Ok(())
+}
+
+#[test]
+fn gps_week() -> time::Result<()> {
+    assert_eq!(Gps::EPOCH.datetime(), datetime!(1980-01-06 0:00));
+    assert_eq!(Gps::EPOCH.to_week_and_time_of_week(), (0, Duration::ZERO));
+    assert_eq!(
+        Gps::new(datetime!(1999-08-21 23:59:59.999_999_999)).to_week_and_time_of_week(),
+        (1_023, 604_799_999_999_999.nanoseconds())
+    );
+    assert_eq!(
+        Gps::new(datetime!(1999-08-22 0:00)).to_week_and_time_of_week(),
+        (1_024, Duration::ZERO)
+    );
+    assert_eq!(
+        Gps::new(datetime!(1980-01-05 23:59:59.5)).to_week_and_time_of_week(),
+        (-1, 604_799.5.seconds())
+    );
+
+    assert_eq!(
+        Gps::from_week_and_time_of_week(1_024, Duration::ZERO)?,
+        Gps::new(datetime!(1999-08-22 0:00))
+    );
+    assert_eq!(
+        Gps::from_week_and_time_of_week(-1, 604_799.5.seconds())?,
+        Gps::new(datetime!(1980-01-05 23:59:59.5))
+    );
+    assert_eq!(
+        Gps::from_week_and_time_of_week(0, (-1).nanoseconds()).map_err(|err| err.name()),
+        Err("time_of_week")
+    );
+    assert_eq!(
+        Gps::from_week_and_time_of_week(0, Duration::WEEK).map_err(|err| err.name()),
+        Err("time_of_week")
+    );
+    assert_eq!(
+        Gps::from_week_and_time_of_week(i32::MAX, Duration::ZERO).map_err(|err| err.name()),
+        Err("week")
+    );
+    assert_eq!(
+        Gps::from_week_and_time_of_week(i32::MIN, Duration::ZERO).map_err(|err| err.name()),
+        Err("week")
+    );
+    Ok(())
+}
+
+#[test]
+fn out_of_range() {
+    let max = PrimitiveDateTime::MAX.assume_utc();
+    assert_eq!(Tai::from_utc(max), Err(error::ConversionRange));
+    assert_eq!(Gps::from_utc(max), Err(error::ConversionRange));
+    assert_eq!(Tt::from_utc(max), Err(error::ConversionRange));
+    assert_eq!(
+        Tai::new(PrimitiveDateTime::MIN).to_utc(),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        Tt::new(PrimitiveDateTime::MIN).to_utc(),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        Gps::try_from(Tai::new(PrimitiveDateTime::MIN)),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        Tai::try_from(Gps::new(PrimitiveDateTime::MAX)),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        Tt::try_from(Tai::new(PrimitiveDateTime::MAX)),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        Tai::try_from(Tt::new(PrimitiveDateTime::MIN)),
+        Err(error::ConversionRange)
+    );
+}
@@ -20,6 +20,9 @@ pub(crate) enum Component {
     OffsetHour(modifier::OffsetHour),
     OffsetMinute(modifier::OffsetMinute),
     OffsetSecond(modifier::OffsetSecond),
+    UnixTimestamp(modifier::UnixTimestamp),
+    Ignore(modifier::Ignore),
+    End(modifier::End),
 }
 
 impl ToTokens for Component {
@@ -40,6 +43,9 @@ impl ToTokens for Component {
                 Self::OffsetHour(modifier) => quote! { OffsetHour(#(modifier)) },
                 Self::OffsetMinute(modifier) => quote! { OffsetMinute(#(modifier)) },
                 Self::OffsetSecond(modifier) => quote! { OffsetSecond(#(modifier)) },
+                Self::UnixTimestamp(modifier) => quote! { UnixTimestamp(#(modifier)) },
+                Self::Ignore(modifier) => quote! { Ignore(#(modifier)) },
+                Self::End(modifier) => quote! { End(#(modifier)) },
             })
         }
     }
@@ -60,6 +66,9 @@ pub(crate) enum NakedComponent {
     OffsetHour,
     OffsetMinute,
     OffsetSecond,
+    UnixTimestamp,
+    Ignore,
+    End,
 }
 
 impl NakedComponent {
@@ -82,6 +91,9 @@ impl NakedComponent {
             b"offset_hour" => Ok(Self::OffsetHour),
             b"offset_minute" => Ok(Self::OffsetMinute),
             b"offset_second" => Ok(Self::OffsetSecond),
+            b"unix_timestamp" => Ok(Self::UnixTimestamp),
+            b"ignore" => Ok(Self::Ignore),
+            b"end" => Ok(Self::End),
             b"" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -92,10 +104,15 @@ impl NakedComponent {
         }
     }
 
-    pub(crate) fn attach_modifiers(self, modifiers: Modifiers) -> Component {
-        match self {
+    pub(crate) fn attach_modifiers(
+        self,
+        modifiers: Modifiers,
+        component_index: usize,
+    ) -> Result<Component, InvalidFormatDescription> {
+        Ok(match self {
             Self::Day => Component::Day(modifier::Day {
                 padding: modifiers.padding.unwrap_or_default(),
+                ordinal_suffix: modifiers.day_has_ordinal_suffix.unwrap_or_default(),
             }),
             Self::Month => Component::Month(modifier::Month {
                 padding: modifiers.padding.unwrap_or_default(),
@@ -147,6 +164,20 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
-        }
+            Self::UnixTimestamp => Component::UnixTimestamp(modifier::UnixTimestamp {
+                precision: modifiers.unix_timestamp_precision.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+            }),
+            Self::Ignore => match (modifiers.ignore_count, modifiers.ignore_until) {
+                (None, None) => {
+                    return Err(InvalidFormatDescription::MissingRequiredModifier {
+                        name: "count",
+                        index: component_index,
+                    });
+                }
+                (count, until) => Component::Ignore(modifier::Ignore { count, until }),
+            },
+            Self::End => Component::End(modifier::End),
+        })
     }
 }
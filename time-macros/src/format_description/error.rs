@@ -1,10 +1,14 @@
 use std::fmt;
 
+#[derive(Debug)]
 pub(crate) enum InvalidFormatDescription {
     UnclosedOpeningBracket { index: usize },
     InvalidComponentName { name: String, index: usize },
     InvalidModifier { value: String, index: usize },
     MissingComponentName { index: usize },
+    UnsupportedSpecifier { specifier: String, index: usize },
+    Expected { what: &'static str, index: usize },
+    MissingRequiredModifier { name: &'static str, index: usize },
 }
 
 impl fmt::Display for InvalidFormatDescription {
@@ -26,6 +30,17 @@ impl fmt::Display for InvalidFormatDescription {
             MissingComponentName { index } => {
                 write!(f, "missing component name at byte index {}", index)
             }
+            UnsupportedSpecifier { specifier, index } => write!(
+                f,
+                "unsupported specifier `{}` at byte index {}",
+                specifier, index
+            ),
+            Expected { what, index } => write!(f, "expected {} at byte index {}", what, index),
+            MissingRequiredModifier { name, index } => write!(
+                f,
+                "missing required modifier `{}` for component at byte index {}",
+                name, index
+            ),
         }
     }
 }
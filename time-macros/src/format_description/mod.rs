@@ -2,12 +2,16 @@ mod component;
 pub(crate) mod error;
 pub(crate) mod modifier;
 pub(crate) mod parse;
+pub(crate) mod strftime;
 
-use proc_macro::{Literal, TokenStream};
+use std::iter::Peekable;
+
+use proc_macro::{token_stream, Literal, TokenStream, TokenTree};
 
 pub(crate) use self::component::Component;
 pub(crate) use self::parse::parse;
 use crate::to_tokens::ToTokens;
+use crate::{helpers, Error};
 
 mod helper {
     #[must_use = "This does not modify the original slice."]
@@ -21,11 +25,31 @@ mod helper {
     }
 }
 
+pub(crate) fn parse_version(tokens: &mut Peekable<token_stream::IntoIter>) -> Result<u8, Error> {
+    match tokens.peek() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "version" => drop(tokens.next()),
+        _ => return Ok(1),
+    }
+    helpers::consume_punct('=', tokens)?;
+    let (span, version) = helpers::consume_number::<u8>("version", tokens)?;
+    if !(1..=2).contains(&version) {
+        return Err(Error::Custom {
+            message: "invalid format description version".into(),
+            span_start: Some(span),
+            span_end: Some(span),
+        });
+    }
+    helpers::consume_punct(',', tokens)?;
+    Ok(version)
+}
+
 #[allow(single_use_lifetimes)] // false positive
 #[allow(variant_size_differences)]
 pub(crate) enum FormatItem<'a> {
     Literal(&'a [u8]),
     Component(Component),
+    Optional(Vec<Self>),
+    First(Vec<Vec<Self>>),
 }
 
 impl ToTokens for FormatItem<'_> {
@@ -34,7 +58,27 @@ impl ToTokens for FormatItem<'_> {
             ::time::format_description::FormatItem::#(match self {
                 FormatItem::Literal(bytes) => quote! { Literal(#(Literal::byte_string(bytes))) },
                 FormatItem::Component(component) => quote! { Component(#(component)) },
+                FormatItem::Optional(items) => quote! { Optional(&#(compound(items))) },
+                FormatItem::First(sequences) => quote! {
+                    First(&[#(
+                        sequences
+                            .into_iter()
+                            .map(|items| quote! { #(compound(items)), })
+                            .collect::<TokenStream>()
+                    )])
+                },
             })
         }
     }
 }
+
+fn compound(items: Vec<FormatItem<'_>>) -> TokenStream {
+    quote! {
+        ::time::format_description::FormatItem::Compound(&[#(
+            items
+                .into_iter()
+                .map(|item| quote! { #(item), })
+                .collect::<TokenStream>()
+        )])
+    }
+}
@@ -1,4 +1,5 @@
 use core::mem;
+use core::num::NonZeroU16;
 
 use proc_macro::TokenStream;
 
@@ -59,6 +60,7 @@ macro_rules! to_tokens {
 to_tokens! {
     pub(crate) struct Day {
         pub(crate) padding: Padding,
+        pub(crate) ordinal_suffix: bool,
     }
 }
 
@@ -199,6 +201,47 @@ to_tokens! {
 }
 
 to_tokens! {
+    pub(crate) enum UnixTimestampPrecision {
+        Second,
+        Millisecond,
+        Microsecond,
+        Nanosecond,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct UnixTimestamp {
+        pub(crate) precision: UnixTimestampPrecision,
+        pub(crate) sign_is_mandatory: bool,
+    }
+}
+
+pub(crate) struct Ignore {
+    pub(crate) count: Option<NonZeroU16>,
+    pub(crate) until: Option<u8>,
+}
+
+impl ToTokens for Ignore {
+    fn into_token_stream(self) -> TokenStream {
+        quote! {{
+            let mut value = ::time::format_description::modifier::Ignore::until(#(0_u8));
+            value.count = #(self.count);
+            value.until = #(self.until);
+            value
+        }}
+    }
+}
+
+pub(crate) struct End;
+
+impl ToTokens for End {
+    fn into_token_stream(self) -> TokenStream {
+        quote! { ::time::format_description::modifier::End::default() }
+    }
+}
+
+to_tokens! {
+    #[derive(Clone, Copy)]
     pub(crate) enum Padding {
         Space,
         Zero,
@@ -217,7 +260,10 @@ macro_rules! impl_default {
 }
 
 impl_default! {
-    Day => Self { padding: Padding::default() };
+    Day => Self {
+        padding: Padding::default(),
+        ordinal_suffix: false,
+    };
     MonthRepr => Self::Numerical;
     Month => Self {
         padding: Padding::default(),
@@ -258,6 +304,11 @@ impl_default! {
     };
     OffsetMinute => Self { padding: Padding::default() };
     OffsetSecond => Self { padding: Padding::default() };
+    UnixTimestampPrecision => Self::Second;
+    UnixTimestamp => Self {
+        precision: UnixTimestampPrecision::default(),
+        sign_is_mandatory: false,
+    };
     Padding => Self::Zero;
 }
 
@@ -275,6 +326,10 @@ pub(crate) struct Modifiers {
     pub(crate) year_is_iso_week_based: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
     pub(crate) case_sensitive: Option<bool>,
+    pub(crate) unix_timestamp_precision: Option<UnixTimestampPrecision>,
+    pub(crate) ignore_count: Option<NonZeroU16>,
+    pub(crate) ignore_until: Option<u8>,
+    pub(crate) day_has_ordinal_suffix: Option<bool>,
 }
 
 impl Modifiers {
@@ -319,6 +374,8 @@ impl Modifiers {
                     | b"offset_second" | b"ordinal" | b"second" | b"week_number" | b"year",
                     b"padding:none",
                 ) => modifiers.padding = Some(Padding::None),
+                (b"day", b"suffix:none") => modifiers.day_has_ordinal_suffix = Some(false),
+                (b"day", b"suffix:ordinal") => modifiers.day_has_ordinal_suffix = Some(true),
                 (b"hour", b"repr:24") => modifiers.hour_is_12_hour_clock = Some(false),
                 (b"hour", b"repr:12") => modifiers.hour_is_12_hour_clock = Some(true),
                 (b"month" | b"period" | b"weekday", b"case_sensitive:true") => {
@@ -327,13 +384,35 @@ impl Modifiers {
                 (b"month" | b"period" | b"weekday", b"case_sensitive:false") => {
                     modifiers.case_sensitive = Some(false)
                 }
+                (b"ignore", modifier) if modifier.starts_with(b"count:") => {
+                    modifiers.ignore_count = Some(
+                        core::str::from_utf8(&modifier[b"count:".len()..])
+                            .ok()
+                            .and_then(|count| count.parse().ok())
+                            .ok_or_else(|| InvalidFormatDescription::InvalidModifier {
+                                value: String::from_utf8_lossy(modifier).into_owned(),
+                                index: *index,
+                            })?,
+                    );
+                }
+                (b"ignore", modifier) if modifier.starts_with(b"until:") => {
+                    modifiers.ignore_until = match modifier[b"until:".len()..] {
+                        [byte] => Some(byte),
+                        _ => {
+                            return Err(InvalidFormatDescription::InvalidModifier {
+                                value: String::from_utf8_lossy(modifier).into_owned(),
+                                index: *index,
+                            });
+                        }
+                    };
+                }
                 (b"month", b"repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 (b"month", b"repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 (b"month", b"repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                (b"offset_hour" | b"year", b"sign:automatic") => {
+                (b"offset_hour" | b"unix_timestamp" | b"year", b"sign:automatic") => {
                     modifiers.sign_is_mandatory = Some(false);
                 }
-                (b"offset_hour" | b"year", b"sign:mandatory") => {
+                (b"offset_hour" | b"unix_timestamp" | b"year", b"sign:mandatory") => {
                     modifiers.sign_is_mandatory = Some(true);
                 }
                 (b"period", b"case:upper") => modifiers.period_is_uppercase = Some(true),
@@ -368,6 +447,18 @@ impl Modifiers {
                 (b"subsecond", b"digits:1+") => {
                     modifiers.subsecond_digits = Some(SubsecondDigits::OneOrMore);
                 }
+                (b"unix_timestamp", b"precision:second") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Second);
+                }
+                (b"unix_timestamp", b"precision:millisecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Millisecond);
+                }
+                (b"unix_timestamp", b"precision:microsecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Microsecond);
+                }
+                (b"unix_timestamp", b"precision:nanosecond") => {
+                    modifiers.unix_timestamp_precision = Some(UnixTimestampPrecision::Nanosecond);
+                }
                 (b"weekday", b"repr:short") => modifiers.weekday_repr = Some(WeekdayRepr::Short),
                 (b"weekday", b"repr:long") => modifiers.weekday_repr = Some(WeekdayRepr::Long),
                 (b"weekday", b"repr:sunday") => modifiers.weekday_repr = Some(WeekdayRepr::Sunday),
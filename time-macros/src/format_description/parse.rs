@@ -23,12 +23,94 @@ fn parse_component(mut s: &[u8], index: &mut usize) -> Result<Component, Invalid
     s = &s[whitespace_loc..];
     s = helper::consume_whitespace(s, index);
 
-    Ok(NakedComponent::parse(component_name, component_index)?
-        .attach_modifiers(modifier::Modifiers::parse(component_name, s, index)?))
+    NakedComponent::parse(component_name, component_index)?.attach_modifiers(
+        modifier::Modifiers::parse(component_name, s, index)?,
+        component_index,
+    )
 }
 
-fn parse_literal<'a>(s: &'a [u8], index: &mut usize) -> ParsedItem<'a> {
-    let loc = s.iter().position(|&c| c == b'[').unwrap_or(s.len());
+fn parse_nested<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+) -> Result<(Vec<FormatItem<'a>>, &'a [u8]), InvalidFormatDescription> {
+    let opening_bracket_index = *index;
+    *index += 1;
+    let (items, remaining) = parse_items(&s[1..], index, version, true)?;
+    match remaining.split_first() {
+        Some((b']', remaining)) => {
+            *index += 1;
+            Ok((items, remaining))
+        }
+        _ => Err(InvalidFormatDescription::UnclosedOpeningBracket {
+            index: opening_bracket_index,
+        }),
+    }
+}
+
+fn parse_nested_component<'a>(
+    component_name: &[u8],
+    mut s: &'a [u8],
+    index: &mut usize,
+    opening_bracket_index: usize,
+    version: u8,
+) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
+    let mut sequences = Vec::new();
+
+    loop {
+        s = helper::consume_whitespace(s, index);
+        match s.first() {
+            Some(b'[') if component_name == b"first" || sequences.is_empty() => {
+                let (items, remaining) = parse_nested(s, index, version)?;
+                sequences.push(items);
+                s = remaining;
+            }
+            Some(b']') if component_name == b"first" || !sequences.is_empty() => {
+                *index += 1;
+                break;
+            }
+            Some(b'[') => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "closing bracket",
+                    index: *index,
+                });
+            }
+            Some(_) => {
+                return Err(InvalidFormatDescription::Expected {
+                    what: "opening bracket",
+                    index: *index,
+                });
+            }
+            None => {
+                return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket_index,
+                });
+            }
+        }
+    }
+
+    let item = if component_name == b"optional" {
+        FormatItem::Optional(sequences.pop().unwrap_or_default())
+    } else {
+        FormatItem::First(sequences)
+    };
+
+    Ok(ParsedItem {
+        item,
+        remaining: &s[1..],
+    })
+}
+
+fn parse_literal<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+    is_nested: bool,
+) -> ParsedItem<'a> {
+    let loc = s
+        .iter()
+        .position(|&c| c == b'[' || (version >= 2 && c == b'\\') || (is_nested && c == b']'))
+        .unwrap_or(s.len());
     *index += loc;
     ParsedItem {
         item: FormatItem::Literal(&s[..loc]),
@@ -39,46 +121,96 @@ fn parse_literal<'a>(s: &'a [u8], index: &mut usize) -> ParsedItem<'a> {
 fn parse_item<'a>(
     s: &'a [u8],
     index: &mut usize,
+    version: u8,
+    is_nested: bool,
 ) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
-    if let [b'[', b'[', remaining @ ..] = s {
-        *index += 2;
-        return Ok(ParsedItem {
-            item: FormatItem::Literal(&[b'[']),
-            remaining,
-        });
-    };
+    match (version, s) {
+        (1, [b'[', b'[', remaining @ ..]) => {
+            *index += 2;
+            return Ok(ParsedItem {
+                item: FormatItem::Literal(&[b'[']),
+                remaining,
+            });
+        }
+        (2, [b'\\', c @ (b'\\' | b'[' | b']'), remaining @ ..]) => {
+            *index += 2;
+            return Ok(ParsedItem {
+                item: FormatItem::Literal(std::slice::from_ref(c)),
+                remaining,
+            });
+        }
+        (2, [b'\\', ..]) => {
+            return Err(InvalidFormatDescription::Expected {
+                what: "valid escape sequence",
+                index: *index,
+            });
+        }
+        _ => {}
+    }
 
-    if s.starts_with(&[b'[']) {
-        if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
-            *index += 1; // opening bracket
-            let ret_val = ParsedItem {
-                item: FormatItem::Component(parse_component(&s[1..bracket_index], index)?),
-                remaining: &s[bracket_index + 1..],
-            };
-            *index += 1; // closing bracket
-            Ok(ret_val)
-        } else {
-            Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
+    if !s.starts_with(&[b'[']) {
+        return Ok(parse_literal(s, index, version, is_nested));
+    }
+
+    if version >= 2 {
+        let name_start = helper::consume_whitespace(&s[1..], &mut 0);
+        let name_len = name_start
+            .iter()
+            .position(|&c| c.is_ascii_whitespace() || c == b']')
+            .unwrap_or(name_start.len());
+        let component_name = &name_start[..name_len];
+        if component_name == b"optional" || component_name == b"first" {
+            let opening_bracket_index = *index;
+            *index += s.len() - name_start.len() + name_len;
+            return parse_nested_component(
+                component_name,
+                &name_start[name_len..],
+                index,
+                opening_bracket_index,
+                version,
+            );
         }
+    }
+
+    if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
+        *index += 1; // opening bracket
+        let ret_val = ParsedItem {
+            item: FormatItem::Component(parse_component(&s[1..bracket_index], index)?),
+            remaining: &s[bracket_index + 1..],
+        };
+        *index += 1; // closing bracket
+        Ok(ret_val)
     } else {
-        Ok(parse_literal(s, index))
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
     }
 }
 
-pub(crate) fn parse(mut s: &[u8], span: Span) -> Result<Vec<FormatItem<'_>>, Error> {
-    let mut compound = Vec::new();
-    let mut loc = 0;
-
-    while !s.is_empty() {
-        let ParsedItem { item, remaining } =
-            parse_item(s, &mut loc).map_err(|error| Error::InvalidFormatDescription {
-                error,
-                span_start: Some(span),
-                span_end: Some(span),
-            })?;
+fn parse_items<'a>(
+    mut s: &'a [u8],
+    index: &mut usize,
+    version: u8,
+    is_nested: bool,
+) -> Result<(Vec<FormatItem<'a>>, &'a [u8]), InvalidFormatDescription> {
+    let mut items = Vec::new();
+
+    while let Some(&first) = s.first() {
+        if is_nested && first == b']' {
+            break;
+        }
+        let ParsedItem { item, remaining } = parse_item(s, index, version, is_nested)?;
         s = remaining;
-        compound.push(item);
+        items.push(item);
     }
 
-    Ok(compound)
+    Ok((items, s))
+}
+
+pub(crate) fn parse(s: &[u8], span: Span, version: u8) -> Result<Vec<FormatItem<'_>>, Error> {
+    parse_items(s, &mut 0, version, false)
+        .map(|(items, _)| items)
+        .map_err(|error| Error::InvalidFormatDescription {
+            error,
+            span_start: Some(span),
+            span_end: Some(span),
+        })
 }
@@ -0,0 +1,236 @@
+use proc_macro::Span;
+
+use crate::format_description::component::NakedComponent;
+use crate::format_description::error::InvalidFormatDescription;
+use crate::format_description::modifier::{
+    Modifiers, MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+};
+use crate::format_description::FormatItem;
+use crate::Error;
+
+fn expand(specifier: &[u8], padding: Option<Padding>) -> Option<Vec<FormatItem<'static>>> {
+    let component = |component: NakedComponent, modifiers: Modifiers| {
+        FormatItem::Component(
+            component
+                .attach_modifiers(modifiers, 0)
+                .expect("no specifier has a required modifier"),
+        )
+    };
+    let numeric = |naked_component: NakedComponent, modifiers: Modifiers| {
+        component(
+            naked_component,
+            Modifiers {
+                padding: padding.or(modifiers.padding),
+                ..modifiers
+            },
+        )
+    };
+    let year = |repr, iso_week_based| Modifiers {
+        year_repr: Some(repr),
+        year_is_iso_week_based: Some(iso_week_based),
+        ..Modifiers::default()
+    };
+    let hour = |padding, is_12_hour_clock| Modifiers {
+        padding: Some(padding),
+        hour_is_12_hour_clock: Some(is_12_hour_clock),
+        ..Modifiers::default()
+    };
+    let week_number = |repr| Modifiers {
+        week_number_repr: Some(repr),
+        ..Modifiers::default()
+    };
+    let weekday = |repr, one_indexed| Modifiers {
+        weekday_repr: Some(repr),
+        weekday_is_one_indexed: Some(one_indexed),
+        ..Modifiers::default()
+    };
+    let month = |repr| Modifiers {
+        month_repr: Some(repr),
+        ..Modifiers::default()
+    };
+    let offset_hour = || Modifiers {
+        sign_is_mandatory: Some(true),
+        ..Modifiers::default()
+    };
+    let period = |is_uppercase| Modifiers {
+        period_is_uppercase: Some(is_uppercase),
+        ..Modifiers::default()
+    };
+
+    Some(match specifier {
+        b"d" => vec![numeric(NakedComponent::Day, Modifiers::default())],
+        b"e" => vec![numeric(
+            NakedComponent::Day,
+            Modifiers {
+                padding: Some(Padding::Space),
+                ..Modifiers::default()
+            },
+        )],
+        b"g" => vec![numeric(NakedComponent::Year, year(YearRepr::LastTwo, true))],
+        b"G" => vec![numeric(NakedComponent::Year, year(YearRepr::Full, true))],
+        b"H" => vec![numeric(NakedComponent::Hour, hour(Padding::Zero, false))],
+        b"I" => vec![numeric(NakedComponent::Hour, hour(Padding::Zero, true))],
+        b"j" => vec![numeric(NakedComponent::Ordinal, Modifiers::default())],
+        b"k" => vec![numeric(NakedComponent::Hour, hour(Padding::Space, false))],
+        b"l" => vec![numeric(NakedComponent::Hour, hour(Padding::Space, true))],
+        b"m" => vec![numeric(NakedComponent::Month, Modifiers::default())],
+        b"M" => vec![numeric(NakedComponent::Minute, Modifiers::default())],
+        b"S" => vec![numeric(NakedComponent::Second, Modifiers::default())],
+        b"U" => vec![numeric(
+            NakedComponent::WeekNumber,
+            week_number(WeekNumberRepr::Sunday),
+        )],
+        b"V" => vec![numeric(
+            NakedComponent::WeekNumber,
+            week_number(WeekNumberRepr::Iso),
+        )],
+        b"W" => vec![numeric(
+            NakedComponent::WeekNumber,
+            week_number(WeekNumberRepr::Monday),
+        )],
+        b"y" => vec![numeric(
+            NakedComponent::Year,
+            year(YearRepr::LastTwo, false),
+        )],
+        b"Y" => vec![numeric(NakedComponent::Year, year(YearRepr::Full, false))],
+        _ if padding.is_some() => return None,
+        b"a" => vec![component(
+            NakedComponent::Weekday,
+            weekday(WeekdayRepr::Short, true),
+        )],
+        b"A" => vec![component(
+            NakedComponent::Weekday,
+            weekday(WeekdayRepr::Long, true),
+        )],
+        b"b" | b"h" => vec![component(NakedComponent::Month, month(MonthRepr::Short))],
+        b"B" => vec![component(NakedComponent::Month, month(MonthRepr::Long))],
+        b"D" => vec![
+            component(NakedComponent::Month, Modifiers::default()),
+            FormatItem::Literal(b"/"),
+            component(NakedComponent::Day, Modifiers::default()),
+            FormatItem::Literal(b"/"),
+            component(NakedComponent::Year, year(YearRepr::LastTwo, false)),
+        ],
+        b"f" => vec![component(
+            NakedComponent::Subsecond,
+            Modifiers {
+                subsecond_digits: Some(SubsecondDigits::Six),
+                ..Modifiers::default()
+            },
+        )],
+        b"F" => vec![
+            component(NakedComponent::Year, Modifiers::default()),
+            FormatItem::Literal(b"-"),
+            component(NakedComponent::Month, Modifiers::default()),
+            FormatItem::Literal(b"-"),
+            component(NakedComponent::Day, Modifiers::default()),
+        ],
+        b"n" => vec![FormatItem::Literal(b"\n")],
+        b"p" => vec![component(NakedComponent::Period, period(true))],
+        b"P" => vec![component(NakedComponent::Period, period(false))],
+        b"r" => vec![
+            component(NakedComponent::Hour, hour(Padding::Zero, true)),
+            FormatItem::Literal(b":"),
+            component(NakedComponent::Minute, Modifiers::default()),
+            FormatItem::Literal(b":"),
+            component(NakedComponent::Second, Modifiers::default()),
+            FormatItem::Literal(b" "),
+            component(NakedComponent::Period, period(true)),
+        ],
+        b"R" => vec![
+            component(NakedComponent::Hour, Modifiers::default()),
+            FormatItem::Literal(b":"),
+            component(NakedComponent::Minute, Modifiers::default()),
+        ],
+        b"s" => vec![component(
+            NakedComponent::UnixTimestamp,
+            Modifiers::default(),
+        )],
+        b"t" => vec![FormatItem::Literal(b"\t")],
+        b"T" => vec![
+            component(NakedComponent::Hour, Modifiers::default()),
+            FormatItem::Literal(b":"),
+            component(NakedComponent::Minute, Modifiers::default()),
+            FormatItem::Literal(b":"),
+            component(NakedComponent::Second, Modifiers::default()),
+        ],
+        b"u" => vec![component(
+            NakedComponent::Weekday,
+            weekday(WeekdayRepr::Monday, true),
+        )],
+        b"w" => vec![component(
+            NakedComponent::Weekday,
+            weekday(WeekdayRepr::Sunday, false),
+        )],
+        b"z" => vec![
+            component(NakedComponent::OffsetHour, offset_hour()),
+            component(NakedComponent::OffsetMinute, Modifiers::default()),
+        ],
+        b":z" => vec![
+            component(NakedComponent::OffsetHour, offset_hour()),
+            FormatItem::Literal(b":"),
+            component(NakedComponent::OffsetMinute, Modifiers::default()),
+        ],
+        b"%" => vec![FormatItem::Literal(b"%")],
+        _ => return None,
+    })
+}
+
+fn parse_inner(s: &[u8]) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let mut items = Vec::new();
+    let mut index = 0;
+
+    while index < s.len() {
+        let remaining = &s[index..];
+        if remaining[0] != b'%' {
+            let len = remaining
+                .iter()
+                .position(|&c| c == b'%')
+                .unwrap_or(remaining.len());
+            items.push(FormatItem::Literal(&remaining[..len]));
+            index += len;
+            continue;
+        }
+
+        let specifier_index = index;
+        let mut specifier = &remaining[1..];
+        let padding = match specifier.first() {
+            Some(b'-') => Some(Padding::None),
+            Some(b'_') => Some(Padding::Space),
+            Some(b'0') => Some(Padding::Zero),
+            _ => None,
+        };
+        if padding.is_some() {
+            specifier = &specifier[1..];
+        }
+        let len = match specifier {
+            [b':', _, ..] => 2,
+            [b':'] | [] => {
+                return Err(InvalidFormatDescription::MissingComponentName { index: s.len() });
+            }
+            _ => 1,
+        };
+        specifier = &specifier[..len];
+        index = specifier_index + 1 + usize::from(padding.is_some()) + len;
+
+        match expand(specifier, padding) {
+            Some(expansion) => items.extend(expansion),
+            None => {
+                return Err(InvalidFormatDescription::UnsupportedSpecifier {
+                    specifier: String::from_utf8_lossy(&s[specifier_index..index]).into_owned(),
+                    index: specifier_index,
+                });
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+pub(crate) fn parse(s: &[u8], span: Span) -> Result<Vec<FormatItem<'_>>, Error> {
+    parse_inner(s).map_err(|error| Error::InvalidFormatDescription {
+        error,
+        span_start: Some(span),
+        span_end: Some(span),
+    })
+}
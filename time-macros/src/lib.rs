@@ -70,16 +70,38 @@ impl_macros![date datetime offset time];
 // features land.
 #[proc_macro]
 pub fn format_description(input: TokenStream) -> TokenStream {
-    let (span, string) = match helpers::get_string_literal(input) {
+    let mut tokens = input.into_iter().peekable();
+    let version = match format_description::parse_version(&mut tokens) {
+        Ok(version) => version,
+        Err(err) => return err.to_compile_error(),
+    };
+    let (span, string) = match helpers::get_string_literal(tokens.collect()) {
         Ok(val) => val,
         Err(err) => return err.to_compile_error(),
     };
 
-    let items = match format_description::parse(&string, span) {
-        Ok(items) => items,
+    match format_description::parse(&string, span, version) {
+        Ok(items) => description_to_tokens(items),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+// TODO Gate this behind the the `formatting` or `parsing` feature flag when weak dependency
+// features land.
+#[proc_macro]
+pub fn strftime(input: TokenStream) -> TokenStream {
+    let (span, string) = match helpers::get_string_literal(input) {
+        Ok(val) => val,
         Err(err) => return err.to_compile_error(),
     };
 
+    match format_description::strftime::parse(&string, span) {
+        Ok(items) => description_to_tokens(items),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+fn description_to_tokens(items: Vec<format_description::FormatItem<'_>>) -> TokenStream {
     quote! {{
         const DESCRIPTION: &[::time::format_description::FormatItem<'_>] = &[#(
             items
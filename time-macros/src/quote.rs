@@ -27,6 +27,14 @@ macro_rules! quote_internal {
             ::proc_macro::Punct::new('.', ::proc_macro::Spacing::Alone)
         )),
     ] $($tail)*));
+    ([$($expanded:tt)*] => $($tail:tt)*) => (quote_internal!([$($expanded)*
+        ::proc_macro::TokenStream::from(::proc_macro::TokenTree::from(
+            ::proc_macro::Punct::new('=', ::proc_macro::Spacing::Joint)
+        )),
+        ::proc_macro::TokenStream::from(::proc_macro::TokenTree::from(
+            ::proc_macro::Punct::new('>', ::proc_macro::Spacing::Alone)
+        )),
+    ] $($tail)*));
     ([$($expanded:tt)*] : $($tail:tt)*) => (quote_internal!([$($expanded)*
         ::proc_macro::TokenStream::from(::proc_macro::TokenTree::from(
             ::proc_macro::Punct::new(':', ::proc_macro::Spacing::Alone)
@@ -65,6 +73,11 @@ macro_rules! quote_internal {
             ::proc_macro::Ident::new("_", ::proc_macro::Span::mixed_site())
         )),
     ] $($tail)*));
+    ([$($expanded:tt)*] ! $($tail:tt)*) => (quote_internal!([$($expanded)*
+        ::proc_macro::TokenStream::from(::proc_macro::TokenTree::from(
+            ::proc_macro::Punct::new('!', ::proc_macro::Spacing::Alone)
+        )),
+    ] $($tail)*));
     ([$($expanded:tt)*] < $($tail:tt)*) => (quote_internal!([$($expanded)*
         ::proc_macro::TokenStream::from(::proc_macro::TokenTree::from(
             ::proc_macro::Punct::new('<', ::proc_macro::Spacing::Alone)
@@ -1,3 +1,5 @@
+use std::num::NonZeroU16;
+
 use proc_macro::{Group, Ident, Literal, Punct, TokenStream, TokenTree};
 
 pub(crate) trait ToTokens: Sized {
@@ -43,3 +45,23 @@ impl_for_int! {
     i32 => i32_unsuffixed
     u32 => u32_unsuffixed
 }
+
+impl ToTokens for NonZeroU16 {
+    fn into_token_stream(self) -> TokenStream {
+        quote! {
+            match ::core::num::NonZeroU16::new(#(self.get())) {
+                ::core::option::Option::Some(value) => value,
+                ::core::option::Option::None => ::core::unreachable!(),
+            }
+        }
+    }
+}
+
+impl<T: ToTokens> ToTokens for Option<T> {
+    fn into_token_stream(self) -> TokenStream {
+        self.map_or_else(
+            || quote! { ::core::option::Option::None },
+            |value| quote! { ::core::option::Option::Some(#(value)) },
+        )
+    }
+}